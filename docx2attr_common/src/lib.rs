@@ -20,7 +20,7 @@ fn resolve_namespace<'a>(namespace: ResolveResult<'a>) -> Option<String> {
     }
 }
 
-pub fn docx_to_paragraphs<P: FnMut(&String) -> bool>(path: &Path, mut paragraph_predicate: P) -> Vec<String> {
+pub fn docx_to_paragraphs<P: FnMut(&String) -> bool>(path: &Path, paragraph_predicate: P) -> Vec<String> {
     let body_string = {
         // open DOCX file
         let docx_file = File::open(path)
@@ -38,8 +38,12 @@ pub fn docx_to_paragraphs<P: FnMut(&String) -> bool>(path: &Path, mut paragraph_
             .expect("failed to decode word/document.xml from docx file as UTF-8")
     };
 
+    document_xml_to_paragraphs(&body_string, paragraph_predicate)
+}
+
+pub fn document_xml_to_paragraphs<P: FnMut(&String) -> bool>(body_string: &str, mut paragraph_predicate: P) -> Vec<String> {
     // parse DOCX as XML
-    let mut parser = quick_xml::NsReader::from_str(&body_string);
+    let mut parser = quick_xml::NsReader::from_str(body_string);
     let mut buf = Vec::new();
     let mut name_stack = Vec::new();
     let mut ret = Vec::new();
@@ -77,6 +81,23 @@ pub fn docx_to_paragraphs<P: FnMut(&String) -> bool>(path: &Path, mut paragraph_
                     }
                 }
             },
+            Ok((ns, XmlEvent::Empty(empty))) => {
+                let ns_str = resolve_namespace(ns);
+                let name_str = String::from_utf8_lossy(empty.name().local_name().into_inner()).into_owned();
+                // w:tab also appears in paragraph properties (w:tabs); only count it within runs
+                let in_run = name_stack.last()
+                    .map(|(parent_ns, parent_name)|
+                        parent_ns.as_ref().map(|ns| ns == WORD_NS).unwrap_or(false) && parent_name == "r"
+                    )
+                    .unwrap_or(false);
+                if in_run && ns_str.as_ref().map(|ns| ns == WORD_NS).unwrap_or(false) {
+                    if name_str == "br" || name_str == "cr" {
+                        current_text.push('\n');
+                    } else if name_str == "tab" {
+                        current_text.push('\t');
+                    }
+                }
+            },
             Ok((_ns, XmlEvent::Text(txt))) => {
                 if collect_text {
                     current_text.push_str(txt.unescape().unwrap().as_ref());
@@ -95,3 +116,21 @@ pub fn byte_string_to_le_int_string(byte_str: &str) -> String {
     pieces.reverse();
     pieces.concat()
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_breaks_and_tabs_within_runs() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+            <w:p><w:pPr><w:tabs><w:tab w:val="left" w:pos="720"/></w:tabs></w:pPr>
+                <w:r><w:t>first</w:t><w:br/><w:t>second</w:t><w:tab/><w:t>third</w:t></w:r></w:p>
+            <w:p><w:r><w:t>skipped</w:t></w:r></w:p>
+            <w:p><w:r><w:t xml:space="preserve">a &amp; b</w:t></w:r></w:p>
+        </w:body></w:document>"#;
+        let paragraphs = document_xml_to_paragraphs(xml, |p| p != "skipped");
+        assert_eq!(paragraphs, vec!["first\nsecond\tthird".to_owned(), "a & b".to_owned()]);
+    }
+}
@@ -3,9 +3,10 @@ use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry as HashMapEntry;
 use std::env;
 use std::ffi::OsString;
-use std::fs::{File, read_dir};
+use std::fmt::Write;
+use std::fs::{self, File, read_dir};
 use std::io::Read;
-use std::mem::replace;
+use std::mem::take;
 use std::path::{Path, PathBuf};
 
 use docx2attr_common::docx_to_paragraphs;
@@ -14,6 +15,8 @@ use regex::{Regex, RegexSet};
 
 
 const PROPERTY_PREFIX: &str = "Pid";
+const TAG_PREFIX: &str = "Tag";
+const LONG_ID_PREFIX: &str = "PidLid";
 static MARKDOWN_NAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
     "(?m)",
     "^",
@@ -36,11 +39,7 @@ static MARKDOWN_VALUE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
     "\\s*",
     "\\|",
     "\\s*",
-    "(?:",
-        "Identifier:",
-        "|",
-        "Long ID \\(LID\\):",
-    ")",
+    "Identifier:",
     "\\s*",
     "(?:<br\\s*/>\\s*)?",
     "\\|",
@@ -53,6 +52,45 @@ static MARKDOWN_VALUE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
     "\\s*",
     "$",
 )).unwrap());
+static MARKDOWN_LID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
+    "(?m)",
+    "^",
+    "\\s*",
+    "\\|",
+    "\\s*",
+    "Long ID \\(LID\\):",
+    "\\s*",
+    "(?:<br\\s*/>\\s*)?",
+    "\\|",
+    "\\s*",
+    "0x",
+    "(?P<value>[0-9A-Fa-f]+)",
+    "\\s*",
+    "(?:<br\\s*/>\\s*)?",
+    "\\|",
+    "\\s*",
+    "$",
+)).unwrap());
+static MARKDOWN_PROPERTY_SET_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
+    "(?m)",
+    "^",
+    "\\s*",
+    "\\|",
+    "\\s*",
+    "Property set:",
+    "\\s*",
+    "(?:<br\\s*/>\\s*)?",
+    "\\|",
+    "\\s*",
+    "(?P<name>[A-Za-z0-9_]+)",
+    "\\s*",
+    "\\{(?P<guid>[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12})\\}",
+    "\\s*",
+    "(?:<br\\s*/>\\s*)?",
+    "\\|",
+    "\\s*",
+    "$",
+)).unwrap());
 static DOCX_NAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
     "^",
     "\\s*",
@@ -65,19 +103,37 @@ static DOCX_NAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
 static DOCX_VALUE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
     "^",
     "\\s*",
-    "(?:",
-        "Property ID:",
-        "|",
-        "Property long ID \\(LID\\):",
-    ")",
+    "Property ID:",
+    "\\s*",
+    "0x(?P<value>[0-9A-Fa-f]+)",
+    "\\s*",
+    "$",
+)).unwrap());
+static DOCX_LID_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
+    "^",
+    "\\s*",
+    "Property long ID \\(LID\\):",
     "\\s*",
     "0x(?P<value>[0-9A-Fa-f]+)",
     "\\s*",
     "$",
 )).unwrap());
+static DOCX_PROPERTY_SET_RE: Lazy<Regex> = Lazy::new(|| Regex::new(concat!(
+    "^",
+    "\\s*",
+    "Property set:",
+    "\\s*",
+    "(?P<name>[A-Za-z0-9_]+)",
+    "\\s*",
+    "\\{(?P<guid>[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12})\\}",
+    "\\s*",
+    "$",
+)).unwrap());
 static DOCX_RE_SET: Lazy<RegexSet> = Lazy::new(|| RegexSet::new(&[
     DOCX_NAME_RE.as_str(),
     DOCX_VALUE_RE.as_str(),
+    DOCX_LID_RE.as_str(),
+    DOCX_PROPERTY_SET_RE.as_str(),
 ]).unwrap());
 
 
@@ -125,6 +181,95 @@ impl PropertyCollection {
 }
 
 
+struct LongIdCollection {
+    pub long_ids: Vec<LongIdProperty>,
+    pub known_names: HashSet<String>,
+}
+impl LongIdCollection {
+    pub fn new() -> Self {
+        Self {
+            long_ids: Vec::new(),
+            known_names: HashSet::new(),
+        }
+    }
+
+    pub fn add_long_id(&mut self, mut key: String, property_set: PropertySet, long_id: u32) {
+        // variants may not start with number
+        if key.chars().nth(0).map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            key.insert(0, '_');
+        }
+
+        if !self.known_names.insert(key.clone()) {
+            // we already have a variant by this name
+            return;
+        }
+
+        self.long_ids.push(LongIdProperty {
+            property_set,
+            long_id,
+            name: key,
+        });
+    }
+
+    pub fn property_sets(&self) -> Vec<PropertySet> {
+        let mut property_sets: Vec<PropertySet> = self.long_ids.iter()
+            .map(|l| l.property_set.clone())
+            .collect();
+        property_sets.sort_unstable();
+        property_sets.dedup();
+        property_sets
+    }
+}
+
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct PropertySet {
+    pub name: String,
+    pub guid: String,
+}
+impl PropertySet {
+    pub fn const_name(&self) -> String {
+        self.name.to_uppercase()
+    }
+
+    pub fn to_const_definition(&self) -> String {
+        // 01234567-9abc-efgh-jklm-opqrstuvwxyz
+        let data1 = &self.guid[0..8];
+        let data2 = &self.guid[9..13];
+        let data3 = &self.guid[14..18];
+        let data4: Vec<String> = (19..23).step_by(2)
+            .chain((24..36).step_by(2))
+            .map(|i| format!("0x{}", self.guid[i..i+2].to_uppercase()))
+            .collect();
+        format!(
+            "pub const {}: Guid = Guid {{\n    data1: 0x{},\n    data2: 0x{},\n    data3: 0x{},\n    data4: [{}],\n}};",
+            self.const_name(), data1.to_uppercase(), data2.to_uppercase(), data3.to_uppercase(), data4.join(", "),
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct LongIdProperty {
+    pub property_set: PropertySet,
+    pub long_id: u32,
+    pub name: String,
+}
+impl LongIdProperty {
+    pub fn to_enum_variant(&self) -> String {
+        format!("    {},", self.name)
+    }
+
+    pub fn to_name_match_arm(&self) -> String {
+        let canonical_name = self.name.trim_start_matches('_');
+        format!("            Self::{} => \"{}{}\",", self.name, LONG_ID_PREFIX, canonical_name)
+    }
+
+    pub fn to_table_entry(&self) -> String {
+        format!("    ({}, 0x{:04X}, PidLid::{}),", self.property_set.const_name(), self.long_id, self.name)
+    }
+}
+
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 enum Property {
     Defined(DefinedProperty),
@@ -148,6 +293,11 @@ impl DefinedProperty {
     pub fn to_enum_variant(&self) -> String {
         format!("    {} = 0x{:04X},", self.name, self.value)
     }
+
+    pub fn to_name_match_arm(&self) -> String {
+        let canonical_name = self.name.trim_start_matches('_');
+        format!("            Self::{} => Some(\"{}{}\"),", self.name, PROPERTY_PREFIX, canonical_name)
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -162,7 +312,7 @@ impl AliasedProperty {
 }
 
 
-fn add_markdown_properties(markdown_path: &Path, properties: &mut PropertyCollection) {
+fn add_markdown_properties(markdown_path: &Path, properties: &mut PropertyCollection, long_ids: &mut LongIdCollection) {
     let entries = read_dir(&markdown_path)
         .expect("failed to read directory");
     for entry_res in entries {
@@ -173,7 +323,10 @@ fn add_markdown_properties(markdown_path: &Path, properties: &mut PropertyCollec
             None => continue,
         };
 
-        if !utf8_file_name.to_lowercase().starts_with("pidtag") {
+        let lower_file_name = utf8_file_name.to_lowercase();
+        let is_tag = lower_file_name.starts_with("pidtag");
+        let is_long_id = lower_file_name.starts_with("pidlid");
+        if !is_tag && !is_long_id {
             continue;
         }
 
@@ -194,6 +347,41 @@ fn add_markdown_properties(markdown_path: &Path, properties: &mut PropertyCollec
             Some(c) => c.name("value").unwrap().as_str(),
             None => panic!("failed to find name in {}", utf8_file_name),
         };
+
+        if is_long_id {
+            let stripped_name = match name.strip_prefix(LONG_ID_PREFIX) {
+                Some(p) => p.to_owned(),
+                None => continue,
+            };
+            let property_set = match MARKDOWN_PROPERTY_SET_RE.captures(&string) {
+                Some(c) => PropertySet {
+                    name: c.name("name").unwrap().as_str().to_owned(),
+                    guid: c.name("guid").unwrap().as_str().to_owned(),
+                },
+                None => {
+                    eprintln!("failed to find property set in {}; skipping", utf8_file_name);
+                    continue;
+                },
+            };
+            let value_str = match MARKDOWN_LID_RE.captures(&string) {
+                Some(c) => c.name("value").unwrap().as_str(),
+                None => {
+                    eprintln!("failed to find long ID in {}; skipping", utf8_file_name);
+                    continue;
+                },
+            };
+            let long_id: u32 = match u32::from_str_radix(value_str, 16) {
+                Ok(v) => v,
+                Err(_) => {
+                    eprintln!("long ID 0x{} for {} does not fit u32; skipping", value_str, name);
+                    continue;
+                },
+            };
+
+            long_ids.add_long_id(stripped_name, property_set, long_id);
+            continue;
+        }
+
         let stripped_name = match name.strip_prefix(PROPERTY_PREFIX) {
             Some(p) => p.to_owned(),
             None => continue,
@@ -218,87 +406,240 @@ fn add_markdown_properties(markdown_path: &Path, properties: &mut PropertyCollec
 }
 
 
-fn add_docx_properties(docx_path: &Path, properties: &mut PropertyCollection) {
+#[derive(Clone, Debug, Default)]
+struct DocxPropertyState {
+    pub name: Option<String>,
+    pub value: Option<u16>,
+    pub long_id: Option<u32>,
+    pub property_set: Option<PropertySet>,
+}
+impl DocxPropertyState {
+    pub fn flush(&mut self, properties: &mut PropertyCollection, long_ids: &mut LongIdCollection) {
+        let state = take(self);
+        let name = match state.name {
+            Some(n) => n,
+            None => return,
+        };
+
+        if let Some(tag_name) = name.strip_prefix(PROPERTY_PREFIX).filter(|n| n.starts_with(TAG_PREFIX)) {
+            match state.value {
+                Some(v) => properties.add_property(tag_name.to_owned(), v),
+                None => eprintln!("docx property {} does not have a value; skipping", name),
+            }
+        } else if let Some(long_id_name) = name.strip_prefix(LONG_ID_PREFIX) {
+            match (state.property_set, state.long_id) {
+                (Some(ps), Some(lid)) => long_ids.add_long_id(long_id_name.to_owned(), ps, lid),
+                _ => eprintln!("docx property {} does not have a property set and long ID; skipping", name),
+            }
+        }
+    }
+}
+
+
+fn add_docx_properties(docx_path: &Path, properties: &mut PropertyCollection, long_ids: &mut LongIdCollection) {
     let paragraphs = docx_to_paragraphs(
         &docx_path,
         |para| DOCX_RE_SET.is_match(para),
     );
 
-    let mut name: Option<String> = None;
-    let mut value: Option<u16> = None;
+    let mut state = DocxPropertyState::default();
     for paragraph in &paragraphs {
         if let Some(caps) = DOCX_NAME_RE.captures(paragraph) {
-            if let Some(n) = &name {
-                if let Some(v) = value {
-                    let new_name = replace(&mut name, None).unwrap();
-                    properties.add_property(new_name, v);
-                } else {
-                    eprintln!("docx property {} does not have a value; skipping", n);
-                }
-            }
-
-            let name_str = caps.name("value").unwrap().as_str();
-            name = if let Some(stripped) = name_str.strip_prefix(PROPERTY_PREFIX) {
-                Some(stripped.to_owned())
-            } else {
-                None
-            };
+            state.flush(properties, long_ids);
+            state.name = Some(caps.name("value").unwrap().as_str().to_owned());
         } else if let Some(caps) = DOCX_VALUE_RE.captures(paragraph) {
             let value_str = caps.name("value").unwrap().as_str();
             let new_value = match u16::from_str_radix(value_str, 16) {
                 Ok(nv) => nv,
                 Err(_) => {
-                    eprintln!("failed to parse {} as u16 as value for {:?}", value_str, name);
+                    eprintln!("failed to parse {} as u16 as value for {:?}", value_str, state.name);
                     continue;
                 },
             };
-            value = Some(new_value);
+            state.value = Some(new_value);
+        } else if let Some(caps) = DOCX_LID_RE.captures(paragraph) {
+            let value_str = caps.name("value").unwrap().as_str();
+            let new_long_id = match u32::from_str_radix(value_str, 16) {
+                Ok(nl) => nl,
+                Err(_) => {
+                    eprintln!("failed to parse {} as u32 as long ID for {:?}", value_str, state.name);
+                    continue;
+                },
+            };
+            state.long_id = Some(new_long_id);
+        } else if let Some(caps) = DOCX_PROPERTY_SET_RE.captures(paragraph) {
+            state.property_set = Some(PropertySet {
+                name: caps.name("name").unwrap().as_str().to_owned(),
+                guid: caps.name("guid").unwrap().as_str().to_owned(),
+            });
         }
     }
+    state.flush(properties, long_ids);
+}
 
-    if name.is_some() {
-        if let Some(v) = value {
-            let new_name = replace(&mut name, None).unwrap();
-            properties.add_property(new_name, v);
+
+fn output_prop_tags(properties: &PropertyCollection, out: &mut String) {
+    writeln!(out, "// This file has been generated by props_md2attr.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use std::fmt;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use from_to_repr::from_to_other;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "#[derive(Clone, Copy, Debug)]").unwrap();
+    writeln!(out, "#[from_to_other(base_type = u16, derive_compare = \"as_int\")]").unwrap();
+    writeln!(out, "pub enum PropTag {{").unwrap();
+    for property in &properties.properties {
+        writeln!(out, "{}", property.to_enum_variant()).unwrap();
+    }
+    writeln!(out, "    Other(u16),").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "impl PropTag {{").unwrap();
+    writeln!(out, "    pub fn name(&self) -> Option<&'static str> {{").unwrap();
+    writeln!(out, "        match self {{").unwrap();
+    for property in &properties.properties {
+        if let Property::Defined(defined) = property {
+            writeln!(out, "{}", defined.to_name_match_arm()).unwrap();
         }
     }
+    writeln!(out, "            Self::Other(_) => None,").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "impl fmt::Display for PropTag {{").unwrap();
+    writeln!(out, "    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{").unwrap();
+    writeln!(out, "        match self.name() {{").unwrap();
+    writeln!(out, "            Some(name) => write!(f, \"{{}}\", name),").unwrap();
+    writeln!(out, "            None => write!(f, \"0x{{:04X}}\", u16::from(*self)),").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+
+fn output_long_ids(long_ids: &LongIdCollection, out: &mut String) {
+    writeln!(out, "// This file has been generated by props_md2attr.").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use std::fmt;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use crate::guid::Guid;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out).unwrap();
+    for property_set in long_ids.property_sets() {
+        writeln!(out, "{}", property_set.to_const_definition()).unwrap();
+        writeln!(out).unwrap();
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]").unwrap();
+    writeln!(out, "pub enum PidLid {{").unwrap();
+    for long_id in &long_ids.long_ids {
+        writeln!(out, "{}", long_id.to_enum_variant()).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "impl PidLid {{").unwrap();
+    writeln!(out, "    pub fn from_long_id(property_set: &Guid, long_id: u32) -> Option<Self> {{").unwrap();
+    writeln!(out, "        LONG_IDS.iter()").unwrap();
+    writeln!(out, "            .find(|(ps, lid, _pid_lid)| ps == property_set && *lid == long_id)").unwrap();
+    writeln!(out, "            .map(|(_ps, _lid, pid_lid)| *pid_lid)").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn property_set(&self) -> Guid {{").unwrap();
+    writeln!(out, "        self.table_entry().0").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn long_id(&self) -> u32 {{").unwrap();
+    writeln!(out, "        self.table_entry().1").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    fn table_entry(&self) -> &'static (Guid, u32, PidLid) {{").unwrap();
+    writeln!(out, "        LONG_IDS.iter()").unwrap();
+    writeln!(out, "            .find(|(_ps, _lid, pid_lid)| pid_lid == self)").unwrap();
+    writeln!(out, "            .unwrap()").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "    pub fn name(&self) -> &'static str {{").unwrap();
+    writeln!(out, "        match self {{").unwrap();
+    for long_id in &long_ids.long_ids {
+        writeln!(out, "{}", long_id.to_name_match_arm()).unwrap();
+    }
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "impl fmt::Display for PidLid {{").unwrap();
+    writeln!(out, "    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{").unwrap();
+    writeln!(out, "        write!(f, \"{{}}\", self.name())").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pub const LONG_IDS: &[(Guid, u32, PidLid)] = &[").unwrap();
+    for long_id in &long_ids.long_ids {
+        writeln!(out, "{}", long_id.to_table_entry()).unwrap();
+    }
+    writeln!(out, "];").unwrap();
 }
 
 
 fn run() -> i32 {
     let args: Vec<OsString> = env::args_os().collect();
-    if args.len() != 3 {
+    let mut long_id_mode = false;
+    let mut out_path: Option<PathBuf> = None;
+    let mut positional: Vec<&OsString> = Vec::new();
+    let mut bad_args = false;
+    let mut i = 1;
+    while i < args.len() {
+        if args[i] == "--lids" {
+            long_id_mode = true;
+        } else if args[i] == "--out" {
+            i += 1;
+            match args.get(i) {
+                Some(op) => out_path = Some(PathBuf::from(op)),
+                None => bad_args = true,
+            }
+        } else {
+            positional.push(&args[i]);
+        }
+        i += 1;
+    }
+    if bad_args || positional.len() != 2 {
         let prog_name = args.get(0)
             .map(|a| a.to_string_lossy())
             .unwrap_or(Cow::Borrowed("mapi_docx2attr"));
-        eprintln!("Usage: {} MAPI_DOC_DIR MS-OXPROPS.DOCX", prog_name);
+        eprintln!("Usage: {} [--lids] [--out OUTPUT.RS] MAPI_DOC_DIR MS-OXPROPS.DOCX", prog_name);
+        eprintln!();
+        eprintln!("Outputs the PropTag enumeration, or the PidLid enumeration and long ID table if --lids is given.");
+        eprintln!("The output is written to OUTPUT.RS if --out is given and to standard output otherwise.");
         return 1;
     }
 
-    let markdown_path = PathBuf::from(&args[1]);
-    let docx_path = PathBuf::from(&args[2]);
+    let markdown_path = PathBuf::from(positional[0]);
+    let docx_path = PathBuf::from(positional[1]);
 
     let mut properties = PropertyCollection::new();
+    let mut long_ids = LongIdCollection::new();
 
     // DOCX trumps Markdown
-    add_docx_properties(&docx_path, &mut properties);
-    add_markdown_properties(&markdown_path, &mut properties);
+    add_docx_properties(&docx_path, &mut properties, &mut long_ids);
+    add_markdown_properties(&markdown_path, &mut properties, &mut long_ids);
 
     properties.properties.sort_unstable();
+    long_ids.long_ids.sort_unstable();
 
-    println!("// This file has been generated by props_md2attr.");
-    println!();
-    println!("use from_to_repr::from_to_other;");
-    println!();
-    println!();
-    println!("#[derive(Clone, Copy, Debug)]");
-    println!("#[from_to_other(base_type = u16, derive_compare = \"as_int\")]");
-    println!("pub enum PropTag {{");
-    for property in &properties.properties {
-        println!("{}", property.to_enum_variant());
+    let mut output = String::new();
+    if long_id_mode {
+        output_long_ids(&long_ids, &mut output);
+    } else {
+        output_prop_tags(&properties, &mut output);
+    }
+
+    match out_path {
+        Some(op) => {
+            if let Err(e) = fs::write(&op, output) {
+                eprintln!("failed to write {}: {}", op.display(), e);
+                return 1;
+            }
+        },
+        None => print!("{}", output),
     }
-    println!("    Other(u16),");
-    println!("}}");
 
     0
 }
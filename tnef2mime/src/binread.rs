@@ -14,11 +14,29 @@ pub trait BinaryReader {
     fn read_f64_be(&mut self) -> Result<f64, io::Error>;
     fn read_f64_le(&mut self) -> Result<f64, io::Error>;
     fn pad_to_4(&mut self, bytes_read: usize) -> Result<(), io::Error>;
+    fn pad_to_4_strict(&mut self, bytes_read: usize) -> Result<(), io::Error>;
+    // std has an unstable Read::read_array; call this one as BinaryReader::read_array(...)
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], io::Error>;
+    fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, io::Error>;
+    fn skip(&mut self, count: usize) -> Result<(), io::Error>;
 
+    // single bytes have no endianness; these allow uniform _le/_be call sites
+    fn read_u8_le(&mut self) -> Result<u8, io::Error> {
+        self.read_u8()
+    }
+    fn read_u8_be(&mut self) -> Result<u8, io::Error> {
+        self.read_u8()
+    }
     fn read_i8(&mut self) -> Result<i8, io::Error> {
         let val = self.read_u8()?;
         Ok(val as i8)
     }
+    fn read_i8_le(&mut self) -> Result<i8, io::Error> {
+        self.read_i8()
+    }
+    fn read_i8_be(&mut self) -> Result<i8, io::Error> {
+        self.read_i8()
+    }
     fn read_i16_be(&mut self) -> Result<i16, io::Error> {
         let val = self.read_u16_be()?;
         Ok(val as i16)
@@ -147,13 +165,249 @@ impl<R: io::Read> BinaryReader for R {
         Ok(f64::from_le_bytes(buf))
     }
 
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], io::Error> {
+        let mut buf = [0u8; N];
+        self.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, io::Error> {
+        // the length comes from the data; grow the buffer as bytes arrive instead of trusting it
+        let mut buf = Vec::new();
+        let mut limited = LimitReader::new(&mut *self, u64::try_from(len).unwrap());
+        io::Read::read_to_end(&mut limited, &mut buf)?;
+        if buf.len() < len {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stream ended before the expected length"));
+        }
+        Ok(buf)
+    }
+
+    fn skip(&mut self, count: usize) -> Result<(), io::Error> {
+        // discard in chunks instead of allocating the whole region
+        let mut discard_buf = [0u8; 512];
+        let mut remaining = count;
+        while remaining > 0 {
+            let chunk_size = remaining.min(discard_buf.len());
+            self.read_exact(&mut discard_buf[0..chunk_size])?;
+            remaining -= chunk_size;
+        }
+        Ok(())
+    }
+
     #[inline]
     fn pad_to_4(&mut self, bytes_read: usize) -> Result<(), io::Error> {
+        if bytes_read % 4 == 0 {
+            return Ok(())
+        }
+        let pad_count = 4 - (bytes_read % 4);
+        self.skip(pad_count)
+    }
+
+    fn pad_to_4_strict(&mut self, bytes_read: usize) -> Result<(), io::Error> {
         if bytes_read % 4 == 0 {
             return Ok(())
         }
         let mut pad_buf = [0u8; 3];
         let pad_count = 4 - (bytes_read % 4);
-        self.read_exact(&mut pad_buf[0..pad_count])
+        self.read_exact(&mut pad_buf[0..pad_count])?;
+        if pad_buf[0..pad_count].iter().any(|&b| b != 0x00) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "non-zero padding bytes"));
+        }
+        Ok(())
+    }
+}
+
+
+pub trait SeekingBinaryReader {
+    fn skip_seek(&mut self, count: usize) -> Result<(), io::Error>;
+}
+
+impl<R: io::Read + io::Seek> SeekingBinaryReader for R {
+    fn skip_seek(&mut self, count: usize) -> Result<(), io::Error> {
+        // seeking past the end succeeds, so compare against the length first;
+        // like skip, stop at the end of the stream before failing
+        let position = self.stream_position()?;
+        let end = self.seek(io::SeekFrom::End(0))?;
+        let target = position.saturating_add(u64::try_from(count).unwrap());
+        if target > end {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "skipped past the end of the stream"));
+        }
+        self.seek(io::SeekFrom::Start(target))?;
+        Ok(())
+    }
+}
+
+
+pub struct CountingReader<R> {
+    inner: R,
+    position: u64,
+}
+impl<R> CountingReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            position: 0,
+        }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+impl<R: io::Read> io::Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.position += u64::try_from(count).unwrap();
+        Ok(count)
+    }
+}
+impl<R: io::BufRead> io::BufRead for CountingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.position += u64::try_from(amt).unwrap();
+    }
+}
+
+
+pub struct LimitReader<R> {
+    inner: R,
+    remaining: u64,
+}
+impl<R> LimitReader<R> {
+    pub fn new(inner: R, limit: u64) -> Self {
+        Self {
+            inner,
+            remaining: limit,
+        }
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+impl<R: io::Read> io::Read for LimitReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // like io::Take, the end of the limit looks like the end of the stream
+        if buf.is_empty() || self.remaining == 0 {
+            return Ok(0);
+        }
+        let max_count = usize::try_from(self.remaining).unwrap_or(usize::MAX).min(buf.len());
+        let count = self.inner.read(&mut buf[..max_count])?;
+        self.remaining -= u64::try_from(count).unwrap();
+        Ok(count)
+    }
+}
+impl<R: io::BufRead> io::BufRead for LimitReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.remaining == 0 {
+            return Ok(&[]);
+        }
+        let buf = self.inner.fill_buf()?;
+        let max_count = usize::try_from(self.remaining).unwrap_or(usize::MAX).min(buf.len());
+        Ok(&buf[..max_count])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let amt_u64 = u64::try_from(amt).unwrap().min(self.remaining);
+        self.inner.consume(amt);
+        self.remaining -= amt_u64;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, Cursor, Read};
+
+    #[test]
+    fn reads_byte_arrays_and_vecs() {
+        let mut reader = Cursor::new(b"\x01\x02\x03\x04\x05\x06".to_vec());
+        let array: [u8; 2] = BinaryReader::read_array(&mut reader).unwrap();
+        assert_eq!(array, [0x01, 0x02]);
+        assert_eq!(reader.read_vec(3).unwrap(), [0x03, 0x04, 0x05]);
+        assert_eq!(reader.read_vec(0).unwrap(), Vec::<u8>::new());
+        assert_eq!(reader.read_u8().unwrap(), 0x06);
+
+        let short: Result<[u8; 4], io::Error> = BinaryReader::read_array(&mut Cursor::new(b"\x01\x02".to_vec()));
+        assert_eq!(short.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn read_vec_rejects_lengths_beyond_the_data() {
+        let mut reader = Cursor::new(b"\x01\x02\x03".to_vec());
+        let error = reader.read_vec(usize::MAX).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut reader = Cursor::new(b"\x01\x02\x03".to_vec());
+        assert_eq!(reader.read_vec(4).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn skips_bytes() {
+        let mut reader = Cursor::new(vec![0u8; 1000]);
+        reader.get_mut()[700] = 0x42;
+        reader.skip(700).unwrap();
+        assert_eq!(reader.read_u8().unwrap(), 0x42);
+
+        reader.set_position(0);
+        reader.skip_seek(700).unwrap();
+        assert_eq!(reader.read_u8().unwrap(), 0x42);
+
+        reader.skip_seek(299).unwrap();
+        assert_eq!(reader.position(), 1000);
+    }
+
+    #[test]
+    fn skipping_past_the_end_fails() {
+        let mut reader = Cursor::new(vec![0u8; 1000]);
+        assert_eq!(reader.skip(1001).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut reader = Cursor::new(vec![0u8; 1000]);
+        assert_eq!(reader.skip_seek(1001).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.position(), 1000);
+    }
+
+    #[test]
+    fn limit_reader_stops_at_limit() {
+        let mut inner = Cursor::new(b"abcdefgh".to_vec());
+        let mut limited = LimitReader::new(&mut inner, 5);
+
+        let mut buf = [0u8; 3];
+        assert_eq!(limited.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf, b"abc");
+        assert_eq!(limited.remaining(), 2);
+
+        let mut rest = Vec::new();
+        assert_eq!(limited.read_to_end(&mut rest).unwrap(), 2);
+        assert_eq!(rest, b"de");
+        assert_eq!(limited.read(&mut buf).unwrap(), 0);
+        assert_eq!(limited.read_u8().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+        // the inner reader continues after the limited region
+        assert_eq!(inner.read_u8().unwrap(), b'f');
+    }
+
+    #[test]
+    fn limit_reader_limits_buffered_reads() {
+        let mut limited = LimitReader::new(Cursor::new(b"abcdefgh".to_vec()), 4);
+        assert_eq!(limited.fill_buf().unwrap(), b"abcd");
+        limited.consume(3);
+        assert_eq!(limited.fill_buf().unwrap(), b"d");
+        limited.consume(1);
+        assert_eq!(limited.fill_buf().unwrap(), b"");
+        assert_eq!(limited.remaining(), 0);
     }
 }
@@ -0,0 +1,140 @@
+use std::io;
+
+
+pub trait BinaryWriter {
+    fn write_u8(&mut self, val: u8) -> Result<(), io::Error>;
+    fn write_u16_be(&mut self, val: u16) -> Result<(), io::Error>;
+    fn write_u16_le(&mut self, val: u16) -> Result<(), io::Error>;
+    fn write_u32_be(&mut self, val: u32) -> Result<(), io::Error>;
+    fn write_u32_le(&mut self, val: u32) -> Result<(), io::Error>;
+    fn write_u64_be(&mut self, val: u64) -> Result<(), io::Error>;
+    fn write_u64_le(&mut self, val: u64) -> Result<(), io::Error>;
+    fn write_f32_be(&mut self, val: f32) -> Result<(), io::Error>;
+    fn write_f32_le(&mut self, val: f32) -> Result<(), io::Error>;
+    fn write_f64_be(&mut self, val: f64) -> Result<(), io::Error>;
+    fn write_f64_le(&mut self, val: f64) -> Result<(), io::Error>;
+    fn pad_to_4(&mut self, bytes_written: usize) -> Result<(), io::Error>;
+
+    fn write_i8(&mut self, val: i8) -> Result<(), io::Error> {
+        self.write_u8(val as u8)
+    }
+    fn write_i16_be(&mut self, val: i16) -> Result<(), io::Error> {
+        self.write_u16_be(val as u16)
+    }
+    fn write_i16_le(&mut self, val: i16) -> Result<(), io::Error> {
+        self.write_u16_le(val as u16)
+    }
+    fn write_i32_be(&mut self, val: i32) -> Result<(), io::Error> {
+        self.write_u32_be(val as u32)
+    }
+    fn write_i32_le(&mut self, val: i32) -> Result<(), io::Error> {
+        self.write_u32_le(val as u32)
+    }
+    fn write_i64_be(&mut self, val: i64) -> Result<(), io::Error> {
+        self.write_u64_be(val as u64)
+    }
+    fn write_i64_le(&mut self, val: i64) -> Result<(), io::Error> {
+        self.write_u64_le(val as u64)
+    }
+}
+
+impl<W: io::Write> BinaryWriter for W {
+    fn write_u8(&mut self, val: u8) -> Result<(), io::Error> {
+        self.write_all(&[val])
+    }
+
+    fn write_u16_be(&mut self, val: u16) -> Result<(), io::Error> {
+        self.write_all(&val.to_be_bytes())
+    }
+
+    fn write_u16_le(&mut self, val: u16) -> Result<(), io::Error> {
+        self.write_all(&val.to_le_bytes())
+    }
+
+    fn write_u32_be(&mut self, val: u32) -> Result<(), io::Error> {
+        self.write_all(&val.to_be_bytes())
+    }
+
+    fn write_u32_le(&mut self, val: u32) -> Result<(), io::Error> {
+        self.write_all(&val.to_le_bytes())
+    }
+
+    fn write_u64_be(&mut self, val: u64) -> Result<(), io::Error> {
+        self.write_all(&val.to_be_bytes())
+    }
+
+    fn write_u64_le(&mut self, val: u64) -> Result<(), io::Error> {
+        self.write_all(&val.to_le_bytes())
+    }
+
+    fn write_f32_be(&mut self, val: f32) -> Result<(), io::Error> {
+        self.write_all(&val.to_be_bytes())
+    }
+
+    fn write_f32_le(&mut self, val: f32) -> Result<(), io::Error> {
+        self.write_all(&val.to_le_bytes())
+    }
+
+    fn write_f64_be(&mut self, val: f64) -> Result<(), io::Error> {
+        self.write_all(&val.to_be_bytes())
+    }
+
+    fn write_f64_le(&mut self, val: f64) -> Result<(), io::Error> {
+        self.write_all(&val.to_le_bytes())
+    }
+
+    #[inline]
+    fn pad_to_4(&mut self, bytes_written: usize) -> Result<(), io::Error> {
+        if bytes_written % 4 == 0 {
+            return Ok(())
+        }
+        let pad_buf = [0u8; 3];
+        let pad_count = 4 - (bytes_written % 4);
+        self.write_all(&pad_buf[0..pad_count])
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::binread::BinaryReader;
+
+    #[test]
+    fn writes_integers_in_byte_order() {
+        let mut buf = Vec::new();
+        buf.write_u16_le(0x1234).unwrap();
+        buf.write_u16_be(0x1234).unwrap();
+        buf.write_u32_le(0x12345678).unwrap();
+        buf.write_i32_be(-2).unwrap();
+        assert_eq!(buf, [
+            0x34, 0x12,
+            0x12, 0x34,
+            0x78, 0x56, 0x34, 0x12,
+            0xFF, 0xFF, 0xFF, 0xFE,
+        ]);
+    }
+
+    #[test]
+    fn round_trips_through_reader() {
+        let mut buf = Vec::new();
+        buf.write_u64_le(0x0123456789ABCDEF).unwrap();
+        buf.write_f64_be(-1.5).unwrap();
+        buf.write_i16_le(-300).unwrap();
+
+        let mut reader = Cursor::new(&buf);
+        assert_eq!(reader.read_u64_le().unwrap(), 0x0123456789ABCDEF);
+        assert_eq!(reader.read_f64_be().unwrap(), -1.5);
+        assert_eq!(reader.read_i16_le().unwrap(), -300);
+    }
+
+    #[test]
+    fn pads_to_4() {
+        for (written, pad_count) in [(0, 0), (1, 3), (2, 2), (3, 1), (4, 0), (5, 3)] {
+            let mut buf = Vec::new();
+            buf.pad_to_4(written).unwrap();
+            assert_eq!(buf, vec![0u8; pad_count]);
+        }
+    }
+}
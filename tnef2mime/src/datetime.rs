@@ -0,0 +1,123 @@
+use std::fmt;
+
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UtcDateTime {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub weekday: u8, // 0 = Monday
+}
+impl UtcDateTime {
+    pub fn from_filetime(filetime: i64) -> Option<Self> {
+        // FILETIME counts 100ns intervals since 1601-01-01T00:00:00Z
+        const FILETIME_UNIX_EPOCH_SECONDS: i64 = 11_644_473_600;
+
+        if filetime < 0 {
+            return None;
+        }
+        let unix_seconds = filetime / 10_000_000 - FILETIME_UNIX_EPOCH_SECONDS;
+        let days = unix_seconds.div_euclid(86_400);
+        let seconds_of_day = unix_seconds.rem_euclid(86_400);
+
+        // convert days since 1970-01-01 to a proleptic Gregorian date
+        let shifted_days = days + 719_468;
+        let era = shifted_days.div_euclid(146_097);
+        let day_of_era = shifted_days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Some(Self {
+            year,
+            month: month.try_into().unwrap(),
+            day: day.try_into().unwrap(),
+            hour: (seconds_of_day / 3600).try_into().unwrap(),
+            minute: ((seconds_of_day / 60) % 60).try_into().unwrap(),
+            second: (seconds_of_day % 60).try_into().unwrap(),
+            // 1970-01-01 was a Thursday
+            weekday: (days + 3).rem_euclid(7).try_into().unwrap(),
+        })
+    }
+
+    pub fn from_parts(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 => if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) { 29 } else { 28 },
+            _ => return None,
+        };
+        if day < 1 || day > days_in_month || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        // convert the proleptic Gregorian date to days since 1970-01-01
+        let shifted_year = if month <= 2 { year - 1 } else { year };
+        let era = shifted_year.div_euclid(400);
+        let year_of_era = shifted_year.rem_euclid(400);
+        let shifted_month = (i64::from(month) + 9) % 12;
+        let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            // 1970-01-01 was a Thursday
+            weekday: (days + 3).rem_euclid(7).try_into().unwrap(),
+        })
+    }
+}
+impl fmt::Display for UtcDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            self.year, self.month, self.day, self.hour, self.minute, self.second,
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_filetimes() {
+        let epoch = UtcDateTime::from_filetime(116_444_736_000_000_000).unwrap();
+        assert_eq!(epoch.to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!(epoch.weekday, 3);
+
+        let leap_day = UtcDateTime::from_filetime(133_536_855_070_000_000).unwrap();
+        assert_eq!(leap_day.to_string(), "2024-02-29T13:05:07Z");
+        assert_eq!(leap_day.weekday, 3);
+
+        let start = UtcDateTime::from_filetime(0).unwrap();
+        assert_eq!(start.to_string(), "1601-01-01T00:00:00Z");
+        assert_eq!(start.weekday, 0);
+
+        assert_eq!(UtcDateTime::from_filetime(-1), None);
+    }
+
+    #[test]
+    fn agrees_with_from_parts() {
+        let from_parts = UtcDateTime::from_parts(2024, 2, 29, 13, 5, 7).unwrap();
+        assert_eq!(Some(from_parts), UtcDateTime::from_filetime(133_536_855_070_000_000));
+
+        assert!(UtcDateTime::from_parts(2000, 2, 29, 0, 0, 0).is_some());
+        assert_eq!(UtcDateTime::from_parts(1900, 2, 29, 0, 0, 0), None);
+        assert_eq!(UtcDateTime::from_parts(2023, 4, 31, 0, 0, 0), None);
+        assert_eq!(UtcDateTime::from_parts(2023, 13, 1, 0, 0, 0), None);
+        assert_eq!(UtcDateTime::from_parts(2023, 1, 1, 24, 0, 0), None);
+    }
+}
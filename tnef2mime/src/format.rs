@@ -0,0 +1,233 @@
+use std::fmt;
+use std::io::{self, BufRead, Cursor, Read};
+
+use log::warn;
+
+use crate::tnef::{read_tnef_with_options, TnefFile, TnefReadError, TnefReadOptions, TNEF_SIGNATURE};
+
+
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FormatGuess {
+    Tnef { confident: bool },
+    Cfb,
+    Maybe,
+    Unknown,
+}
+
+
+pub fn format_confidence(bytes: &[u8]) -> FormatGuess {
+    let tnef_signature = TNEF_SIGNATURE.to_le_bytes();
+
+    if bytes.len() >= tnef_signature.len() && bytes[..tnef_signature.len()] == tnef_signature {
+        // signature (4 bytes) + legacy key (2 bytes), then the first attribute's level
+        // (message = 0x01, attachment = 0x02)
+        let confident = bytes.get(6)
+            .map(|&level| level == 0x01 || level == 0x02)
+            .unwrap_or(false);
+        return FormatGuess::Tnef { confident };
+    }
+    if bytes.len() >= CFB_SIGNATURE.len() && bytes[..CFB_SIGNATURE.len()] == CFB_SIGNATURE {
+        return FormatGuess::Cfb;
+    }
+
+    // too short to tell, but what is there matches a signature
+    let is_prefix_of = |signature: &[u8]| !bytes.is_empty()
+        && bytes.len() < signature.len()
+        && signature.starts_with(bytes);
+    if is_prefix_of(&tnef_signature) || is_prefix_of(&CFB_SIGNATURE) {
+        return FormatGuess::Maybe;
+    }
+
+    FormatGuess::Unknown
+}
+
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ParsedMessage {
+    Tnef(TnefFile),
+}
+
+
+#[derive(Debug)]
+pub enum ParseError {
+    UnknownFormat { magic: Vec<u8> },
+    UnsupportedFormat { format: FormatGuess },
+    Io(io::Error),
+    Tnef(TnefReadError),
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFormat { magic } => {
+                write!(f, "unknown file format (magic bytes:")?;
+                for b in magic {
+                    write!(f, " {:02X}", b)?;
+                }
+                write!(f, ")")
+            },
+            Self::UnsupportedFormat { format: FormatGuess::Cfb }
+                => write!(f, "this is a compound file (Outlook .msg); only TNEF is supported"),
+            Self::UnsupportedFormat { format }
+                => write!(f, "unsupported file format {:?}", format),
+            Self::Io(e)
+                => write!(f, "I/O error: {}", e),
+            Self::Tnef(e)
+                => write!(f, "failed to read TNEF: {}", e),
+        }
+    }
+}
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Tnef(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<io::Error> for ParseError {
+    fn from(e: io::Error) -> Self { Self::Io(e) }
+}
+impl From<TnefReadError> for ParseError {
+    fn from(e: TnefReadError) -> Self { Self::Tnef(e) }
+}
+
+
+pub fn parse_message_bytes(data: &[u8]) -> Result<ParsedMessage, ParseError> {
+    parse_message_bytes_with_options(data, TnefReadOptions::default())
+}
+
+pub fn parse_message_bytes_with_options(data: &[u8], options: TnefReadOptions) -> Result<ParsedMessage, ParseError> {
+    parse_message_reader_with_options(Cursor::new(data), options)
+}
+
+
+pub fn parse_message_reader<R: BufRead>(reader: R) -> Result<ParsedMessage, ParseError> {
+    parse_message_reader_with_options(reader, TnefReadOptions::default())
+}
+
+pub fn parse_message_reader_with_options<R: BufRead>(mut reader: R, options: TnefReadOptions) -> Result<ParsedMessage, ParseError> {
+    const MAGIC_LENGTH: usize = 8;
+
+    // a single fill_buf() may return fewer bytes than we need; collect the magic
+    // by hand and put it back in front of the reader afterwards
+    let mut peeked = [0u8; MAGIC_LENGTH];
+    let mut peeked_len = 0;
+    while peeked_len < MAGIC_LENGTH {
+        match reader.read(&mut peeked[peeked_len..]) {
+            Ok(0) => break,
+            Ok(n) => peeked_len += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    let peeked = &peeked[..peeked_len];
+
+    match format_confidence(peeked) {
+        FormatGuess::Tnef { confident } => {
+            if !confident {
+                warn!("TNEF signature found, but the data following it looks wrong");
+            }
+            let tnef = read_tnef_with_options(Cursor::new(peeked).chain(reader), options)?;
+            Ok(ParsedMessage::Tnef(tnef))
+        },
+        FormatGuess::Cfb => Err(ParseError::UnsupportedFormat { format: FormatGuess::Cfb }),
+        FormatGuess::Maybe | FormatGuess::Unknown => Err(ParseError::UnknownFormat {
+            magic: peeked.to_vec(),
+        }),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tnef::{write_tnef, TnefAttribute, TnefAttributeId, TnefAttributeLevel};
+
+    #[test]
+    fn detects_tnef() {
+        let file = TnefFile {
+            legacy_key: 1,
+            attributes: vec![TnefAttribute {
+                level: TnefAttributeLevel::Message,
+                id: TnefAttributeId::MessageClass,
+                data: b"IPM.Note\0".to_vec(),
+                checksum: 0,
+            }],
+        };
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &file).unwrap();
+
+        assert_eq!(format_confidence(&buf), FormatGuess::Tnef { confident: true });
+        let ParsedMessage::Tnef(parsed) = parse_message_bytes(&buf).unwrap();
+        assert_eq!(parsed.message_class().as_deref(), Some("IPM.Note"));
+    }
+
+    // hands out a single byte per read
+    struct TricklingReader<'a>(&'a [u8]);
+    impl<'a> Read for TricklingReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if buf.is_empty() || self.0.is_empty() {
+                return Ok(0);
+            }
+            buf[0] = self.0[0];
+            self.0 = &self.0[1..];
+            Ok(1)
+        }
+    }
+    impl<'a> BufRead for TricklingReader<'a> {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            Ok(&self.0[..self.0.len().min(1)])
+        }
+        fn consume(&mut self, amt: usize) {
+            self.0 = &self.0[amt..];
+        }
+    }
+
+    #[test]
+    fn detects_tnef_from_short_reads() {
+        let file = TnefFile {
+            legacy_key: 1,
+            attributes: vec![TnefAttribute {
+                level: TnefAttributeLevel::Message,
+                id: TnefAttributeId::MessageClass,
+                data: b"IPM.Note\0".to_vec(),
+                checksum: 0,
+            }],
+        };
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &file).unwrap();
+
+        let ParsedMessage::Tnef(parsed) = parse_message_reader(TricklingReader(&buf)).unwrap();
+        assert_eq!(parsed.message_class().as_deref(), Some("IPM.Note"));
+
+        match parse_message_reader(TricklingReader(b"From: someone\r\n")) {
+            Err(ParseError::UnknownFormat { magic }) => assert_eq!(magic, b"From: so"),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_compound_files() {
+        let mut buf = CFB_SIGNATURE.to_vec();
+        buf.extend_from_slice(&[0u8; 24]);
+        assert_eq!(format_confidence(&buf), FormatGuess::Cfb);
+        assert!(matches!(
+            parse_message_bytes(&buf),
+            Err(ParseError::UnsupportedFormat { format: FormatGuess::Cfb }),
+        ));
+    }
+
+    #[test]
+    fn reports_unknown_magic() {
+        match parse_message_bytes(b"From: someone\r\n") {
+            Err(ParseError::UnknownFormat { magic }) => assert_eq!(magic, b"From: so"),
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(format_confidence(&[0x78, 0x9F]), FormatGuess::Maybe);
+        assert_eq!(format_confidence(&[]), FormatGuess::Unknown);
+    }
+}
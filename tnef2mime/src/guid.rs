@@ -72,6 +72,60 @@ impl Guid {
             data4,
         })
     }
+
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&self.data1.to_le_bytes());
+        bytes[4..6].copy_from_slice(&self.data2.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.data3.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.data4);
+        bytes
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&self.data1.to_be_bytes());
+        bytes[4..6].copy_from_slice(&self.data2.to_be_bytes());
+        bytes[6..8].copy_from_slice(&self.data3.to_be_bytes());
+        bytes[8..16].copy_from_slice(&self.data4);
+        bytes
+    }
+
+    pub fn parse_str(s: &str) -> Option<Self> {
+        // accepts the format output by Display, optionally in braces and in any case
+        let trimmed = s.trim();
+        let unbraced = match trimmed.strip_prefix('{') {
+            Some(rest) => rest.strip_suffix('}')?,
+            None => trimmed,
+        };
+
+        let groups: Vec<&str> = unbraced.split('-').collect();
+        let group_lengths = [8, 4, 4, 4, 12];
+        if groups.len() != group_lengths.len() {
+            return None;
+        }
+        for (group, &length) in groups.iter().zip(group_lengths.iter()) {
+            if group.len() != length || !group.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+        }
+
+        let data1 = u32::from_str_radix(groups[0], 16).ok()?;
+        let data2 = u16::from_str_radix(groups[1], 16).ok()?;
+        let data3 = u16::from_str_radix(groups[2], 16).ok()?;
+        let data4_hex = format!("{}{}", groups[3], groups[4]);
+        let mut data4 = [0u8; 8];
+        for (i, b) in data4.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&data4_hex[2*i..2*i+2], 16).ok()?;
+        }
+
+        Some(Self {
+            data1,
+            data2,
+            data3,
+            data4,
+        })
+    }
 }
 impl fmt::Display for Guid {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -82,3 +136,49 @@ impl fmt::Display for Guid {
         )
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // PS_PUBLIC_STRINGS {00020329-0000-0000-C000-000000000046}
+    const PUBLIC_STRINGS: Guid = Guid {
+        data1: 0x00020329,
+        data2: 0x0000,
+        data3: 0x0000,
+        data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+    };
+    const PUBLIC_STRINGS_LE: [u8; 16] = [
+        0x29, 0x03, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46,
+    ];
+
+    #[test]
+    fn converts_bytes() {
+        assert_eq!(PUBLIC_STRINGS.to_le_bytes(), PUBLIC_STRINGS_LE);
+        assert_eq!(Guid::from_le_bytes(&PUBLIC_STRINGS_LE), Some(PUBLIC_STRINGS));
+
+        let be_bytes = PUBLIC_STRINGS.to_be_bytes();
+        assert_eq!(be_bytes[..4], [0x00, 0x02, 0x03, 0x29]);
+        assert_eq!(Guid::from_be_bytes(&be_bytes), Some(PUBLIC_STRINGS));
+
+        assert_eq!(Guid::from_le_bytes(&PUBLIC_STRINGS_LE[..15]), None);
+    }
+
+    #[test]
+    fn parses_display_output() {
+        let displayed = PUBLIC_STRINGS.to_string();
+        assert_eq!(displayed, "00020329-0000-0000-C000-000000000046");
+        assert_eq!(Guid::parse_str(&displayed), Some(PUBLIC_STRINGS));
+        assert_eq!(Guid::parse_str("{00020329-0000-0000-c000-000000000046}"), Some(PUBLIC_STRINGS));
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        assert_eq!(Guid::parse_str("{00020329-0000-0000-C000-000000000046"), None);
+        assert_eq!(Guid::parse_str("00020329-0000-0000-C000000000000046"), None);
+        assert_eq!(Guid::parse_str("00020329-0000-0000-C000-00000000004G"), None);
+        assert_eq!(Guid::parse_str("+0020329-0000-0000-C000-000000000046"), None);
+    }
+}
@@ -0,0 +1,296 @@
+use encoding_rs::{Encoding, UTF_8};
+
+
+const LINE_BREAK_ELEMENTS: [&str; 12] = [
+    "br", "p", "/p", "div", "/div", "tr", "li", "h1", "h2", "h3", "h4", "/table",
+];
+const INVISIBLE_ELEMENTS: [&str; 4] = ["style", "script", "head", "title"];
+
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(numeric) = entity.strip_prefix('#') {
+        let value = if let Some(hex) = numeric.strip_prefix('x').or_else(|| numeric.strip_prefix('X')) {
+            u32::from_str_radix(hex, 16).ok()?
+        } else {
+            numeric.parse().ok()?
+        };
+        return char::from_u32(value);
+    }
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{A0}'),
+        "shy" => Some('\u{AD}'),
+        "copy" => Some('\u{A9}'),
+        "reg" => Some('\u{AE}'),
+        "hellip" => Some('\u{2026}'),
+        "ndash" => Some('\u{2013}'),
+        "mdash" => Some('\u{2014}'),
+        "lsquo" => Some('\u{2018}'),
+        "rsquo" => Some('\u{2019}'),
+        "ldquo" => Some('\u{201C}'),
+        "rdquo" => Some('\u{201D}'),
+        "euro" => Some('\u{20AC}'),
+        _ => None,
+    }
+}
+
+
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut invisible_until: Option<String> = None;
+    let mut last_was_space = true;
+    let mut rest = html;
+
+    while let Some(c) = rest.chars().next() {
+        let starts_tag = c == '<' && rest[1..].starts_with(|n: char| n.is_ascii_alphabetic() || n == '/' || n == '!');
+        if starts_tag {
+            let tag_end = match rest.find('>') {
+                Some(te) => te,
+                None => break, // unclosed tag at the end
+            };
+            let tag = &rest[1..tag_end];
+            rest = &rest[tag_end+1..];
+
+            if tag.starts_with("!--") && !tag.ends_with("--") {
+                // comment containing '>'
+                match rest.find("-->") {
+                    Some(ce) => rest = &rest[ce+3..],
+                    None => break,
+                }
+                continue;
+            }
+
+            let tag_name: String = tag
+                .trim_start()
+                .chars()
+                .take_while(|c| !c.is_whitespace() && *c != '>')
+                .collect::<String>()
+                .trim_end_matches('/')
+                .to_lowercase();
+
+            if let Some(closing) = &invisible_until {
+                if &tag_name == closing {
+                    invisible_until = None;
+                }
+                continue;
+            }
+            if INVISIBLE_ELEMENTS.contains(&tag_name.as_str()) {
+                invisible_until = Some(format!("/{}", tag_name));
+                continue;
+            }
+            if LINE_BREAK_ELEMENTS.contains(&tag_name.as_str()) {
+                text.push('\n');
+                last_was_space = true;
+            } else if tag_name == "td" || tag_name == "th" {
+                text.push('\t');
+                last_was_space = true;
+            }
+        } else if invisible_until.is_some() {
+            rest = &rest[c.len_utf8()..];
+        } else if c == '&' {
+            let entity_end = rest[1..].find(|c: char| c == ';' || c == '<' || c == '&' || c.is_whitespace())
+                .map(|e| e + 1)
+                .filter(|&e| rest.as_bytes()[e] == b';');
+            let decoded = entity_end.and_then(|e| decode_entity(&rest[1..e]).map(|d| (d, e)));
+            match decoded {
+                Some((d, e)) => {
+                    text.push(d);
+                    last_was_space = false;
+                    rest = &rest[e+1..];
+                },
+                None => {
+                    // not a known entity; keep it verbatim
+                    text.push('&');
+                    last_was_space = false;
+                    rest = &rest[1..];
+                },
+            }
+        } else if c.is_whitespace() {
+            // collapse whitespace as a browser would
+            if !last_was_space {
+                text.push(' ');
+                last_was_space = true;
+            }
+            rest = &rest[c.len_utf8()..];
+        } else {
+            text.push(c);
+            last_was_space = false;
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    // drop spaces around line breaks
+    let lines: Vec<&str> = text.split('\n')
+        .map(|l| l.trim_matches(' '))
+        .collect();
+    lines.join("\n").trim().to_owned()
+}
+
+
+fn rewrite_meta_charset(html: &str) -> String {
+    // ASCII lowercasing keeps byte offsets intact
+    let lower_html = html.to_ascii_lowercase();
+    let mut ret = String::with_capacity(html.len() + 24);
+    let mut copied_up_to = 0;
+    let mut found_meta_charset = false;
+
+    let mut search_from = 0;
+    while let Some(meta_rel) = lower_html[search_from..].find("<meta") {
+        let meta_start = search_from + meta_rel;
+        let meta_end = match lower_html[meta_start..].find('>') {
+            Some(me) => meta_start + me,
+            None => break,
+        };
+        search_from = meta_end;
+
+        let charset_pos = match lower_html[meta_start..meta_end].find("charset=") {
+            Some(cp) => meta_start + cp + "charset=".len(),
+            None => continue,
+        };
+        found_meta_charset = true;
+
+        // the value may be quoted (charset="x") or end a content attribute (content="...; charset=x")
+        let value_start = if html[charset_pos..].starts_with(['"', '\'']) { charset_pos + 1 } else { charset_pos };
+        let value_end = lower_html[value_start..meta_end]
+            .find(|c: char| c == '"' || c == '\'' || c == ';' || c == '/' || c.is_whitespace())
+            .map(|ve| value_start + ve)
+            .unwrap_or(meta_end);
+        ret.push_str(&html[copied_up_to..value_start]);
+        ret.push_str("utf-8");
+        copied_up_to = value_end;
+    }
+    ret.push_str(&html[copied_up_to..]);
+
+    if !found_meta_charset {
+        let insert_at = lower_html.find("<head>")
+            .or_else(|| lower_html.find("<head "))
+            .and_then(|head_start| lower_html[head_start..].find('>').map(|he| head_start + he + 1))
+            .unwrap_or(0);
+        ret.insert_str(insert_at, "<meta charset=\"utf-8\">");
+    }
+    ret
+}
+
+
+pub fn html_meta_charset(html: &[u8]) -> Option<&'static Encoding> {
+    // only the ASCII parts matter; lowercasing them keeps byte offsets intact
+    let lower_html = String::from_utf8_lossy(html).to_ascii_lowercase();
+    let mut search_from = 0;
+    while let Some(meta_rel) = lower_html[search_from..].find("<meta") {
+        let meta_start = search_from + meta_rel;
+        let meta_end = lower_html[meta_start..].find('>')
+            .map(|me| meta_start + me)?;
+        search_from = meta_end;
+
+        let charset_pos = match lower_html[meta_start..meta_end].find("charset=") {
+            Some(cp) => meta_start + cp + "charset=".len(),
+            None => continue,
+        };
+        let value = lower_html[charset_pos..meta_end].trim_start_matches(['"', '\'']);
+        let value_end = value.find(|c: char| c == '"' || c == '\'' || c == ';' || c == '/' || c.is_whitespace())
+            .unwrap_or(value.len());
+        // as in browsers, a document cannot declare itself UTF-16 from within
+        return Encoding::for_label(&value.as_bytes()[..value_end])
+            .map(|enc| enc.output_encoding());
+    }
+    None
+}
+
+
+pub fn html_source_encoding(html: &[u8], declared: Option<&'static Encoding>, fallback: &'static Encoding) -> &'static Encoding {
+    // an explicit codepage wins over the document's own declaration, which wins over guessing
+    if let Some(enc) = declared.or_else(|| html_meta_charset(html)) {
+        return enc;
+    }
+    if std::str::from_utf8(html).is_ok() {
+        return UTF_8;
+    }
+    fallback
+}
+
+
+pub fn transcode_html_to_utf8(html: &[u8], encoding: &'static Encoding) -> Option<Vec<u8>> {
+    if encoding == UTF_8 {
+        // nothing to do
+        return None;
+    }
+    let (decoded, _bad_sequences) = encoding.decode_without_bom_handling(html);
+    Some(rewrite_meta_charset(&decoded).into_bytes())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_markup_to_lines() {
+        let html = "<html><head><title>Ignored</title><style>p { color: red; }</style></head>\r\n\
+            <body><p>Hello,   <b>World</b>!</p><p>Second<br>line</p>\
+            <table><tr><td>a</td><td>b</td></tr></table></body></html>";
+        assert_eq!(html_to_text(html), "Hello, World!\n\nSecond\nline\n\n\ta\tb");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(html_to_text("Fish &amp; Chips &#8211; &#x20AC;5&nbsp;each"), "Fish & Chips \u{2013} \u{20AC}5\u{A0}each");
+        assert_eq!(html_to_text("AT&T &bogus; &lt;tag&gt;"), "AT&T &bogus; <tag>");
+    }
+
+    #[test]
+    fn skips_comments_and_stray_brackets() {
+        assert_eq!(html_to_text("a<!-- x > y -->b<!--c-->d"), "abd");
+        assert_eq!(html_to_text("1 < 2 and 3 > 2"), "1 < 2 and 3 > 2");
+        assert_eq!(html_to_text("unclosed <b"), "unclosed");
+    }
+
+    #[test]
+    fn rewrites_meta_charsets() {
+        assert_eq!(
+            rewrite_meta_charset("<head><meta charset=\"windows-1252\"></head>"),
+            "<head><meta charset=\"utf-8\"></head>",
+        );
+        assert_eq!(
+            rewrite_meta_charset("<META http-equiv=\"Content-Type\" content=\"text/html; charset=iso-8859-1\">x"),
+            "<META http-equiv=\"Content-Type\" content=\"text/html; charset=utf-8\">x",
+        );
+        assert_eq!(
+            rewrite_meta_charset("<html><head><title>t</title></head></html>"),
+            "<html><head><meta charset=\"utf-8\"><title>t</title></head></html>",
+        );
+        assert_eq!(rewrite_meta_charset("<p>bare</p>"), "<meta charset=\"utf-8\"><p>bare</p>");
+    }
+
+    #[test]
+    fn transcodes_to_utf8() {
+        let windows_1252 = Encoding::for_label(b"windows-1252").unwrap();
+        let html = b"<head><meta charset=\"windows-1252\"></head>Gr\xFC\xDFe";
+        assert_eq!(
+            transcode_html_to_utf8(html, windows_1252).unwrap(),
+            "<head><meta charset=\"utf-8\"></head>Gr\u{FC}\u{DF}e".as_bytes(),
+        );
+        assert_eq!(transcode_html_to_utf8("Gr\u{FC}\u{DF}e".as_bytes(), UTF_8), None);
+    }
+
+    #[test]
+    fn determines_the_source_encoding() {
+        let windows_1252 = Encoding::for_label(b"windows-1252").unwrap();
+        let koi8_r = Encoding::for_label(b"koi8-r").unwrap();
+
+        let utf8_html = "<p>Gr\u{FC}\u{DF}e</p>".as_bytes();
+        assert_eq!(html_source_encoding(utf8_html, None, windows_1252), UTF_8);
+        assert_eq!(html_source_encoding(b"<p>Gr\xFC\xDFe</p>", None, windows_1252), windows_1252);
+
+        let meta_html = b"<meta http-equiv='Content-Type' content='text/html; charset=KOI8-R'><p>\xF0\xD2\xC9</p>";
+        assert_eq!(html_meta_charset(meta_html), Some(koi8_r));
+        assert_eq!(html_source_encoding(meta_html, None, windows_1252), koi8_r);
+        assert_eq!(html_source_encoding(meta_html, Some(windows_1252), UTF_8), windows_1252);
+
+        assert_eq!(html_meta_charset(b"<meta charset=\"utf-16\">"), Some(UTF_8));
+        assert_eq!(html_meta_charset(b"<meta name=\"generator\">"), None);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::datetime::UtcDateTime;
+use crate::tnef::{PidLid, Property, PropId, PropTag, PropValue};
+
+
+const ICAL_LINE_LENGTH: usize = 75;
+
+
+pub fn tagged_value(props: &[Property], tag: PropTag) -> Option<&PropValue> {
+    props.iter()
+        .filter(|p| p.id.is_none())
+        .find(|p| p.tag == tag)
+        .map(|p| &p.value)
+}
+
+pub fn named_value(props: &[Property], lid: PidLid) -> Option<&PropValue> {
+    props.iter()
+        .find(|p| match &p.id {
+            Some((guid, PropId::Number(long_id))) => PidLid::from_long_id(guid, *long_id) == Some(lid),
+            _ => false,
+        })
+        .map(|p| &p.value)
+}
+
+pub fn as_str(value: &PropValue) -> Option<&str> {
+    match value {
+        PropValue::String8(s) | PropValue::String(s) => Some(s.trim_end_matches('\0')),
+        _ => None,
+    }
+}
+
+fn as_time(value: &PropValue) -> Option<i64> {
+    match value {
+        PropValue::Time(t) => Some(*t),
+        _ => None,
+    }
+}
+
+fn format_ical_time(filetime: i64) -> Option<String> {
+    let dt = UtcDateTime::from_filetime(filetime)?;
+    Some(format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        dt.year, dt.month, dt.day, dt.hour, dt.minute, dt.second,
+    ))
+}
+
+pub fn escape_text(text: &str) -> String {
+    let mut ret = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                ret.push('\\');
+                ret.push(c);
+            },
+            '\n' => ret.push_str("\\n"),
+            '\r' => {},
+            _ => ret.push(c),
+        }
+    }
+    ret
+}
+
+pub fn push_line(ical: &mut String, line: &str) {
+    // fold at 75 octets without splitting a UTF-8 sequence
+    let mut rest = line;
+    let mut limit = ICAL_LINE_LENGTH;
+    while rest.len() > limit {
+        let mut split_at = limit;
+        while !rest.is_char_boundary(split_at) {
+            split_at -= 1;
+        }
+        ical.push_str(&rest[..split_at]);
+        ical.push_str("\r\n ");
+        rest = &rest[split_at..];
+        // the leading space of a continuation line counts towards its length
+        limit = ICAL_LINE_LENGTH - 1;
+    }
+    ical.push_str(rest);
+    ical.push_str("\r\n");
+}
+
+
+pub fn calendar_method(message_class: &str) -> Option<&'static str> {
+    let lower_class = message_class.to_lowercase();
+    if lower_class.starts_with("ipm.schedule.meeting.request") {
+        Some("REQUEST")
+    } else if lower_class.starts_with("ipm.schedule.meeting.canceled") {
+        Some("CANCEL")
+    } else if lower_class.starts_with("ipm.schedule.meeting.resp.") {
+        Some("REPLY")
+    } else {
+        None
+    }
+}
+
+
+pub fn build_vevent(props: &[Property]) -> Option<String> {
+    let start = named_value(props, PidLid::AppointmentStartWhole)
+        .or_else(|| tagged_value(props, PropTag::TagStartDate))
+        .and_then(as_time)
+        .and_then(format_ical_time)?;
+    let end = named_value(props, PidLid::AppointmentEndWhole)
+        .or_else(|| tagged_value(props, PropTag::TagEndDate))
+        .and_then(as_time)
+        .and_then(format_ical_time);
+    let stamp = tagged_value(props, PropTag::TagCreationTime)
+        .and_then(as_time)
+        .and_then(format_ical_time)
+        .unwrap_or_else(|| start.clone());
+
+    let mut vevent = String::new();
+    push_line(&mut vevent, "BEGIN:VEVENT");
+
+    let global_object_id = named_value(props, PidLid::CleanGlobalObjectId)
+        .or_else(|| named_value(props, PidLid::GlobalObjectId));
+    if let Some(PropValue::Binary(goid)) = global_object_id {
+        let uid: String = goid.iter()
+            .map(|b| format!("{:02X}", b))
+            .collect();
+        push_line(&mut vevent, &format!("UID:{}", uid));
+    }
+    push_line(&mut vevent, &format!("DTSTAMP:{}", stamp));
+    push_line(&mut vevent, &format!("DTSTART:{}", start));
+    if let Some(e) = end {
+        push_line(&mut vevent, &format!("DTEND:{}", e));
+    }
+    if let Some(subject) = tagged_value(props, PropTag::TagSubject).and_then(as_str) {
+        push_line(&mut vevent, &format!("SUMMARY:{}", escape_text(subject)));
+    }
+    if let Some(location) = named_value(props, PidLid::Location).and_then(as_str) {
+        if !location.is_empty() {
+            push_line(&mut vevent, &format!("LOCATION:{}", escape_text(location)));
+        }
+    }
+
+    let organizer_address = tagged_value(props, PropTag::TagSentRepresentingSmtpAddress)
+        .or_else(|| tagged_value(props, PropTag::TagSenderSmtpAddress))
+        .and_then(as_str);
+    if let Some(address) = organizer_address {
+        let name = tagged_value(props, PropTag::TagSentRepresentingName)
+            .or_else(|| tagged_value(props, PropTag::TagSenderName))
+            .and_then(as_str);
+        match name {
+            Some(n) => push_line(&mut vevent, &format!("ORGANIZER;CN=\"{}\":mailto:{}", n.replace('"', "'"), address)),
+            None => push_line(&mut vevent, &format!("ORGANIZER:mailto:{}", address)),
+        }
+    }
+
+    push_line(&mut vevent, "END:VEVENT");
+    Some(vevent)
+}
+
+
+pub fn build_calendar(method: &str, vevent: &str) -> String {
+    let mut ical = String::new();
+    push_line(&mut ical, "BEGIN:VCALENDAR");
+    push_line(&mut ical, "PRODID:-//tnef2mime//EN");
+    push_line(&mut ical, "VERSION:2.0");
+    push_line(&mut ical, &format!("METHOD:{}", method));
+    ical.push_str(vevent);
+    push_line(&mut ical, "END:VCALENDAR");
+    ical
+}
@@ -0,0 +1,14 @@
+pub mod binread;
+pub mod binwrite;
+pub mod datetime;
+pub mod format;
+pub mod guid;
+pub mod html;
+pub mod ical;
+pub mod mime;
+pub mod rtf;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod summary;
+pub mod tnef;
+pub mod vcard;
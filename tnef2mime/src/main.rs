@@ -1,19 +1,52 @@
-mod binread;
-mod guid;
-mod tnef;
-
-
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::env;
 use std::ffi::OsString;
-use std::fs::File;
-use std::io::{Cursor, Read, Write};
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use codepage::to_encoding;
 use encoding_rs::{Encoding, UTF_8};
 use env_logger;
+use log::warn;
+
+use tnef2mime::format::{parse_message_bytes_with_options, parse_message_reader_with_options, ParsedMessage};
+use tnef2mime::html::{html_source_encoding, html_to_text, transcode_html_to_utf8};
+use tnef2mime::ical::{build_calendar, build_vevent, calendar_method};
+use tnef2mime::mime::{
+    add_header_field, base64_encode, base64_encode_line, encode_unstructured, format_address, format_date_time,
+    format_filetime, has_header_field, header_field_value, header_parameter, normalize_header_block,
+    quoted_printable_encode, remove_header_fields, replace_content_type, DEFAULT_HEADER_LINE_LENGTH,
+};
+use tnef2mime::rtf::{decode_compressed_rtf, rtf_to_text};
+use tnef2mime::summary::{recipient_address, sender_address, string_property, summarize};
+use tnef2mime::tnef::{
+    AttachRenderData, decode_ansi_string, decode_flags, decode_properties_lenient, decode_properties_with_options,
+    decode_property_lists_with_options, parse_attach_render_data, parse_tnef_date, Property, PropId, PropTag, PropValue,
+    resolve_named_property, TnefAttributeId, TnefReadOptions,
+};
+use tnef2mime::vcard::{build_vcard, is_contact_class};
+
+
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+struct Attachment {
+    pub data: Option<Vec<u8>>,
+    pub file_name: Option<String>,
+    pub mime_tag: Option<String>,
+    pub declared_size: Option<i32>,
+    pub modification_time: Option<i64>,
+    pub render_data: Option<AttachRenderData>,
+    pub properties: Vec<Property>,
+}
+
 
-use crate::tnef::{decode_properties, PropTag, PropValue, read_tnef, TnefAttributeId};
+const TNEF_DATE_ATTRIBUTES: [TnefAttributeId; 7] = [
+    TnefAttributeId::DateSent, TnefAttributeId::DateRecd, TnefAttributeId::DateModified,
+    TnefAttributeId::DateStart, TnefAttributeId::DateEnd,
+    TnefAttributeId::AttachCreateDate, TnefAttributeId::AttachModifyDate,
+];
 
 
 fn hexdump(bytes: &[u8], prefix: &str) {
@@ -50,67 +83,795 @@ fn hexdump(bytes: &[u8], prefix: &str) {
 }
 
 
+fn omitted_notice(total: usize, limit: Option<usize>) -> Option<String> {
+    let l = limit?;
+    if total > l {
+        Some(format!("... ({} more omitted)", total - l))
+    } else {
+        None
+    }
+}
+
+
+fn trim_html_body(body: &[u8], strict: bool) -> Option<&[u8]> {
+    // strip trailing NUL padding
+    let trimmed_len = body.iter()
+        .rposition(|&b| b != 0x00)
+        .map(|p| p + 1)
+        .unwrap_or(0);
+    let trimmed = &body[..trimmed_len];
+
+    if trimmed.contains(&0x00) {
+        if strict {
+            return None;
+        }
+        warn!("HTML body contains NUL bytes before its end");
+    }
+
+    Some(trimmed)
+}
+
+
+// interface identifiers (GUIDs in little-endian byte order) that may precede PtypObject values
+const OBJECT_INTERFACE_IDS: [[u8; 16]; 3] = [
+    // IID_IMessage {00020307-0000-0000-C000-000000000046}
+    [0x07, 0x03, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+    // IID_IStorage {0000000B-0000-0000-C000-000000000046}
+    [0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+    // IID_IStream {0000000C-0000-0000-C000-000000000046}
+    [0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+];
+
+
+fn ole1_native_data(data: &[u8]) -> Option<&[u8]> {
+    // OLE 1.0 embedded object (MS-OLEDS 2.2.4): version (4), format ID (4),
+    // class, topic and item names as length-prefixed strings, native data size (4), native data
+    const OLE_VERSION: u32 = 0x00000501;
+    const FORMAT_ID_EMBEDDED: u32 = 0x00000002;
+
+    let read_u32 = |offset: usize| -> Option<u32> {
+        let bytes = data.get(offset..offset+4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+    if read_u32(0)? != OLE_VERSION || read_u32(4)? != FORMAT_ID_EMBEDDED {
+        return None;
+    }
+    let mut offset = 8;
+    for _ in 0..3 {
+        let string_length: usize = read_u32(offset)?.try_into().ok()?;
+        offset = offset.checked_add(4 + string_length)?;
+    }
+    let native_size: usize = read_u32(offset)?.try_into().ok()?;
+    let native_start = offset + 4;
+    data.get(native_start..native_start.checked_add(native_size)?)
+}
+
+
+fn unwrap_ole_attachment(data: &[u8], is_ole: bool) -> &[u8] {
+    // only OLE attachments are wrapped; a file attachment may well start with the same bytes
+    if !is_ole {
+        return data;
+    }
+    ole1_native_data(data).unwrap_or(data)
+}
+
+
+fn is_ole_attachment(render_data: Option<&AttachRenderData>, properties: &[Property]) -> bool {
+    const ATTACH_OLE: i32 = 6;
+
+    let ole_method = properties.iter()
+        .any(|p| p.tag == PropTag::TagAttachMethod && p.value == PropValue::Integer32(ATTACH_OLE));
+    ole_method || render_data.map(|rd| rd.is_ole()).unwrap_or(false)
+}
+
+
+fn attachment_payload(prop: &Property, is_ole: bool) -> Option<Cow<'_, [u8]>> {
+    match &prop.value {
+        PropValue::Binary(data) => Some(Cow::Borrowed(unwrap_ole_attachment(data, is_ole))),
+        PropValue::Object(data) => {
+            // MS-OXTNEF stores PtypObject values prefixed with the IID of the interface
+            // through which the object is to be opened; it is not part of the data
+            let has_interface_id = data.len() >= 16
+                && OBJECT_INTERFACE_IDS.iter().any(|iid| data[..16] == *iid);
+            if has_interface_id {
+                Some(Cow::Borrowed(unwrap_ole_attachment(&data[16..], is_ole)))
+            } else {
+                Some(Cow::Borrowed(unwrap_ole_attachment(data, is_ole)))
+            }
+        },
+        _ => None,
+    }
+}
+
+
+fn attachment_size_problem(index: usize, attachment: &Attachment) -> Option<String> {
+    let data = attachment.data.as_ref()?;
+    let declared_size = attachment.declared_size?;
+
+    // PR_ATTACH_SIZE also counts the attachment's properties,
+    // so it may only ever be larger than the data
+    let data_len: i64 = data.len().try_into().unwrap();
+    if i64::from(declared_size) < data_len {
+        Some(format!("attachment {} declares a size of {} bytes but contains {} bytes of data", index, declared_size, data_len))
+    } else {
+        None
+    }
+}
+
+
+fn is_html_attachment(attachment: &Attachment) -> bool {
+    if let Some(mime_tag) = &attachment.mime_tag {
+        return mime_tag.to_lowercase().starts_with("text/html");
+    }
+    if let Some(file_name) = &attachment.file_name {
+        let lower_name = file_name.to_lowercase();
+        return lower_name.ends_with(".htm") || lower_name.ends_with(".html");
+    }
+    false
+}
+
+
+fn is_text_attachment(attachment: &Attachment) -> bool {
+    if let Some(mime_tag) = &attachment.mime_tag {
+        let lower_tag = mime_tag.to_lowercase();
+        return lower_tag.starts_with("text/html") || lower_tag.starts_with("text/plain");
+    }
+    if let Some(file_name) = &attachment.file_name {
+        let lower_name = file_name.to_lowercase();
+        return lower_name.ends_with(".htm") || lower_name.ends_with(".html") || lower_name.ends_with(".txt");
+    }
+    false
+}
+
+
+fn pkcs7_smime_type(data: &[u8]) -> Option<&'static str> {
+    // ContentInfo ::= SEQUENCE { contentType OBJECT IDENTIFIER, ... }
+    const CONTENT_TYPES: [(&[u8], &str); 4] = [
+        (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x02], "signed-data"),
+        (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x03], "enveloped-data"),
+        (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x10, 0x01, 0x09], "compressed-data"),
+        (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x10, 0x01, 0x17], "authEnveloped-data"),
+    ];
+
+    if *data.first()? != 0x30 {
+        return None;
+    }
+    // short, long or (BER) indefinite length; we only need to skip it
+    let length_byte = *data.get(1)?;
+    let oid_pos = if length_byte > 0x80 { 2 + usize::from(length_byte & 0x7F) } else { 2 };
+    if *data.get(oid_pos)? != 0x06 {
+        return None;
+    }
+    let oid_len = usize::from(*data.get(oid_pos + 1)?);
+    let oid = data.get(oid_pos + 2..oid_pos + 2 + oid_len)?;
+    CONTENT_TYPES.iter()
+        .find(|(content_type, _smime_type)| *content_type == oid)
+        .map(|(_content_type, smime_type)| *smime_type)
+}
+
+
+fn build_smime_email(headers: &str, message_class: &str, attachments: &[Attachment]) -> Option<Vec<u8>> {
+    // the signed or encrypted content is stored as an attachment
+    let smime_attachment = attachments.iter()
+        .filter(|a| a.data.is_some())
+        .find(|a| a.file_name.as_ref().map(|f| {
+            let lower_name = f.to_lowercase();
+            lower_name == "smime.p7m" || lower_name == "smime.p7s"
+        }).unwrap_or(false))?;
+    let data = smime_attachment.data.as_ref().unwrap();
+
+    let mut email = remove_header_fields(
+        headers,
+        &["MIME-Version", "Content-Type", "Content-Transfer-Encoding", "Content-Disposition"],
+    );
+    email.push_str("MIME-Version: 1.0\r\n");
+
+    if message_class.eq_ignore_ascii_case("IPM.Note.SMIME.MultipartSigned") {
+        // the attachment is the complete multipart/signed entity, including its headers
+        let mut email_bytes = email.into_bytes();
+        email_bytes.extend_from_slice(data);
+        Some(email_bytes)
+    } else {
+        // the attachment is the opaque PKCS#7 structure; it knows whether it is signed or encrypted
+        match pkcs7_smime_type(data) {
+            Some(smime_type) => email.push_str(&format!(
+                "Content-Type: application/pkcs7-mime; smime-type={}; name=\"smime.p7m\"\r\n", smime_type,
+            )),
+            None => email.push_str("Content-Type: application/pkcs7-mime; name=\"smime.p7m\"\r\n"),
+        }
+        email.push_str("Content-Transfer-Encoding: base64\r\n");
+        email.push_str("Content-Disposition: attachment; filename=\"smime.p7m\"\r\n");
+        email.push_str("\r\n");
+        email.push_str(&base64_encode(data));
+        Some(email.into_bytes())
+    }
+}
+
+
+fn filetime_to_system_time(filetime: i64) -> Option<SystemTime> {
+    // FILETIME counts 100ns intervals since 1601-01-01T00:00:00Z
+    const FILETIME_UNIX_EPOCH_OFFSET: i64 = 116_444_736_000_000_000;
+    let unix_intervals = u64::try_from(filetime.checked_sub(FILETIME_UNIX_EPOCH_OFFSET)?).ok()?;
+    SystemTime::UNIX_EPOCH.checked_add(Duration::from_nanos(unix_intervals.checked_mul(100)?))
+}
+
+
+fn json_string(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len() + 2);
+    ret.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if c < ' ' => ret.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+
+fn message_to_json_line(path: &Path, read_options: TnefReadOptions) -> String {
+    let file_name = json_string(&path.to_string_lossy());
+
+    let buf = match fs::read(path) {
+        Ok(b) => b,
+        Err(e) => return format!("{{\"file\":{},\"error\":{}}}", file_name, json_string(&e.to_string())),
+    };
+    let tnef = match parse_message_bytes_with_options(&buf, read_options) {
+        Ok(ParsedMessage::Tnef(t)) => t,
+        Err(e) => return format!("{{\"file\":{},\"error\":{}}}", file_name, json_string(&e.to_string())),
+    };
+
+    let attributes: Vec<String> = tnef.attributes.iter()
+        .map(|a| format!(
+            "{{\"level\":{},\"id\":{},\"length\":{}}}",
+            json_string(&format!("{:?}", a.level)),
+            json_string(&format!("{:?}", a.id)),
+            a.data.len(),
+        ))
+        .collect();
+    format!(
+        "{{\"file\":{},\"legacy_key\":{},\"attachments\":{},\"attributes\":[{}]}}",
+        file_name, tnef.legacy_key, tnef.attachment_groups().len(), attributes.join(","),
+    )
+}
+
+
+fn run_jsonl(dir: &OsString, strict: bool) -> i32 {
+    let mut paths: Vec<_> = match fs::read_dir(dir) {
+        Ok(rd) => rd
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file())
+            .collect(),
+        Err(e) => {
+            eprintln!("failed to read directory {}: {}", dir.to_string_lossy(), e);
+            return 1;
+        },
+    };
+    paths.sort();
+
+    let read_options = TnefReadOptions {
+        verify_checksums: strict,
+        verify_padding: strict,
+        ..TnefReadOptions::default()
+    };
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    for path in &paths {
+        writeln!(out, "{}", message_to_json_line(path, read_options))
+            .expect("failed to write to stdout");
+    }
+    0
+}
+
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Options {
+    pub limit: Option<usize>,
+    pub strict: bool,
+    pub promote_text_attachment: bool,
+    pub max_header_length: usize,
+    pub time_headers: bool,
+    pub preserve_times: bool,
+    pub text_transcript: bool,
+}
+
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct OutputPaths {
+    pub attachment: PathBuf,
+    pub eml: PathBuf,
+    pub text: PathBuf,
+    pub invite: PathBuf,
+    pub contact: PathBuf,
+}
+
+
+fn run_batch(message_paths: &[&OsString], options: &Options, out_dir: &Path) -> i32 {
+    let mut inputs: Vec<PathBuf> = Vec::new();
+    for message_path in message_paths {
+        let path = Path::new(message_path);
+        if path.is_dir() {
+            let mut dir_files: Vec<PathBuf> = match fs::read_dir(path) {
+                Ok(rd) => rd
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.is_file())
+                    .collect(),
+                Err(e) => {
+                    eprintln!("failed to read directory {}: {}", path.display(), e);
+                    return 1;
+                },
+            };
+            dir_files.sort();
+            inputs.extend(dir_files);
+        } else {
+            inputs.push(path.to_owned());
+        }
+    }
+
+    let mut manifest_entries = Vec::with_capacity(inputs.len());
+    let mut used_stems = HashSet::new();
+    for input in &inputs {
+        let base_stem = input.file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "email".to_owned());
+        // x.dat and x.tnef must not overwrite each other's output
+        let mut stem = base_stem.clone();
+        let mut counter = 1;
+        while !used_stems.insert(stem.clone()) {
+            counter += 1;
+            stem = format!("{}-{}", base_stem, counter);
+        }
+        let paths = OutputPaths {
+            attachment: out_dir.join(format!("{}.attachment.bin", stem)),
+            eml: out_dir.join(format!("{}.eml", stem)),
+            text: out_dir.join(format!("{}.txt", stem)),
+            invite: out_dir.join(format!("{}.ics", stem)),
+            contact: out_dir.join(format!("{}.vcf", stem)),
+        };
+
+        println!("=== {}", input.display());
+        let result = File::open(input)
+            .map_err(|e| format!("failed to open file: {}", e))
+            .and_then(|file| convert_message(BufReader::new(file), options, &paths));
+        let input_json = json_string(&input.to_string_lossy());
+        let entry = match result {
+            Ok(eml_written) => {
+                let output_json = if eml_written {
+                    json_string(&paths.eml.to_string_lossy())
+                } else {
+                    "null".to_owned()
+                };
+                format!("{{\"input\":{},\"output\":{},\"status\":\"ok\"}}", input_json, output_json)
+            },
+            Err(e) => {
+                eprintln!("{}: {}", input.display(), e);
+                format!("{{\"input\":{},\"output\":null,\"status\":\"error\",\"error\":{}}}", input_json, json_string(&e))
+            },
+        };
+        manifest_entries.push(entry);
+    }
+
+    let manifest = format!("[\n  {}\n]\n", manifest_entries.join(",\n  "));
+    if let Err(e) = fs::write(out_dir.join("manifest.json"), manifest) {
+        eprintln!("failed to write manifest.json: {}", e);
+        return 1;
+    }
+    0
+}
+
+
+fn usage(args: &[OsString]) {
+    let arg0 = args
+        .get(0)
+        .map(|a| a.to_string_lossy())
+        .unwrap_or(Cow::Borrowed("tnef2mime"));
+    eprintln!("Usage: {} [OPTIONS] MESSAGE", arg0);
+    eprintln!("       {} [OPTIONS] MESSAGE|DIRECTORY...", arg0);
+    eprintln!("       {} --jsonl DIRECTORY", arg0);
+    eprintln!();
+    eprintln!("If MESSAGE is -, the message is read from standard input.");
+    eprintln!("With multiple messages or a directory, each MESSAGE.dat is converted to MESSAGE.eml");
+    eprintln!("and the results are listed in manifest.json.");
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --limit N    print at most N properties per section");
+    eprintln!("  --strict     fail on questionable data instead of repairing it");
+    eprintln!("  --promote-text-attachment");
+    eprintln!("               use the only attachment as the body if it is text and there is no body");
+    eprintln!("  --max-header-length N");
+    eprintln!("               fold added header fields at N characters (default: {})", DEFAULT_HEADER_LINE_LENGTH);
+    eprintln!("  --time-headers");
+    eprintln!("               add X-Creation-Time and X-Last-Modification-Time header fields");
+    eprintln!("  --out-dir DIRECTORY");
+    eprintln!("               write output files into DIRECTORY instead of the current directory");
+    eprintln!("  --eml-name NAME");
+    eprintln!("               name of the email file (default: email.eml)");
+    eprintln!("  --text       also write the body as plain text to email.txt (named after the email file)");
+    eprintln!("  --preserve-times");
+    eprintln!("               set the modification time of attachment.bin to that of the attachment");
+    eprintln!("  --jsonl DIRECTORY");
+    eprintln!("               print the structure of each message in DIRECTORY as one JSON object per line");
+}
+
+
 fn run() -> i32 {
     let args: Vec<OsString> = env::args_os().collect();
-    if args.len() != 2 {
-        let arg0 = args
-            .get(0)
-            .map(|a| a.to_string_lossy())
-            .unwrap_or(Cow::Borrowed("tnef2mime"));
-        eprintln!("Usage: {} MESSAGE", arg0);
+
+    let mut limit: Option<usize> = None;
+    let mut strict = false;
+    let mut promote_text_attachment = false;
+    let mut message_paths: Vec<&OsString> = Vec::new();
+    let mut jsonl_dir: Option<&OsString> = None;
+    let mut max_header_length = DEFAULT_HEADER_LINE_LENGTH;
+    let mut time_headers = false;
+    let mut preserve_times = false;
+    let mut out_dir: Option<PathBuf> = None;
+    let mut eml_name: OsString = OsString::from("email.eml");
+    let mut text_transcript = false;
+    let mut i = 1;
+    while i < args.len() {
+        if args[i] == "--limit" {
+            i += 1;
+            let parsed = args.get(i)
+                .and_then(|a| a.to_str())
+                .and_then(|a| a.parse().ok());
+            match parsed {
+                Some(l) => limit = Some(l),
+                None => {
+                    usage(&args);
+                    return 1;
+                },
+            }
+        } else if args[i] == "--max-header-length" {
+            i += 1;
+            let parsed = args.get(i)
+                .and_then(|a| a.to_str())
+                .and_then(|a| a.parse().ok());
+            match parsed {
+                Some(l) => max_header_length = l,
+                None => {
+                    usage(&args);
+                    return 1;
+                },
+            }
+        } else if args[i] == "--jsonl" {
+            i += 1;
+            match args.get(i) {
+                Some(dir) => jsonl_dir = Some(dir),
+                None => {
+                    usage(&args);
+                    return 1;
+                },
+            }
+        } else if args[i] == "--time-headers" {
+            time_headers = true;
+        } else if args[i] == "--out-dir" {
+            i += 1;
+            match args.get(i) {
+                Some(dir) => out_dir = Some(PathBuf::from(dir)),
+                None => {
+                    usage(&args);
+                    return 1;
+                },
+            }
+        } else if args[i] == "--eml-name" {
+            i += 1;
+            match args.get(i) {
+                Some(name) => eml_name = name.clone(),
+                None => {
+                    usage(&args);
+                    return 1;
+                },
+            }
+        } else if args[i] == "--preserve-times" {
+            preserve_times = true;
+        } else if args[i] == "--text" {
+            text_transcript = true;
+        } else if args[i] == "--strict" {
+            strict = true;
+        } else if args[i] == "--promote-text-attachment" {
+            promote_text_attachment = true;
+        } else {
+            message_paths.push(&args[i]);
+        }
+        i += 1;
+    }
+    if let Some(dir) = jsonl_dir {
+        if !message_paths.is_empty() {
+            usage(&args);
+            return 1;
+        }
+        env_logger::init();
+        return run_jsonl(dir, strict);
+    }
+    if message_paths.is_empty() {
+        usage(&args);
         return 1;
     }
+    let options = Options {
+        limit,
+        strict,
+        promote_text_attachment,
+        max_header_length,
+        time_headers,
+        preserve_times,
+        text_transcript,
+    };
 
     env_logger::init();
 
-    let mut buf = Vec::new();
-    {
-        let mut file = File::open(&args[1])
+    let out_dir = match out_dir {
+        Some(od) => {
+            fs::create_dir_all(&od)
+                .expect("failed to create output directory");
+            od
+        },
+        None => PathBuf::new(),
+    };
+
+    let is_batch = message_paths.len() > 1
+        || message_paths.iter().any(|mp| Path::new(mp).is_dir());
+    if is_batch {
+        return run_batch(&message_paths, &options, &out_dir);
+    }
+    let message_path = message_paths[0];
+
+    let eml_path = out_dir.join(&eml_name);
+    let paths = OutputPaths {
+        attachment: out_dir.join("attachment.bin"),
+        text: eml_path.with_extension("txt"),
+        invite: out_dir.join("invite.ics"),
+        contact: out_dir.join("contact.vcf"),
+        eml: eml_path,
+    };
+
+    // the message is parsed straight from the reader instead of being buffered first
+    let result = if message_path == "-" {
+        convert_message(io::stdin().lock(), &options, &paths)
+    } else {
+        let file = File::open(message_path)
             .expect("failed to open file");
-        file.read_to_end(&mut buf)
-            .expect("failed to read file");
+        convert_message(BufReader::new(file), &options, &paths)
+    };
+
+    match result {
+        Ok(_) => 0,
+        Err(e) => {
+            eprintln!("{}", e);
+            1
+        },
     }
+}
 
-    let mut encoder: &Encoding = UTF_8;
 
+fn convert_message<R: BufRead>(reader: R, options: &Options, paths: &OutputPaths) -> Result<bool, String> {
     let mut headers = None;
     let mut body = None;
+    let mut body_is_html = true;
+    let mut html_body_string: Option<String> = None;
+    let mut text_body: Option<String> = None;
+    let mut message_class = None;
+    let mut conversation_index = None;
+    let mut message_props: Vec<Property> = Vec::new();
+    let mut rtf_compressed = None;
+    let mut creation_time = None;
+    let mut last_modification_time = None;
+    let mut to_addresses: Vec<String> = Vec::new();
+    let mut cc_addresses: Vec<String> = Vec::new();
+    let mut seen_recipient_table = false;
+    let mut recipient_lists: Vec<Vec<Property>> = Vec::new();
+    let mut attachments: Vec<Attachment> = Vec::new();
 
-    let buf_cursor = Cursor::new(&buf);
-    let tnef = read_tnef(buf_cursor)
-        .expect("failed to read TNEF");
+    // outside strict mode, repair what can be repaired
+    let read_options = TnefReadOptions {
+        verify_checksums: options.strict,
+        allow_odd_string_lengths: !options.strict,
+        verify_padding: options.strict,
+    };
+    let tnef = match parse_message_reader_with_options(reader, read_options) {
+        Ok(ParsedMessage::Tnef(t)) => t,
+        Err(e) => return Err(e.to_string()),
+    };
     println!("legacy key: {}", tnef.legacy_key);
+
+    let encoder = tnef.encoding();
+
     for attribute in &tnef.attributes {
         println!("attribute {:?}.{:?}", attribute.level, attribute.id);
         if attribute.id == TnefAttributeId::OemCodepage && attribute.data.len() >= 2 {
-            let codepage_id =
-                ((attribute.data[0] as u16) << 0)
-                | ((attribute.data[1] as u16) << 8)
-            ;
-            if let Some(new_encoder) = to_encoding(codepage_id) {
-                encoder = new_encoder;
+            println!("    {}", encoder.name());
+        } else if attribute.id == TnefAttributeId::MessageClass {
+            let class = decode_ansi_string(&attribute.data, encoder);
+            let class = class.trim_end_matches('\0').to_owned();
+            println!("    {}", class);
+            message_class = Some(class);
+        } else if TNEF_DATE_ATTRIBUTES.contains(&attribute.id) {
+            match parse_tnef_date(&attribute.data) {
+                Some(date) => println!("    {}", date),
+                None => hexdump(&attribute.data, "    "),
             }
+        } else if attribute.id == TnefAttributeId::RecipTable {
+            seen_recipient_table = true;
+            match decode_property_lists_with_options(attribute.data_reader(), encoder, read_options) {
+                Ok(recipients) => {
+                    for (index, recipient) in recipients.iter().enumerate() {
+                        println!("    recipient {}", index);
+                        for prop in recipient.iter().take(options.limit.unwrap_or(usize::MAX)) {
+                            println!("      {}: {}", prop.tag, prop.value);
+                        }
+                        if let Some(notice) = omitted_notice(recipient.len(), options.limit) {
+                            println!("      {}", notice);
+                        }
+
+                        let recipient_type = recipient.iter()
+                            .filter(|p| p.tag == PropTag::TagRecipientType)
+                            .find_map(|p| if let PropValue::Integer32(t) = &p.value { Some(*t) } else { None });
+                        if let Some(address) = recipient_address(recipient) {
+                            // MAPI_TO = 1, MAPI_CC = 2; blind carbon copies are not output
+                            match recipient_type {
+                                Some(1) => to_addresses.push(address),
+                                Some(2) => cc_addresses.push(address),
+                                _ => {},
+                            }
+                        }
+                    }
+                    recipient_lists.extend(recipients);
+                },
+                Err(e) => {
+                    println!("    failed to decode recipients: {}", e);
+                    hexdump(&attribute.data, "    ");
+                },
+            }
+        } else if attribute.id == TnefAttributeId::AttachRendData {
+            // starts a new attachment
+            let render_data = parse_attach_render_data(&attribute.data);
+            match &render_data {
+                Some(rd) => println!(
+                    "    type 0x{:04X}, position {}, size {}x{}, flags 0x{:08X}",
+                    rd.attach_type, rd.position, rd.width, rd.height, rd.flags,
+                ),
+                None => hexdump(&attribute.data, "    "),
+            }
+            attachments.push(Attachment {
+                render_data,
+                ..Attachment::default()
+            });
         } else if attribute.id == TnefAttributeId::MsgProps || attribute.id == TnefAttributeId::Attachment {
-            match decode_properties(Cursor::new(&attribute.data), encoder) {
+            let decoded = if options.strict {
+                decode_properties_with_options(attribute.data_reader(), encoder, read_options)
+            } else {
+                // salvage the properties preceding a broken one
+                let (props, error) = decode_properties_lenient(attribute.data_reader(), encoder, read_options);
+                if let Some(e) = error {
+                    println!("    failed to decode all properties: {}", e);
+                }
+                Ok(props)
+            };
+            match decoded {
                 Ok(props) => {
-                    for prop in &props {
+                    if attribute.id == TnefAttributeId::MsgProps {
+                        message_props.extend(props.iter().cloned());
+                    } else if let Some(current) = attachments.last_mut() {
+                        // keep all property sets of the attachment
+                        current.properties.extend(props.iter().cloned());
+                    }
+                    // the attachment method may follow the data in the same property list
+                    let is_ole = attribute.id == TnefAttributeId::Attachment
+                        && attachments.last()
+                            .map(|a| is_ole_attachment(a.render_data.as_ref(), &a.properties))
+                            .unwrap_or(false);
+                    for (index, prop) in props.iter().enumerate() {
                         if prop.tag == PropTag::TagAttachDataBinary {
-                            if let PropValue::Object(val) = &prop.value {
-                                let mut attachment = File::create("attachment.bin")
-                                    .expect("failed to open attachment.bin");
-                                attachment.write_all(&val[16..])
-                                    .expect("failed to write attachment.bin");
+                            if let Some(payload) = attachment_payload(prop, is_ole) {
+                                let mut attachment = File::create(&paths.attachment)
+                                    .map_err(|e| format!("failed to open attachment.bin: {}", e))?;
+                                attachment.write_all(&payload)
+                                    .map_err(|e| format!("failed to write attachment.bin: {}", e))?;
+                                if let Some(current) = attachments.last_mut() {
+                                    current.data = Some(payload.into_owned());
+                                }
+                            }
+                        } else if prop.tag == PropTag::TagAttachLongFilename || prop.tag == PropTag::TagAttachFilename {
+                            if let Some(current) = attachments.last_mut() {
+                                // prefer the long filename
+                                if current.file_name.is_none() || prop.tag == PropTag::TagAttachLongFilename {
+                                    match &prop.value {
+                                        PropValue::String8(s) | PropValue::String(s)
+                                            => current.file_name = Some(s.trim_end_matches('\0').to_owned()),
+                                        _ => {},
+                                    }
+                                }
+                            }
+                        } else if prop.tag == PropTag::TagAttachSize {
+                            if let Some(current) = attachments.last_mut() {
+                                if let PropValue::Integer32(size) = &prop.value {
+                                    current.declared_size = Some(*size);
+                                }
+                            }
+                        } else if prop.tag == PropTag::TagAttachMimeTag {
+                            if let Some(current) = attachments.last_mut() {
+                                match &prop.value {
+                                    PropValue::String8(s) | PropValue::String(s)
+                                        => current.mime_tag = Some(s.trim_end_matches('\0').to_owned()),
+                                    _ => {},
+                                }
                             }
                         } else if prop.tag == PropTag::TagTransportMessageHeaders {
-                            if let PropValue::String8(msg_headers) = &prop.value {
-                                headers = Some(msg_headers.trim_end_matches('\0').to_owned());
+                            match &prop.value {
+                                PropValue::String8(msg_headers) | PropValue::String(msg_headers)
+                                    => headers = Some(normalize_header_block(msg_headers.trim_end_matches('\0'))),
+                                _ => {},
+                            }
+                        } else if prop.tag == PropTag::TagCreationTime {
+                            if attribute.id == TnefAttributeId::MsgProps {
+                                if let PropValue::Time(t) = &prop.value {
+                                    creation_time = Some(*t);
+                                }
+                            }
+                        } else if prop.tag == PropTag::TagLastModificationTime {
+                            if let PropValue::Time(t) = &prop.value {
+                                if attribute.id == TnefAttributeId::MsgProps {
+                                    last_modification_time = Some(*t);
+                                } else if let Some(current) = attachments.last_mut() {
+                                    current.modification_time = Some(*t);
+                                }
+                            }
+                        } else if prop.tag == PropTag::TagRtfCompressed {
+                            if let PropValue::Binary(rtf) = &prop.value {
+                                rtf_compressed = Some(rtf.clone());
+                            }
+                        } else if prop.tag == PropTag::TagConversationIndex {
+                            if let PropValue::Binary(index) = &prop.value {
+                                conversation_index = Some(index.clone());
                             }
                         } else if prop.tag == PropTag::TagBodyHtml {
-                            if let PropValue::Binary(msg_body) = &prop.value {
-                                body = Some(msg_body.clone());
+                            match &prop.value {
+                                PropValue::Binary(msg_body) => body = Some(msg_body.clone()),
+                                PropValue::String8(msg_body) | PropValue::String(msg_body)
+                                    => html_body_string = Some(msg_body.trim_end_matches('\0').to_owned()),
+                                _ => {},
+                            }
+                        } else if prop.tag == PropTag::TagBody {
+                            match &prop.value {
+                                PropValue::String8(msg_body) | PropValue::String(msg_body)
+                                    => text_body = Some(msg_body.trim_end_matches('\0').to_owned()),
+                                _ => {},
+                            }
+                        }
+                        if options.limit.map(|l| index < l).unwrap_or(true) {
+                            match &prop.id {
+                                Some((guid, id)) => {
+                                    let name = match resolve_named_property(guid, id) {
+                                        Some(n) => n.to_owned(),
+                                        None => match id {
+                                            PropId::Number(n) => format!("{{{}}}/0x{:08X}", guid, n),
+                                            PropId::String(s) => format!("{{{}}}/{:?}", guid, s),
+                                        },
+                                    };
+                                    println!("    {}: {}", name, prop.value);
+                                },
+                                None => {
+                                    println!("    {}: {}", prop.tag, prop.value);
+                                    if let PropValue::Integer32(v) = &prop.value {
+                                        let flags = decode_flags(prop.tag, *v);
+                                        if !flags.is_empty() {
+                                            println!("      flags: {}", flags.join(" | "));
+                                        }
+                                    }
+                                },
                             }
                         }
-                        println!("    {:?}: {:?}", prop.tag, prop.value);
+                    }
+                    if let Some(notice) = omitted_notice(props.len(), options.limit) {
+                        println!("    {}", notice);
                     }
                 },
                 Err(e) => {
@@ -120,30 +881,592 @@ fn run() -> i32 {
                 },
             };
         } else if attribute.id == TnefAttributeId::AttachData {
-            let mut attachment = File::create("attachment.bin")
-                .expect("failed to open attachment.bin");
+            let mut attachment = File::create(&paths.attachment)
+                .map_err(|e| format!("failed to open attachment.bin: {}", e))?;
             attachment.write_all(&attribute.data)
-                .expect("failed to write attachment.bin");
+                .map_err(|e| format!("failed to write attachment.bin: {}", e))?;
+            if attachments.is_empty() {
+                attachments.push(Attachment::default());
+            }
+            attachments.last_mut().unwrap().data = Some(attribute.data.clone());
         } else {
             hexdump(&attribute.data, "    ");
         }
     }
 
+    if let Some(method) = message_class.as_deref().and_then(calendar_method) {
+        match build_vevent(&message_props) {
+            Some(vevent) => {
+                let mut invite = File::create(&paths.invite)
+                    .map_err(|e| format!("failed to open invite.ics: {}", e))?;
+                invite.write_all(build_calendar(method, &vevent).as_bytes())
+                    .map_err(|e| format!("failed to write invite.ics: {}", e))?;
+            },
+            None => {
+                warn!("meeting message without an appointment start time; not writing invite.ics");
+            },
+        }
+    }
+
+    if message_class.as_deref().map(is_contact_class).unwrap_or(false) {
+        match build_vcard(&message_props) {
+            Some(vcard) => {
+                let mut contact = File::create(&paths.contact)
+                    .map_err(|e| format!("failed to open contact.vcf: {}", e))?;
+                contact.write_all(vcard.as_bytes())
+                    .map_err(|e| format!("failed to write contact.vcf: {}", e))?;
+            },
+            None => {
+                warn!("contact message without a name; not writing contact.vcf");
+            },
+        }
+    }
+
+    if options.preserve_times {
+        // attachment.bin contains the data of the last attachment that had any
+        let last_modification = attachments.iter()
+            .rev()
+            .find(|a| a.data.is_some())
+            .and_then(|a| a.modification_time)
+            .and_then(filetime_to_system_time);
+        if let Some(lm) = last_modification {
+            let attachment = File::options().write(true).open(&paths.attachment)
+                .map_err(|e| format!("failed to open attachment.bin: {}", e))?;
+            attachment.set_modified(lm)
+                .map_err(|e| format!("failed to set modification time of attachment.bin: {}", e))?;
+        }
+    }
+
+    for (index, attachment) in attachments.iter().enumerate() {
+        if let Some(problem) = attachment_size_problem(index, attachment) {
+            if options.strict {
+                return Err(problem);
+            }
+            warn!("{}", problem);
+        }
+    }
+
+    if headers.is_none() {
+        // no transport headers; synthesize them from the message properties
+        let summary = summarize(&message_props, &recipient_lists, encoder);
+        let mut synthesized = String::from("MIME-Version: 1.0\r\nContent-Type: text/html; charset=utf-8\r\n\r\n");
+        let sent = summary.sent
+            .or_else(|| tnef.date_sent());
+        if let Some(sent) = &sent {
+            synthesized = add_header_field(&synthesized, "Date", &format_date_time(sent), options.max_header_length);
+        }
+        // older producers only write the subject as a TNEF attribute
+        let subject = summary.subject.clone()
+            .or_else(|| tnef.subject());
+        if let Some(subject) = &subject {
+            synthesized = add_header_field(&synthesized, "Subject", &encode_unstructured(subject), options.max_header_length);
+        }
+        headers = Some(synthesized);
+    }
+
+    if !seen_recipient_table {
+        // only display names are available
+        let display_names = |tag| string_property(&message_props, tag)
+            .map(|names| names.split(';')
+                .map(|n| n.trim())
+                .filter_map(|n| format_address(Some(n), None))
+                .collect::<Vec<String>>())
+            .unwrap_or_default();
+        to_addresses = display_names(PropTag::TagDisplayTo);
+        cc_addresses = display_names(PropTag::TagDisplayCc);
+    }
+
+    if let Some(h) = &headers {
+        if !has_header_field(h, "From") {
+            if let Some(from) = sender_address(&message_props, encoder) {
+                headers = Some(add_header_field(h, "From", &from, options.max_header_length));
+            }
+        }
+    }
+    if let Some(h) = &headers {
+        if !to_addresses.is_empty() && !has_header_field(h, "To") {
+            headers = Some(add_header_field(h, "To", &to_addresses.join(", "), options.max_header_length));
+        }
+    }
+    if let Some(h) = &headers {
+        if !cc_addresses.is_empty() && !has_header_field(h, "Cc") {
+            headers = Some(add_header_field(h, "Cc", &cc_addresses.join(", "), options.max_header_length));
+        }
+    }
+    if options.time_headers {
+        if let Some(h) = &headers {
+            if let Some(ct) = creation_time.and_then(format_filetime) {
+                headers = Some(add_header_field(h, "X-Creation-Time", &ct, options.max_header_length));
+            }
+        }
+        if let Some(h) = &headers {
+            if let Some(lmt) = last_modification_time.and_then(format_filetime) {
+                headers = Some(add_header_field(h, "X-Last-Modification-Time", &lmt, options.max_header_length));
+            }
+        }
+    }
+    if let Some(h) = &headers {
+        if let Some(ci) = &conversation_index {
+            if !has_header_field(h, "Thread-Index") {
+                headers = Some(add_header_field(h, "Thread-Index", &base64_encode_line(ci), options.max_header_length));
+            }
+        }
+    }
+
+    // at this point, the body can only be the binary HTML body; the OEM codepage
+    // is only a last resort for its charset
+    if let Some(b) = &body {
+        let internet_codepage = message_props.iter()
+            .filter(|p| p.tag == PropTag::TagInternetCodepage)
+            .find_map(|p| if let PropValue::Integer32(cp) = &p.value { u16::try_from(*cp).ok() } else { None })
+            .and_then(to_encoding);
+        let source_encoding = html_source_encoding(b, internet_codepage, encoder);
+        if let Some(transcoded) = transcode_html_to_utf8(b, source_encoding) {
+            body = Some(transcoded);
+            if let Some(h) = &headers {
+                let html_content_type = header_field_value(h, "Content-Type")
+                    .map(|ct| ct.to_lowercase().starts_with("text/html"))
+                    .unwrap_or(false);
+                if html_content_type {
+                    headers = Some(replace_content_type(h, "text/html; charset=utf-8"));
+                }
+            }
+        }
+    }
+
+    if body.is_none() {
+        if let (Some(h), Some(html)) = (&headers, &html_body_string) {
+            // keep the charset announced by the original headers if we can encode into it
+            let declared_encoding = header_field_value(h, "Content-Type")
+                .filter(|ct| ct.to_lowercase().starts_with("text/html"))
+                .and_then(|ct| header_parameter(&ct, "charset"))
+                .and_then(|cs| Encoding::for_label(cs.as_bytes()))
+                .filter(|enc| enc.output_encoding() == *enc);
+            match declared_encoding {
+                Some(enc) => {
+                    let (encoded, _used_encoding, _unmappable) = enc.encode(html);
+                    body = Some(encoded.into_owned());
+                },
+                None => {
+                    headers = Some(replace_content_type(h, "text/html; charset=utf-8"));
+                    body = Some(html.clone().into_bytes());
+                },
+            }
+        }
+    }
+
+    if body.is_none() {
+        if let (Some(h), Some(text)) = (&headers, &text_body) {
+            headers = Some(replace_content_type(h, "text/plain; charset=utf-8"));
+            body = Some(text.clone().into_bytes());
+            body_is_html = false;
+        }
+    }
+
+    if body.is_none() {
+        if let (Some(h), Some(rtf)) = (&headers, &rtf_compressed) {
+            match decode_compressed_rtf(rtf) {
+                Ok(decompressed) => {
+                    let text = rtf_to_text(&decompressed);
+                    headers = Some(replace_content_type(h, "text/plain; charset=utf-8"));
+                    body = Some(text.into_bytes());
+                    body_is_html = false;
+                },
+                Err(e) => {
+                    warn!("failed to decompress RTF body: {}", e);
+                },
+            }
+        }
+    }
+
+    if body.is_none() && options.promote_text_attachment && attachments.len() == 1 {
+        let attachment = &attachments[0];
+        if attachment.data.is_some() && is_text_attachment(attachment) {
+            body = attachment.data.clone();
+            body_is_html = is_html_attachment(attachment);
+        }
+    }
+
+    if let Some(h) = &headers {
+        let smime_class = message_class.as_ref()
+            .filter(|mc| mc.to_lowercase().starts_with("ipm.note.smime"));
+        if let Some(mc) = smime_class {
+            match build_smime_email(h, mc, &attachments) {
+                Some(smime_email) => {
+                    let mut email = File::create(&paths.eml)
+                        .map_err(|e| format!("failed to open email.eml: {}", e))?;
+                    email.write_all(&smime_email)
+                        .map_err(|e| format!("failed to write email.eml: {}", e))?;
+                    return Ok(true);
+                },
+                None => {
+                    eprintln!("S/MIME message without smime.p7m/smime.p7s attachment; treating as regular message");
+                },
+            }
+        }
+    }
+
     if let Some(h) = headers {
         if let Some(b) = body {
-            let mut email = File::create("email.eml")
-                .expect("failed to open email.eml");
+            // plain-text bodies and promoted attachments are written as they are
+            let b = if body_is_html {
+                match trim_html_body(&b, options.strict) {
+                    Some(tb) => tb,
+                    None => {
+                        return Err("HTML body contains NUL bytes before its end".to_owned());
+                    },
+                }
+            } else {
+                &b[..]
+            };
+            if options.text_transcript {
+                // the body is in the charset the headers declare, which need not be UTF-8
+                let body_encoding = header_field_value(&h, "Content-Type")
+                    .and_then(|ct| header_parameter(&ct, "charset"))
+                    .and_then(|cs| Encoding::for_label(cs.as_bytes()))
+                    .unwrap_or(UTF_8);
+                let (body_string, _used_encoding, _malformed) = body_encoding.decode(b);
+                let mut text = if body_is_html {
+                    html_to_text(&body_string)
+                } else {
+                    body_string.into_owned()
+                };
+                text.push('\n');
+                let mut text_file = File::create(&paths.text)
+                    .map_err(|e| format!("failed to open email.txt: {}", e))?;
+                text_file.write_all(text.as_bytes())
+                    .map_err(|e| format!("failed to write email.txt: {}", e))?;
+            }
+            // 8-bit bodies go out as quoted-printable to survive 7-bit transports
+            let (h, b) = if b.is_ascii() {
+                (h, Cow::Borrowed(b))
+            } else {
+                let mut qp_headers = remove_header_fields(&h, &["Content-Transfer-Encoding"]);
+                qp_headers.push_str("Content-Transfer-Encoding: quoted-printable\r\n\r\n");
+                (qp_headers, Cow::Owned(quoted_printable_encode(b).into_bytes()))
+            };
+            let mut email = File::create(&paths.eml)
+                .map_err(|e| format!("failed to open email.eml: {}", e))?;
             email.write_all(h.as_bytes())
-                .expect("failed to write email.eml headers");
+                .map_err(|e| format!("failed to write email.eml headers: {}", e))?;
             email.write_all(&b)
-                .expect("failed to write email.eml body");
+                .map_err(|e| format!("failed to write email.eml body: {}", e))?;
+            return Ok(true);
         }
     }
 
-    0
+    Ok(false)
 }
 
-
 fn main() {
     std::process::exit(run());
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn ole1_object(native: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&0x00000501u32.to_le_bytes());
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for name in [&b"Package\0"[..], b"\0", b"\0"] {
+            data.extend_from_slice(&u32::try_from(name.len()).unwrap().to_le_bytes());
+            data.extend_from_slice(name);
+        }
+        data.extend_from_slice(&u32::try_from(native.len()).unwrap().to_le_bytes());
+        data.extend_from_slice(native);
+        data
+    }
+
+    fn binary(tag: PropTag, data: &[u8]) -> Property {
+        Property::new(tag, None, PropValue::Binary(data.to_vec()))
+    }
+
+    #[test]
+    fn unwraps_only_ole_attachments() {
+        let wrapped = ole1_object(b"native");
+        let data = binary(PropTag::TagAttachDataBinary, &wrapped);
+        assert_eq!(attachment_payload(&data, true).unwrap().as_ref(), b"native");
+        assert_eq!(attachment_payload(&data, false).unwrap().as_ref(), &wrapped[..]);
+
+        // a different version is not an OLE 1.0 object
+        let mut other_version = wrapped.clone();
+        other_version[0] = 0x00;
+        let data = binary(PropTag::TagAttachDataBinary, &other_version);
+        assert_eq!(attachment_payload(&data, true).unwrap().as_ref(), &other_version[..]);
+    }
+
+    #[test]
+    fn detects_ole_attachments() {
+        let method = |m: i32| Property::new(PropTag::TagAttachMethod, None, PropValue::Integer32(m));
+        let ole_render_data = AttachRenderData {
+            attach_type: AttachRenderData::TYPE_OLE,
+            position: 0,
+            width: 0,
+            height: 0,
+            flags: 0,
+        };
+        let file_render_data = AttachRenderData { attach_type: AttachRenderData::TYPE_FILE, ..ole_render_data };
+
+        assert!(is_ole_attachment(None, &[method(6)]));
+        assert!(!is_ole_attachment(None, &[method(1)]));
+        assert!(is_ole_attachment(Some(&ole_render_data), &[]));
+        assert!(!is_ole_attachment(Some(&file_render_data), &[method(1)]));
+    }
+
+    #[test]
+    fn notes_omitted_properties() {
+        assert_eq!(omitted_notice(5, Some(2)).as_deref(), Some("... (3 more omitted)"));
+        assert_eq!(omitted_notice(2, Some(2)), None);
+        assert_eq!(omitted_notice(0, Some(0)), None);
+        assert_eq!(omitted_notice(5, None), None);
+    }
+
+    #[test]
+    fn trims_nul_padding_from_html_bodies() {
+        let body = b"<html><body>Hi</body></html>\0\0\0";
+        assert_eq!(trim_html_body(body, true), Some(&body[..28]));
+        assert_eq!(trim_html_body(b"\0\0", true), Some(&b""[..]));
+
+        let embedded = b"<html>\0</html>\0";
+        assert_eq!(trim_html_body(embedded, true), None);
+        assert_eq!(trim_html_body(embedded, false), Some(&embedded[..14]));
+    }
+
+    const PT_STRING8: u16 = 0x001E;
+
+    // a property list as stored in MsgProps; variable-length values get their count,
+    // length and padding added, fixed-width values are passed already encoded
+    fn property_list(props: &[(u16, u16, &[u8])]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&u32::try_from(props.len()).unwrap().to_le_bytes());
+        for &(prop_type, prop_id, value) in props {
+            data.extend_from_slice(&prop_type.to_le_bytes());
+            data.extend_from_slice(&prop_id.to_le_bytes());
+            if prop_type == PT_STRING8 || prop_type == 0x001F || prop_type == 0x0102 {
+                data.extend_from_slice(&1u32.to_le_bytes());
+                data.extend_from_slice(&u32::try_from(value.len()).unwrap().to_le_bytes());
+            }
+            data.extend_from_slice(value);
+            data.resize(data.len() + (4 - value.len() % 4) % 4, 0x00);
+        }
+        data
+    }
+
+    fn message(attributes: Vec<(TnefAttributeId, Vec<u8>)>) -> Vec<u8> {
+        use tnef2mime::tnef::{write_tnef, TnefAttribute, TnefAttributeLevel, TnefFile};
+
+        let file = TnefFile {
+            legacy_key: 0,
+            attributes: attributes.into_iter()
+                .map(|(id, data)| TnefAttribute { level: TnefAttributeLevel::Message, id, data, checksum: 0 })
+                .collect(),
+        };
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &file).unwrap();
+        buf
+    }
+
+    fn message_with_body(body: &[u8]) -> Vec<u8> {
+        // one PT_STRING8 PidTagBody property
+        message(vec![(TnefAttributeId::MsgProps, property_list(&[(PT_STRING8, 0x1000, body)]))])
+    }
+
+    fn test_options() -> Options {
+        Options {
+            limit: None,
+            strict: false,
+            promote_text_attachment: false,
+            max_header_length: DEFAULT_HEADER_LINE_LENGTH,
+            time_headers: false,
+            preserve_times: false,
+            text_transcript: false,
+        }
+    }
+
+    // converts the message in a fresh directory, returning email.eml and email.txt
+    fn convert(name: &str, data: &[u8], options: &Options) -> (Vec<u8>, Option<Vec<u8>>) {
+        let dir = env::temp_dir().join(format!("tnef2mime-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let paths = OutputPaths {
+            attachment: dir.join("attachment.bin"),
+            eml: dir.join("email.eml"),
+            text: dir.join("email.txt"),
+            invite: dir.join("invite.ics"),
+            contact: dir.join("contact.vcf"),
+        };
+        assert_eq!(convert_message(Cursor::new(data), options, &paths), Ok(true));
+        let eml = fs::read(&paths.eml).unwrap();
+        let text = fs::read(&paths.text).ok();
+        fs::remove_dir_all(&dir).unwrap();
+        (eml, text)
+    }
+
+    #[test]
+    fn batch_records_each_message_in_the_manifest() {
+        let dir = env::temp_dir().join(format!("tnef2mime-batch-{}", std::process::id()));
+        let in_dir = dir.join("in");
+        let out_dir = dir.join("out");
+        fs::create_dir_all(&in_dir).unwrap();
+        fs::create_dir_all(&out_dir).unwrap();
+        fs::write(in_dir.join("a.dat"), message_with_body(b"first")).unwrap();
+        fs::write(in_dir.join("a.tnef"), message_with_body(b"second")).unwrap();
+        fs::write(in_dir.join("b.dat"), b"not a message").unwrap();
+
+        let options = test_options();
+        let in_dir_arg = OsString::from(&in_dir);
+        assert_eq!(run_batch(&[&in_dir_arg], &options, &out_dir), 0);
+
+        // the second message with the same stem gets its own name
+        let first = fs::read_to_string(out_dir.join("a.eml")).unwrap();
+        let second = fs::read_to_string(out_dir.join("a-2.eml")).unwrap();
+        assert!(first.ends_with("first"));
+        assert!(second.ends_with("second"));
+
+        let manifest = fs::read_to_string(out_dir.join("manifest.json")).unwrap();
+        let lines: Vec<&str> = manifest.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].contains("a.dat") && lines[1].contains("a.eml") && lines[1].contains("\"status\":\"ok\""));
+        assert!(lines[2].contains("a.tnef") && lines[2].contains("a-2.eml") && lines[2].contains("\"status\":\"ok\""));
+        assert!(lines[3].contains("b.dat") && lines[3].contains("\"output\":null,\"status\":\"error\""));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn transcribes_bodies_in_their_declared_charset() {
+        let headers = b"Subject: hi\r\nContent-Type: text/html; charset=iso-8859-1\r\n\r\n";
+        let data = message(vec![
+            (TnefAttributeId::OemCodepage, vec![0xE4, 0x04, 0x00, 0x00]),
+            (TnefAttributeId::MsgProps, property_list(&[
+                (PT_STRING8, 0x007D, headers),
+                (PT_STRING8, 0x1013, b"<p>Gr\xFC\xDFe</p>"),
+            ])),
+        ]);
+        let options = Options { text_transcript: true, ..test_options() };
+
+        let (eml, text) = convert("transcript", &data, &options);
+        let eml = String::from_utf8(eml).unwrap();
+        assert!(eml.contains("\r\nContent-Type: text/html; charset=iso-8859-1\r\n"));
+        assert!(eml.contains("\r\nContent-Transfer-Encoding: quoted-printable\r\n\r\n<p>Gr=FC=DFe</p>"));
+        assert_eq!(String::from_utf8(text.unwrap()).unwrap(), "Gr\u{FC}\u{DF}e\n");
+    }
+
+    #[test]
+    fn writes_display_name_recipients_as_groups() {
+        let data = message(vec![(TnefAttributeId::MsgProps, property_list(&[
+            (PT_STRING8, 0x0E04, b"Alice Example; Bob\0"),
+            (PT_STRING8, 0x1000, b"hi"),
+        ]))]);
+
+        let (eml, _text) = convert("display-to", &data, &test_options());
+        let eml = String::from_utf8(eml).unwrap();
+        assert!(eml.contains("\r\nTo: Alice Example: ;, Bob: ;\r\n"));
+    }
+
+    #[test]
+    fn builds_smime_emails() {
+        let smime_attachment = |name: &str, data: &[u8]| Attachment {
+            data: Some(data.to_vec()),
+            file_name: Some(name.to_owned()),
+            ..Attachment::default()
+        };
+        let headers = "Subject: signed\r\nContent-Type: text/plain\r\n\r\n";
+
+        // opaque signed: ContentInfo with the signed-data content type
+        let signed_data = [0x30, 0x80, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x02, 0xA0, 0x80];
+        let email = build_smime_email(headers, "IPM.Note.SMIME", &[smime_attachment("smime.p7m", &signed_data)]).unwrap();
+        let email = String::from_utf8(email).unwrap();
+        assert!(email.starts_with("Subject: signed\r\nMIME-Version: 1.0\r\n"));
+        assert!(email.contains("\r\nContent-Type: application/pkcs7-mime; smime-type=signed-data; name=\"smime.p7m\"\r\n"));
+        assert!(email.ends_with(&format!("\r\n\r\n{}", base64_encode(&signed_data))));
+
+        let enveloped_data = [0x30, 0x82, 0x01, 0x00, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x03];
+        let email = build_smime_email(headers, "IPM.Note.SMIME", &[smime_attachment("smime.p7m", &enveloped_data)]).unwrap();
+        assert!(String::from_utf8(email).unwrap().contains("; smime-type=enveloped-data; "));
+
+        // without a recognizable content type, no smime-type is claimed
+        let email = build_smime_email(headers, "IPM.Note.SMIME", &[smime_attachment("smime.p7m", b"garbage")]).unwrap();
+        assert!(String::from_utf8(email).unwrap().contains("\r\nContent-Type: application/pkcs7-mime; name=\"smime.p7m\"\r\n"));
+
+        // clear-signed messages carry their own multipart/signed entity
+        let entity = b"Content-Type: multipart/signed; boundary=b\r\n\r\n--b--\r\n";
+        let email = build_smime_email(headers, "IPM.Note.SMIME.MultipartSigned", &[smime_attachment("SMIME.P7M", entity)]).unwrap();
+        assert_eq!(email, [&b"Subject: signed\r\nMIME-Version: 1.0\r\n"[..], entity].concat());
+
+        assert_eq!(build_smime_email(headers, "IPM.Note.SMIME", &[]), None);
+    }
+
+    #[test]
+    fn decodes_strings_with_a_later_codepage() {
+        let data = message(vec![
+            (TnefAttributeId::MsgProps, property_list(&[
+                (PT_STRING8, 0x0037, b"Gr\xFC\xDFe\0"),
+                (PT_STRING8, 0x1000, b"sch\xF6n"),
+            ])),
+            (TnefAttributeId::OemCodepage, vec![0xE4, 0x04, 0x00, 0x00]),
+        ]);
+        let options = Options { text_transcript: true, ..test_options() };
+
+        let (eml, text) = convert("late-codepage", &data, &options);
+        let eml = String::from_utf8(eml).unwrap();
+        assert!(eml.contains(&format!("\r\nSubject: {}\r\n", encode_unstructured("Gr\u{FC}\u{DF}e"))));
+        assert!(eml.ends_with("\r\nContent-Transfer-Encoding: quoted-printable\r\n\r\nsch=C3=B6n"));
+        assert_eq!(String::from_utf8(text.unwrap()).unwrap(), "sch\u{F6}n\n");
+    }
+
+    #[test]
+    fn adds_time_headers() {
+        const PT_SYSTIME: u16 = 0x0040;
+        let created = 116_444_736_000_000_000i64.to_le_bytes();
+        let modified = 133_536_855_070_000_000i64.to_le_bytes();
+        let data = message(vec![(TnefAttributeId::MsgProps, property_list(&[
+            (PT_SYSTIME, 0x3007, &created),
+            (PT_SYSTIME, 0x3008, &modified),
+            (PT_STRING8, 0x1000, b"hi"),
+        ]))]);
+
+        let (eml, _text) = convert("time-headers-off", &data, &test_options());
+        assert!(!String::from_utf8(eml).unwrap().contains("X-Creation-Time"));
+
+        let options = Options { time_headers: true, ..test_options() };
+        let (eml, _text) = convert("time-headers", &data, &options);
+        let eml = String::from_utf8(eml).unwrap();
+        assert!(eml.contains("\r\nX-Creation-Time: Thu, 01 Jan 1970 00:00:00 +0000\r\n"));
+        assert!(eml.contains("\r\nX-Last-Modification-Time: Thu, 29 Feb 2024 13:05:07 +0000\r\n"));
+    }
+
+    #[test]
+    fn checks_declared_attachment_sizes() {
+        let attachment = |data: Option<&[u8]>, declared_size: Option<i32>| Attachment {
+            data: data.map(|d| d.to_vec()),
+            declared_size,
+            ..Attachment::default()
+        };
+
+        assert_eq!(attachment_size_problem(0, &attachment(Some(b"12345"), Some(200))), None);
+        assert_eq!(attachment_size_problem(0, &attachment(Some(b"12345"), Some(5))), None);
+        assert_eq!(
+            attachment_size_problem(1, &attachment(Some(b"12345"), Some(4))).as_deref(),
+            Some("attachment 1 declares a size of 4 bytes but contains 5 bytes of data"),
+        );
+        assert_eq!(attachment_size_problem(0, &attachment(None, Some(4))), None);
+        assert_eq!(attachment_size_problem(0, &attachment(Some(b"12345"), None)), None);
+    }
+
+    #[test]
+    fn converts_filetimes_to_system_times() {
+        assert_eq!(filetime_to_system_time(116_444_736_000_000_000), Some(SystemTime::UNIX_EPOCH));
+        assert_eq!(
+            filetime_to_system_time(133_536_855_070_000_001),
+            Some(SystemTime::UNIX_EPOCH + Duration::new(1_709_211_907, 100)),
+        );
+
+        // times before 1970 are not supported
+        assert_eq!(filetime_to_system_time(116_444_735_999_999_999), None);
+        assert_eq!(filetime_to_system_time(i64::MIN), None);
+    }
+}
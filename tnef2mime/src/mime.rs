@@ -0,0 +1,483 @@
+use crate::datetime::UtcDateTime;
+
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_LINE_LENGTH: usize = 76;
+const QUOTED_PRINTABLE_LINE_LENGTH: usize = 76;
+pub const DEFAULT_HEADER_LINE_LENGTH: usize = 78;
+
+
+pub fn base64_encode_line(bytes: &[u8]) -> String {
+    let mut ret = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+
+        ret.push(BASE64_ALPHABET[usize::from(b0 >> 2)].into());
+        ret.push(BASE64_ALPHABET[usize::from(((b0 & 0x03) << 4) | (b1 >> 4))].into());
+        if chunk.len() > 1 {
+            ret.push(BASE64_ALPHABET[usize::from(((b1 & 0x0F) << 2) | (b2 >> 6))].into());
+        } else {
+            ret.push('=');
+        }
+        if chunk.len() > 2 {
+            ret.push(BASE64_ALPHABET[usize::from(b2 & 0x3F)].into());
+        } else {
+            ret.push('=');
+        }
+    }
+    ret
+}
+
+
+pub fn base64_decode_line(text: &str) -> Option<Vec<u8>> {
+    // the inverse of base64_encode_line; anything else (including line breaks) is rejected
+    let chunks = text.as_bytes().chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+    let chunk_count = chunks.len();
+    let mut ret = Vec::with_capacity(chunk_count * 3);
+    for (chunk_index, chunk) in chunks.enumerate() {
+        let padding = chunk.iter().rev().take_while(|&&b| b == b'=').count();
+        if padding > 2 || (padding > 0 && chunk_index != chunk_count - 1) {
+            return None;
+        }
+        let mut sextets = [0u8; 4];
+        for (sextet, b) in sextets.iter_mut().zip(&chunk[..4 - padding]) {
+            *sextet = u8::try_from(BASE64_ALPHABET.iter().position(|a| a == b)?).unwrap();
+        }
+        ret.push((sextets[0] << 2) | (sextets[1] >> 4));
+        if padding < 2 {
+            ret.push((sextets[1] << 4) | (sextets[2] >> 2));
+        }
+        if padding < 1 {
+            ret.push((sextets[2] << 6) | sextets[3]);
+        }
+    }
+    Some(ret)
+}
+
+
+pub fn base64_encode(bytes: &[u8]) -> String {
+    // 57 input bytes become 76 output characters
+    let line_input_length = BASE64_LINE_LENGTH / 4 * 3;
+    let mut ret = String::with_capacity(bytes.len().div_ceil(3) * 4 + bytes.len() / line_input_length * 2 + 2);
+    for line_bytes in bytes.chunks(line_input_length) {
+        ret.push_str(&base64_encode_line(line_bytes));
+        ret.push_str("\r\n");
+    }
+    ret
+}
+
+
+pub fn quoted_printable_encode(bytes: &[u8]) -> String {
+    // the bytes are in whatever charset the body declares
+    let mut ret = String::with_capacity(bytes.len() + bytes.len() / 8);
+    let mut lines = bytes.split(|&b| b == b'\n').peekable();
+    while let Some(line) = lines.next() {
+        let line_bytes = line.strip_suffix(b"\r").unwrap_or(line);
+        let mut line_length = 0;
+        for (i, &b) in line_bytes.iter().enumerate() {
+            // trailing whitespace would be stripped in transit
+            let is_last = i == line_bytes.len() - 1;
+            let literal = (b'!'..=b'~').contains(&b) && b != b'='
+                || (b == b' ' || b == b'\t') && !is_last;
+            let token = if literal {
+                char::from(b).to_string()
+            } else {
+                format!("={:02X}", b)
+            };
+
+            // keep room for the '=' of a soft line break, unless this is the end of the line
+            let limit = if is_last { QUOTED_PRINTABLE_LINE_LENGTH } else { QUOTED_PRINTABLE_LINE_LENGTH - 1 };
+            if line_length + token.len() > limit {
+                ret.push_str("=\r\n");
+                line_length = 0;
+            }
+            ret.push_str(&token);
+            line_length += token.len();
+        }
+        if lines.peek().is_some() {
+            ret.push_str("\r\n");
+        }
+    }
+    ret
+}
+
+
+pub fn has_header_field(headers: &str, name: &str) -> bool {
+    headers.split('\n')
+        .any(|line| {
+            let line_bytes = line.as_bytes();
+            line_bytes.len() > name.len()
+                && line_bytes[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                && line_bytes[name.len()] == b':'
+        })
+}
+
+
+pub fn fold_header_field(name: &str, value: &str, max_line_length: usize) -> String {
+    // only fold at existing whitespace (after commas, between words or encoded-words),
+    // keeping the whitespace itself; a word longer than the limit stays on an overlong line
+    let mut ret = String::with_capacity(name.len() + 2 + value.len() + 2);
+    ret.push_str(name);
+    ret.push(':');
+    let mut line_length = ret.len();
+    let mut rest = value;
+    while !rest.is_empty() {
+        let after_space = rest.trim_start_matches([' ', '\t']);
+        let mut space = &rest[..rest.len() - after_space.len()];
+        let word_length = after_space.find([' ', '\t']).unwrap_or(after_space.len());
+        let (word, remaining) = after_space.split_at(word_length);
+        if line_length == name.len() + 1 && space.is_empty() {
+            // separate the value from the colon
+            space = " ";
+        }
+
+        // folding right after the colon is allowed too, but trailing whitespace
+        // must not end up on a continuation line of its own
+        if !space.is_empty() && !word.is_empty() && line_length + space.len() + word.len() > max_line_length {
+            ret.push_str("\r\n");
+            line_length = 0;
+        }
+        ret.push_str(space);
+        ret.push_str(word);
+        line_length += space.len() + word.len();
+        rest = remaining;
+    }
+    ret.push_str("\r\n");
+    ret
+}
+
+
+pub fn header_field_value(headers: &str, name: &str) -> Option<String> {
+    let mut value: Option<String> = None;
+    for line in headers.split('\n') {
+        let line = line.trim_end_matches('\r');
+        if line.is_empty() {
+            // end of header
+            break;
+        }
+        let is_continuation = line.starts_with(' ') || line.starts_with('\t');
+        if let Some(v) = value.as_mut() {
+            if !is_continuation {
+                break;
+            }
+            // unfold
+            v.push_str(line);
+            continue;
+        }
+        let line_bytes = line.as_bytes();
+        let matches_name = line_bytes.len() > name.len()
+            && line_bytes[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+            && line_bytes[name.len()] == b':';
+        if matches_name {
+            value = Some(line[name.len()+1..].to_owned());
+        }
+    }
+    value.map(|v| v.trim().to_owned())
+}
+
+
+pub fn header_parameter(field_value: &str, parameter: &str) -> Option<String> {
+    field_value.split(';')
+        .skip(1)
+        .filter_map(|p| p.split_once('='))
+        .find(|(key, _value)| key.trim().eq_ignore_ascii_case(parameter))
+        .map(|(_key, value)| value.trim().trim_matches('"').to_owned())
+}
+
+
+pub fn replace_content_type(headers: &str, content_type: &str) -> String {
+    let mut ret = remove_header_fields(headers, &["Content-Type", "Content-Transfer-Encoding"]);
+    ret.push_str("Content-Type: ");
+    ret.push_str(content_type);
+    ret.push_str("\r\n");
+    ret.push_str("Content-Transfer-Encoding: 8bit\r\n");
+    ret.push_str("\r\n");
+    ret
+}
+
+
+pub fn add_header_field(headers: &str, name: &str, value: &str, max_line_length: usize) -> String {
+    let mut ret = remove_header_fields(headers, &[]);
+    ret.push_str(&fold_header_field(name, value, max_line_length));
+    ret.push_str("\r\n");
+    ret
+}
+
+
+pub fn format_filetime(filetime: i64) -> Option<String> {
+    UtcDateTime::from_filetime(filetime)
+        .map(|dt| format_date_time(&dt))
+}
+
+
+pub fn format_date_time(dt: &UtcDateTime) -> String {
+    const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} +0000",
+        WEEKDAYS[usize::from(dt.weekday)],
+        dt.day,
+        MONTHS[usize::from(dt.month - 1)],
+        dt.year,
+        dt.hour,
+        dt.minute,
+        dt.second,
+    )
+}
+
+
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+
+fn encoded_word_chunks(text: &str) -> Vec<&str> {
+    // "=?UTF-8?B?" and "?=" leave 63 of 75 characters, i.e. 45 bytes of base64-encoded text;
+    // chunks end at character boundaries so that each encoded-word is valid UTF-8 on its own
+    const MAX_CHUNK_BYTES: usize = 45;
+
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(MAX_CHUNK_BYTES);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, remaining) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = remaining;
+    }
+    chunks
+}
+
+
+fn encode_words(text: &str) -> String {
+    // adjacent encoded-words are joined by whitespace, which decoders drop
+    encoded_word_chunks(text).iter()
+        .map(|chunk| format!("=?UTF-8?B?{}?=", base64_encode_line(chunk.as_bytes())))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+
+pub fn encode_phrase(phrase: &str) -> String {
+    if !phrase.is_ascii() {
+        encode_words(phrase)
+    } else if phrase.chars().all(|c| is_atext(c) || c == ' ') {
+        phrase.to_owned()
+    } else {
+        let mut ret = String::with_capacity(phrase.len() + 2);
+        ret.push('"');
+        for c in phrase.chars() {
+            if c == '"' || c == '\\' {
+                ret.push('\\');
+            }
+            ret.push(c);
+        }
+        ret.push('"');
+        ret
+    }
+}
+
+
+pub fn encode_unstructured(text: &str) -> String {
+    if text.is_ascii() {
+        text.to_owned()
+    } else {
+        encode_words(text)
+    }
+}
+
+
+pub fn format_address(display_name: Option<&str>, address: Option<&str>) -> Option<String> {
+    let display_name = display_name.filter(|dn| !dn.is_empty());
+    let address = address.filter(|a| !a.is_empty());
+    match (display_name, address) {
+        (Some(dn), Some(a)) => Some(format!("{} <{}>", encode_phrase(dn), a)),
+        // a bare display name is not a valid address; an empty group keeps the name
+        (Some(dn), None) => Some(format!("{}: ;", encode_phrase(dn))),
+        (None, Some(a)) => Some(a.to_owned()),
+        (None, None) => None,
+    }
+}
+
+
+pub fn normalize_header_block(headers: &str) -> String {
+    // producers may use bare LF (or CR); the header block must end with exactly one empty line
+    let mut ret = String::with_capacity(headers.len() + 4);
+    for line in headers.trim_end_matches(['\r', '\n']).split("\r\n").flat_map(|l| l.split(['\r', '\n'])) {
+        ret.push_str(line);
+        ret.push_str("\r\n");
+    }
+    ret.push_str("\r\n");
+    ret
+}
+
+
+pub fn remove_header_fields(headers: &str, names: &[&str]) -> String {
+    // the result does not end with the empty line separating headers from body
+    // to allow appending further fields
+    let mut ret = String::with_capacity(headers.len());
+    let mut skipping = false;
+    for line in headers.trim_end_matches(['\r', '\n']).split_inclusive('\n') {
+        let is_continuation = line.starts_with(' ') || line.starts_with('\t');
+        if !is_continuation {
+            skipping = names.iter().any(|name| {
+                let line_bytes = line.as_bytes();
+                line_bytes.len() > name.len()
+                    && line_bytes[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+                    && line_bytes[name.len()] == b':'
+            });
+        }
+        if !skipping {
+            ret.push_str(line);
+        }
+    }
+    if !ret.is_empty() && !ret.ends_with('\n') {
+        ret.push_str("\r\n");
+    }
+    ret
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_printable_escapes_equals_and_8bit() {
+        assert_eq!(quoted_printable_encode(b"a=b"), "a=3Db");
+        assert_eq!(quoted_printable_encode("Gr\u{FC}\u{DF}e".as_bytes()), "Gr=C3=BC=C3=9Fe");
+        assert_eq!(quoted_printable_encode(b"Gr\xFC\xDFe"), "Gr=FC=DFe");
+        assert_eq!(quoted_printable_encode(b"one\ntwo\r\nthree"), "one\r\ntwo\r\nthree");
+    }
+
+    #[test]
+    fn quoted_printable_encodes_trailing_whitespace() {
+        assert_eq!(quoted_printable_encode(b"end \r\nnext\t"), "end=20\r\nnext=09");
+        assert_eq!(quoted_printable_encode(b"a b"), "a b");
+    }
+
+    #[test]
+    fn quoted_printable_breaks_long_lines() {
+        let encoded = quoted_printable_encode("x".repeat(100).as_bytes());
+        assert_eq!(encoded, format!("{}=\r\n{}", "x".repeat(75), "x".repeat(25)));
+
+        // a line of exactly 76 characters needs no soft line break
+        assert_eq!(quoted_printable_encode("x".repeat(76).as_bytes()), "x".repeat(76));
+
+        // encoded octets are not split across lines
+        let encoded = quoted_printable_encode(format!("{}\u{E9}", "x".repeat(74)).as_bytes());
+        assert_eq!(encoded, format!("{}=\r\n=C3=A9", "x".repeat(74)));
+        assert!(encoded.split("\r\n").all(|line| line.len() <= QUOTED_PRINTABLE_LINE_LENGTH));
+    }
+
+    #[test]
+    fn folding_keeps_whitespace() {
+        assert_eq!(fold_header_field("X-Test", "a  b\tc", 78), "X-Test: a  b\tc\r\n");
+        assert_eq!(fold_header_field("X-Test", " leading", 78), "X-Test: leading\r\n");
+        assert_eq!(fold_header_field("X-Test", "", 78), "X-Test:\r\n");
+    }
+
+    #[test]
+    fn never_folds_onto_whitespace_only_lines() {
+        let value = format!("{} {}   \t ", "a".repeat(30), "b".repeat(40));
+        let folded = fold_header_field("X-Test", &value, 78);
+        let lines: Vec<&str> = folded.strip_suffix("\r\n").unwrap().split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| !line.trim().is_empty()));
+        assert_eq!(lines.concat(), format!("X-Test: {}", value));
+
+        let folded = fold_header_field("X-Test", &format!("{}      ", "c".repeat(70)), 78);
+        assert_eq!(folded, format!("X-Test: {}      \r\n", "c".repeat(70)));
+    }
+
+    #[test]
+    fn folds_long_recipient_lists() {
+        let recipients: Vec<String> = (0..10)
+            .map(|i| format!("Recipient Number {} <recipient{}@example.com>", i, i))
+            .collect();
+        let value = recipients.join(", ");
+        let folded = fold_header_field("To", &value, 78);
+
+        let lines: Vec<&str> = folded.strip_suffix("\r\n").unwrap().split("\r\n").collect();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| line.len() <= 78));
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+
+        // unfolding restores the original value
+        assert_eq!(lines.concat(), format!("To: {}", value));
+    }
+
+    #[test]
+    fn splits_long_encoded_text_into_words() {
+        let subject = "Gr\u{FC}\u{DF}e aus K\u{F6}ln, \u{1F600} ".repeat(8);
+        let chunks = encoded_word_chunks(&subject);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 45));
+        assert_eq!(chunks.concat(), subject);
+
+        let encoded = encode_unstructured(&subject);
+        let words: Vec<&str> = encoded.split(' ').collect();
+        assert_eq!(words.len(), chunks.len());
+        assert!(words.iter().all(|word| word.len() <= 75 && word.starts_with("=?UTF-8?B?") && word.ends_with("?=")));
+
+        let folded = fold_header_field("Subject", &encoded, 78);
+        assert!(folded.split("\r\n").all(|line| line.len() <= 78));
+
+        assert_eq!(encode_unstructured("plain"), "plain");
+        assert_eq!(encode_phrase("M\u{FC}ller"), "=?UTF-8?B?TcO8bGxlcg==?=");
+    }
+
+    #[test]
+    fn formats_addresses() {
+        assert_eq!(format_address(Some("Alice Example"), Some("alice@example.com")).as_deref(), Some("Alice Example <alice@example.com>"));
+        assert_eq!(format_address(None, Some("alice@example.com")).as_deref(), Some("alice@example.com"));
+        assert_eq!(format_address(Some("M\u{FC}ller"), None).as_deref(), Some("=?UTF-8?B?TcO8bGxlcg==?=: ;"));
+        assert_eq!(format_address(Some(""), None), None);
+    }
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize_header_block("A: 1\nB: 2\n"), "A: 1\r\nB: 2\r\n\r\n");
+        assert_eq!(normalize_header_block("A: 1\rB: 2"), "A: 1\r\nB: 2\r\n\r\n");
+        assert_eq!(normalize_header_block("A: 1\r\n folded\nB: 2\r\n\r\n\r\n"), "A: 1\r\n folded\r\nB: 2\r\n\r\n");
+    }
+
+    #[test]
+    fn formats_filetimes_as_rfc5322_dates() {
+        assert_eq!(format_filetime(133_536_855_070_000_000).as_deref(), Some("Thu, 29 Feb 2024 13:05:07 +0000"));
+        assert_eq!(format_filetime(116_444_736_000_000_000).as_deref(), Some("Thu, 01 Jan 1970 00:00:00 +0000"));
+        assert_eq!(format_filetime(-1), None);
+    }
+
+    #[test]
+    fn encodes_base64_lines() {
+        // RFC 4648 section 10
+        let vectors = [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")];
+        for (plain, encoded) in vectors {
+            assert_eq!(base64_encode_line(plain.as_bytes()), encoded);
+            assert_eq!(base64_decode_line(encoded).unwrap(), plain.as_bytes());
+        }
+        for invalid in ["Zg=", "Zg==Zg==", "Z===", "Zm9v\r\n", "Zm9-"] {
+            assert_eq!(base64_decode_line(invalid), None);
+        }
+    }
+
+    #[test]
+    fn adds_thread_index_on_one_line() {
+        // a 22-byte conversation index header
+        let conversation_index: Vec<u8> = (0..22).collect();
+        let thread_index = base64_encode_line(&conversation_index);
+        assert_eq!(thread_index, "AAECAwQFBgcICQoLDA0ODxAREhMUFQ==");
+
+        let headers = add_header_field("Subject: Hi\r\n\r\n", "Thread-Index", &thread_index, DEFAULT_HEADER_LINE_LENGTH);
+        assert_eq!(headers, "Subject: Hi\r\nThread-Index: AAECAwQFBgcICQoLDA0ODxAREhMUFQ==\r\n\r\n");
+    }
+}
@@ -0,0 +1,459 @@
+use std::fmt;
+use std::io::{self, Cursor};
+
+use codepage::to_encoding;
+use encoding_rs::{Encoding, WINDOWS_1252};
+
+use crate::binread::BinaryReader;
+
+
+const COMPRESSION_TYPE_COMPRESSED: u32 = 0x75465A4C; // "LZFu"
+const COMPRESSION_TYPE_UNCOMPRESSED: u32 = 0x414C454D; // "MELA"
+const DICTIONARY_SIZE: usize = 4096;
+const INITIAL_DICTIONARY: &[u8] = b"{\\rtf1\\ansi\\mac\\deff0\\deftab720{\\fonttbl;}{\\f0\\fnil \\froman \\fswiss \\fmodern \\fscript \\fdecor MS Sans SerifSymbolArialTimes New RomanCourier{\\colortbl\\red0\\green0\\blue0\r\n\\par \\pard\\plain\\f0\\fs20\\b\\i\\u\\tab\\tx";
+
+// destinations whose content is not body text
+const IGNORED_DESTINATIONS: [&str; 14] = [
+    "fonttbl", "colortbl", "stylesheet", "info", "pict", "object", "header", "footer",
+    "headerl", "headerr", "footerl", "footerr", "listtable", "listoverridetable",
+];
+
+
+#[derive(Debug)]
+pub enum RtfDecompressionError {
+    Io(io::Error),
+    UnknownCompressionType { compression_type: u32 },
+    LengthMismatch { expected: usize, obtained: usize },
+}
+impl fmt::Display for RtfDecompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e)
+                => write!(f, "I/O error: {}", e),
+            Self::UnknownCompressionType { compression_type }
+                => write!(f, "unknown RTF compression type 0x{:08X}", compression_type),
+            Self::LengthMismatch { expected, obtained }
+                => write!(f, "RTF length mismatch: expected {} bytes, obtained {}", expected, obtained),
+        }
+    }
+}
+impl std::error::Error for RtfDecompressionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::UnknownCompressionType { .. } => None,
+            Self::LengthMismatch { .. } => None,
+        }
+    }
+}
+impl From<io::Error> for RtfDecompressionError {
+    fn from(value: io::Error) -> Self { Self::Io(value) }
+}
+
+
+pub struct CompressedRtfDict {
+    dictionary: [u8; DICTIONARY_SIZE],
+    write_pos: usize,
+}
+impl CompressedRtfDict {
+    pub fn new() -> Self {
+        let mut dict = Self {
+            dictionary: [0u8; DICTIONARY_SIZE],
+            write_pos: 0,
+        };
+        dict.reset();
+        dict
+    }
+
+    pub fn reset(&mut self) {
+        self.dictionary[..INITIAL_DICTIONARY.len()].copy_from_slice(INITIAL_DICTIONARY);
+        self.dictionary[INITIAL_DICTIONARY.len()..].fill(0);
+        self.write_pos = INITIAL_DICTIONARY.len();
+    }
+
+    fn push(&mut self, b: u8) {
+        self.dictionary[self.write_pos] = b;
+        self.write_pos = (self.write_pos + 1) % DICTIONARY_SIZE;
+    }
+}
+impl Default for CompressedRtfDict {
+    fn default() -> Self { Self::new() }
+}
+
+
+pub fn decode_compressed_rtf(data: &[u8]) -> Result<Vec<u8>, RtfDecompressionError> {
+    let mut output = Vec::new();
+    let mut dict = CompressedRtfDict::new();
+    decode_compressed_rtf_into(data, &mut output, &mut dict)?;
+    Ok(output)
+}
+
+
+pub fn decode_compressed_rtf_into(data: &[u8], output: &mut Vec<u8>, dict: &mut CompressedRtfDict) -> Result<(), RtfDecompressionError> {
+    // both buffers keep their allocations; only their contents are replaced
+    output.clear();
+    dict.reset();
+
+    let mut reader = Cursor::new(data);
+    let compressed_size: usize = reader.read_u32_le()?.try_into().unwrap();
+    let raw_size: usize = reader.read_u32_le()?.try_into().unwrap();
+    let compression_type = reader.read_u32_le()?;
+    let _crc = reader.read_u32_le()?;
+
+    if compression_type == COMPRESSION_TYPE_UNCOMPRESSED {
+        // the compressed size counts the three header fields following it;
+        // anything stored beyond it is padding
+        let stored_size = compressed_size.saturating_sub(12);
+        let header_size: usize = reader.position().try_into().unwrap();
+        let available = &data[header_size..];
+        let stored = &available[..stored_size.min(available.len())];
+
+        // the payload itself is as long as the raw size
+        if stored.len() < raw_size {
+            return Err(RtfDecompressionError::LengthMismatch { expected: raw_size, obtained: stored.len() });
+        }
+        output.extend_from_slice(&stored[..raw_size]);
+        return Ok(());
+    } else if compression_type != COMPRESSION_TYPE_COMPRESSED {
+        return Err(RtfDecompressionError::UnknownCompressionType { compression_type });
+    }
+
+    output.reserve(raw_size);
+    'decoding: loop {
+        let control = match reader.read_u8() {
+            Ok(c) => c,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.into()),
+        };
+        for bit in 0..8 {
+            if control & (1 << bit) == 0 {
+                // literal
+                let b = match reader.read_u8() {
+                    Ok(b) => b,
+                    Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break 'decoding,
+                    Err(e) => return Err(e.into()),
+                };
+                output.push(b);
+                dict.push(b);
+            } else {
+                // dictionary reference: 12 bits offset, 4 bits length
+                let reference = match reader.read_u16_be() {
+                    Ok(r) => r,
+                    Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break 'decoding,
+                    Err(e) => return Err(e.into()),
+                };
+                let offset = usize::from(reference >> 4);
+                let length = usize::from(reference & 0x000F) + 2;
+                if offset == dict.write_pos {
+                    // end of stream
+                    break 'decoding;
+                }
+                for i in 0..length {
+                    let b = dict.dictionary[(offset + i) % DICTIONARY_SIZE];
+                    output.push(b);
+                    dict.push(b);
+                }
+            }
+        }
+    }
+    if output.len() != raw_size {
+        return Err(RtfDecompressionError::LengthMismatch { expected: raw_size, obtained: output.len() });
+    }
+    Ok(())
+}
+
+
+struct GroupState {
+    ignored: bool,
+    unicode_skip: usize,
+}
+
+
+fn flush_bytes(pending: &mut Vec<u8>, encoding: &'static Encoding, text: &mut String) {
+    if !pending.is_empty() {
+        let (decoded, _bad_sequences) = encoding.decode_without_bom_handling(pending);
+        text.push_str(&decoded);
+        pending.clear();
+    }
+}
+
+
+pub fn rtf_to_text(rtf: &[u8]) -> String {
+    let mut text = String::new();
+    let mut pending_bytes: Vec<u8> = Vec::new();
+    let mut encoding: &'static Encoding = WINDOWS_1252;
+    let mut groups = vec![GroupState { ignored: false, unicode_skip: 1 }];
+    let mut skip_count = 0usize;
+    let mut i = 0;
+
+    while i < rtf.len() {
+        let state = groups.last().unwrap();
+        let ignored = state.ignored;
+        match rtf[i] {
+            b'{' => {
+                let new_state = GroupState { ignored, unicode_skip: state.unicode_skip };
+                groups.push(new_state);
+                skip_count = 0;
+                i += 1;
+            },
+            b'}' => {
+                if groups.len() > 1 {
+                    groups.pop();
+                }
+                skip_count = 0;
+                i += 1;
+            },
+            b'\r' | b'\n' => {
+                i += 1;
+            },
+            b'\\' => {
+                i += 1;
+                if i >= rtf.len() {
+                    break;
+                }
+                let c = rtf[i];
+                if c.is_ascii_alphabetic() {
+                    let word_start = i;
+                    while i < rtf.len() && rtf[i].is_ascii_alphabetic() {
+                        i += 1;
+                    }
+                    let word = std::str::from_utf8(&rtf[word_start..i]).unwrap();
+                    let param_start = i;
+                    if i < rtf.len() && rtf[i] == b'-' {
+                        i += 1;
+                    }
+                    while i < rtf.len() && rtf[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    let param: Option<i32> = std::str::from_utf8(&rtf[param_start..i]).unwrap()
+                        .parse().ok();
+                    if i < rtf.len() && rtf[i] == b' ' {
+                        // the delimiting space belongs to the control word
+                        i += 1;
+                    }
+
+                    if IGNORED_DESTINATIONS.contains(&word) {
+                        groups.last_mut().unwrap().ignored = true;
+                        continue;
+                    }
+                    if word == "ansicpg" {
+                        if let Some(cp) = param.and_then(|p| u16::try_from(p).ok()).and_then(to_encoding) {
+                            encoding = cp;
+                        }
+                        continue;
+                    }
+                    if word == "uc" {
+                        if let Some(n) = param.and_then(|p| usize::try_from(p).ok()) {
+                            groups.last_mut().unwrap().unicode_skip = n;
+                        }
+                        continue;
+                    }
+                    if ignored {
+                        continue;
+                    }
+                    if skip_count > 0 {
+                        skip_count -= 1;
+                        continue;
+                    }
+                    match word {
+                        "par" | "line" => {
+                            flush_bytes(&mut pending_bytes, encoding, &mut text);
+                            text.push('\n');
+                        },
+                        "tab" => {
+                            flush_bytes(&mut pending_bytes, encoding, &mut text);
+                            text.push('\t');
+                        },
+                        "u" => {
+                            flush_bytes(&mut pending_bytes, encoding, &mut text);
+                            if let Some(p) = param {
+                                // negative values represent code units above 0x7FFF
+                                let unit = if p < 0 { p + 0x10000 } else { p };
+                                if let Some(c) = u32::try_from(unit).ok().and_then(char::from_u32) {
+                                    text.push(c);
+                                }
+                            }
+                            skip_count = groups.last().unwrap().unicode_skip;
+                        },
+                        _ => {},
+                    }
+                } else if c == b'\'' {
+                    let hex = rtf.get(i+1..i+3)
+                        .and_then(|h| std::str::from_utf8(h).ok())
+                        .and_then(|h| u8::from_str_radix(h, 16).ok());
+                    i += 3;
+                    if ignored {
+                        continue;
+                    }
+                    if skip_count > 0 {
+                        skip_count -= 1;
+                        continue;
+                    }
+                    if let Some(b) = hex {
+                        pending_bytes.push(b);
+                    }
+                } else {
+                    i += 1;
+                    if c == b'*' {
+                        // unknown destinations are marked with \*
+                        groups.last_mut().unwrap().ignored = true;
+                        continue;
+                    }
+                    if ignored {
+                        continue;
+                    }
+                    if skip_count > 0 {
+                        skip_count -= 1;
+                        continue;
+                    }
+                    match c {
+                        b'\\' | b'{' | b'}' => pending_bytes.push(c),
+                        b'~' => {
+                            flush_bytes(&mut pending_bytes, encoding, &mut text);
+                            text.push('\u{A0}');
+                        },
+                        b'_' => pending_bytes.push(b'-'),
+                        b'\r' | b'\n' => {
+                            flush_bytes(&mut pending_bytes, encoding, &mut text);
+                            text.push('\n');
+                        },
+                        _ => {},
+                    }
+                }
+            },
+            b => {
+                i += 1;
+                if ignored {
+                    continue;
+                }
+                if skip_count > 0 {
+                    skip_count -= 1;
+                    continue;
+                }
+                pending_bytes.push(b);
+            },
+        }
+    }
+    flush_bytes(&mut pending_bytes, encoding, &mut text);
+    text
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_text_from_rtf() {
+        let rtf = b"{\\rtf1\\ansi\\ansicpg1251{\\fonttbl{\\f0 Arial;}}{\\*\\generator Foo;}\\f0 Hello \\'e9\\par\\uc1\\u8364?x\\tab y\\}}";
+        assert_eq!(rtf_to_text(rtf), "Hello \u{439}\n\u{20AC}x\ty}");
+    }
+
+    #[test]
+    fn skips_unicode_fallbacks() {
+        let rtf = b"{\\rtf1\\uc2\\u-3971\\'3f\\'3f and\\~more}";
+        assert_eq!(rtf_to_text(rtf), "\u{F07D} and\u{A0}more");
+    }
+
+    // the examples of [MS-OXRTFCP] section 4
+    const SIMPLE_COMPRESSED: [u8; 49] = [
+        0x2D, 0x00, 0x00, 0x00, 0x2B, 0x00, 0x00, 0x00, 0x4C, 0x5A, 0x46, 0x75, 0xF1, 0xC5, 0xC7, 0xA7,
+        0x03, 0x00, 0x0A, 0x00, 0x72, 0x63, 0x70, 0x67, 0x31, 0x32, 0x35, 0x42, 0x32, 0x0A, 0xF3, 0x20,
+        0x68, 0x65, 0x6C, 0x09, 0x00, 0x20, 0x62, 0x77, 0x05, 0xB0, 0x6C, 0x64, 0x7D, 0x0A, 0x80, 0x0F,
+        0xA0,
+    ];
+    const SIMPLE_RAW: &[u8] = b"{\\rtf1\\ansi\\ansicpg1252\\pard hello world}\r\n";
+    const RUN_COMPRESSED: [u8; 30] = [
+        0x1A, 0x00, 0x00, 0x00, 0x1C, 0x00, 0x00, 0x00, 0x4C, 0x5A, 0x46, 0x75, 0xE2, 0xD4, 0x4B, 0x51,
+        0x41, 0x00, 0x04, 0x20, 0x57, 0x58, 0x59, 0x5A, 0x0D, 0x6E, 0x7D, 0x01, 0x0E, 0xB0,
+    ];
+    const RUN_RAW: &[u8] = b"{\\rtf1 WXYZWXYZWXYZWXYZWXYZ}";
+
+    #[test]
+    fn decompresses_examples() {
+        assert_eq!(decode_compressed_rtf(&SIMPLE_COMPRESSED).unwrap(), SIMPLE_RAW);
+        assert_eq!(decode_compressed_rtf(&RUN_COMPRESSED).unwrap(), RUN_RAW);
+    }
+
+    #[test]
+    fn wraps_around_the_dictionary() {
+        // literals fill the dictionary up to its end (the initial contents take the first bytes),
+        // then a reference reads across the boundary while also writing across it
+        let literal_count = DICTIONARY_SIZE - INITIAL_DICTIONARY.len();
+        let literals: Vec<u8> = (0..literal_count)
+            .map(|i| b'a' + u8::try_from(i % 26).unwrap())
+            .collect();
+        let references: [u16; 2] = [
+            (0xFFE << 4) | (4 - 2),
+            // end of stream: the reference points at the write position
+            4 << 4,
+        ];
+
+        let mut payload = Vec::new();
+        let mut tokens = literals.iter().map(|&b| (false, vec![b]))
+            .chain(references.iter().map(|r| (true, r.to_be_bytes().to_vec())))
+            .peekable();
+        while tokens.peek().is_some() {
+            let mut control = 0u8;
+            let mut run = Vec::new();
+            for (bit, (is_reference, bytes)) in tokens.by_ref().take(8).enumerate() {
+                if is_reference {
+                    control |= 1 << bit;
+                }
+                run.extend_from_slice(&bytes);
+            }
+            payload.push(control);
+            payload.extend_from_slice(&run);
+        }
+
+        let mut expected = literals.clone();
+        expected.extend_from_slice(&[literals[literal_count - 2], literals[literal_count - 1]]);
+        expected.extend_from_slice(&[literals[literal_count - 2], literals[literal_count - 1]]);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&u32::try_from(payload.len() + 12).unwrap().to_le_bytes());
+        data.extend_from_slice(&u32::try_from(expected.len()).unwrap().to_le_bytes());
+        data.extend_from_slice(&COMPRESSION_TYPE_COMPRESSED.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&payload);
+
+        assert_eq!(decode_compressed_rtf(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn reuses_buffers() {
+        let mut output = Vec::new();
+        let mut dict = CompressedRtfDict::new();
+        decode_compressed_rtf_into(&SIMPLE_COMPRESSED, &mut output, &mut dict).unwrap();
+        assert_eq!(output, SIMPLE_RAW);
+
+        // neither the previous output nor the previous dictionary contents may leak through
+        decode_compressed_rtf_into(&RUN_COMPRESSED, &mut output, &mut dict).unwrap();
+        assert_eq!(output, RUN_RAW);
+    }
+
+    #[test]
+    fn handles_uncompressed_and_unknown_types() {
+        let mut uncompressed = Vec::new();
+        uncompressed.extend_from_slice(&16u32.to_le_bytes());
+        uncompressed.extend_from_slice(&3u32.to_le_bytes());
+        uncompressed.extend_from_slice(&COMPRESSION_TYPE_UNCOMPRESSED.to_le_bytes());
+        uncompressed.extend_from_slice(&0u32.to_le_bytes());
+        uncompressed.extend_from_slice(b"{}\n\0");
+        assert_eq!(decode_compressed_rtf(&uncompressed).unwrap(), b"{}\n");
+
+        let mut unknown = SIMPLE_COMPRESSED;
+        unknown[8] = 0x00;
+        assert!(matches!(
+            decode_compressed_rtf(&unknown),
+            Err(RtfDecompressionError::UnknownCompressionType { compression_type: 0x75465A00 }),
+        ));
+
+        let mut truncated = SIMPLE_COMPRESSED;
+        truncated[4] = 0x2C;
+        assert!(matches!(
+            decode_compressed_rtf(&truncated),
+            Err(RtfDecompressionError::LengthMismatch { expected: 0x2C, obtained: 0x2B }),
+        ));
+    }
+}
@@ -0,0 +1,141 @@
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::guid::Guid;
+use crate::mime::{base64_decode_line, base64_encode_line};
+use crate::tnef::{PropTag, PropType};
+
+
+// the numeric value is the only representation that also covers the Other(_) catch-alls
+impl Serialize for PropTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(u16::from(*self))
+    }
+}
+impl<'de> Deserialize<'de> for PropTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u16::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for PropType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(u16::from(*self))
+    }
+}
+impl<'de> Deserialize<'de> for PropType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u16::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for Guid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+impl<'de> Deserialize<'de> for Guid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Guid::parse_str(&s)
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"a GUID"))
+    }
+}
+
+
+pub(crate) mod base64_bytes {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&base64_encode_line(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        base64_decode_line(&s)
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"base64 data"))
+    }
+}
+
+
+pub(crate) mod base64_byte_vecs {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(values: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|v| base64_encode_line(v)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        strings.iter()
+            .map(|s| base64_decode_line(s)
+                .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(s), &"base64 data")))
+            .collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::tnef::PropValue;
+    use super::*;
+
+    // PS_PUBLIC_STRINGS {00020329-0000-0000-C000-000000000046}
+    const PUBLIC_STRINGS: Guid = Guid {
+        data1: 0x00020329,
+        data2: 0x0000,
+        data3: 0x0000,
+        data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+    };
+
+    #[test]
+    fn serializes_tags_and_types_as_numbers() {
+        assert_eq!(serde_json::to_string(&PropTag::TagSubject).unwrap(), "55");
+        assert_eq!(serde_json::from_str::<PropTag>("55").unwrap(), PropTag::TagSubject);
+        assert_eq!(serde_json::from_str::<PropTag>("32773").unwrap(), PropTag::from(0x8005));
+
+        assert_eq!(serde_json::to_string(&PropType::Binary).unwrap(), "258");
+        assert_eq!(serde_json::from_str::<PropType>("258").unwrap(), PropType::Binary);
+        assert_eq!(serde_json::from_str::<PropType>("34").unwrap(), PropType::Other(0x0022));
+        assert!(serde_json::from_str::<PropType>("65536").is_err());
+    }
+
+    #[test]
+    fn serializes_guids_as_strings() {
+        let json = serde_json::to_string(&PUBLIC_STRINGS).unwrap();
+        assert_eq!(json, "\"00020329-0000-0000-C000-000000000046\"");
+        assert_eq!(serde_json::from_str::<Guid>(&json).unwrap(), PUBLIC_STRINGS);
+        assert!(serde_json::from_str::<Guid>("\"not a GUID\"").is_err());
+    }
+
+    #[test]
+    fn round_trips_prop_values() {
+        let values = [
+            PropValue::Null,
+            PropValue::Integer32(-7),
+            PropValue::Boolean(true),
+            PropValue::String8("Gr\u{FC}\u{DF}e".to_owned()),
+            PropValue::Time(133_536_855_070_000_000),
+            PropValue::Guid(PUBLIC_STRINGS),
+            PropValue::Object(vec![0xFF]),
+            PropValue::Binary(vec![1, 2, 3]),
+            PropValue::MultipleBinary(vec![vec![], vec![0x00, 0x10]]),
+            PropValue::MultipleGuid(vec![PUBLIC_STRINGS]),
+        ];
+        for value in values {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<PropValue>(&json).unwrap(), value);
+        }
+
+        assert_eq!(serde_json::to_string(&PropValue::Binary(vec![1, 2, 3])).unwrap(), r#"{"Binary":"AQID"}"#);
+        assert_eq!(
+            serde_json::to_string(&PropValue::MultipleBinary(vec![vec![], vec![0x00, 0x10]])).unwrap(),
+            r#"{"MultipleBinary":["","ABA="]}"#,
+        );
+        assert_eq!(
+            serde_json::to_string(&PropValue::Guid(PUBLIC_STRINGS)).unwrap(),
+            r#"{"Guid":"00020329-0000-0000-C000-000000000046"}"#,
+        );
+        assert!(serde_json::from_str::<PropValue>(r#"{"Binary":"AQI"}"#).is_err());
+    }
+}
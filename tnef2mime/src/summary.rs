@@ -0,0 +1,127 @@
+use encoding_rs::Encoding;
+
+use crate::datetime::UtcDateTime;
+use crate::mime::format_address;
+use crate::tnef::{decode_ansi_string, parse_one_off_entry_id, Property, PropTag, PropValue};
+
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MessageSummary {
+    pub subject: Option<String>,
+    pub from: Option<String>,
+    pub to: Vec<String>,
+    pub sent: Option<UtcDateTime>,
+    pub body_text: Option<String>,
+    pub body_html: Option<String>,
+}
+
+
+pub fn string_property(properties: &[Property], tag: PropTag) -> Option<&str> {
+    properties.iter()
+        .filter(|p| p.tag == tag)
+        .find_map(|p| match &p.value {
+            PropValue::String8(s) | PropValue::String(s) => Some(s.trim_end_matches('\0')),
+            _ => None,
+        })
+}
+
+
+pub fn recipient_address(recipient: &[Property]) -> Option<String> {
+    let display_name = string_property(recipient, PropTag::TagDisplayName);
+
+    // PidTagEmailAddress is only usable if it is an SMTP address;
+    // legacy EX addresses (X.500 DNs) cannot appear in a header, so only the name is output
+    let smtp_address = string_property(recipient, PropTag::TagSmtpAddress)
+        .or_else(|| {
+            let address_type = string_property(recipient, PropTag::TagAddressType)?;
+            if address_type.eq_ignore_ascii_case("SMTP") {
+                string_property(recipient, PropTag::TagEmailAddress)
+            } else {
+                None
+            }
+        });
+
+    format_address(display_name, smtp_address)
+}
+
+
+pub fn sender_address(message_props: &[Property], encoding: &'static Encoding) -> Option<String> {
+    // address precedence:
+    // 1. PidTagSenderSmtpAddress
+    // 2. PidTagSenderEmailAddress if PidTagSenderAddressType is SMTP (it is an X.500 DN for EX)
+    // 3. the address in PidTagSenderEntryId if it is a one-off SMTP entry ID
+    // the display name is PidTagSenderName, falling back to the one in the one-off entry ID
+    let one_off = message_props.iter()
+        .filter(|p| p.tag == PropTag::TagSenderEntryId)
+        .find_map(|p| match &p.value {
+            PropValue::Binary(entry_id) => parse_one_off_entry_id(entry_id, encoding),
+            _ => None,
+        });
+
+    let smtp_address = string_property(message_props, PropTag::TagSenderSmtpAddress)
+        .or_else(|| {
+            let address_type = string_property(message_props, PropTag::TagSenderAddressType)?;
+            if address_type.eq_ignore_ascii_case("SMTP") {
+                string_property(message_props, PropTag::TagSenderEmailAddress)
+            } else {
+                None
+            }
+        })
+        .or_else(|| {
+            let oo = one_off.as_ref()?;
+            if oo.address_type.eq_ignore_ascii_case("SMTP") {
+                Some(oo.email_address.as_str())
+            } else {
+                None
+            }
+        });
+    let display_name = string_property(message_props, PropTag::TagSenderName)
+        .or_else(|| one_off.as_ref().map(|oo| oo.display_name.as_str()));
+
+    format_address(display_name, smtp_address)
+}
+
+
+pub fn summarize(message_props: &[Property], recipients: &[Vec<Property>], encoding: &'static Encoding) -> MessageSummary {
+    let subject = string_property(message_props, PropTag::TagSubject)
+        .map(|s| s.to_owned());
+    let from = sender_address(message_props, encoding);
+
+    // MAPI_TO = 1
+    let to = recipients.iter()
+        .filter(|r| r.iter().any(|p| p.tag == PropTag::TagRecipientType && p.value == PropValue::Integer32(1)))
+        .filter_map(|r| recipient_address(r))
+        .collect();
+
+    let time_property = |tag| message_props.iter()
+        .filter(|p| p.tag == tag)
+        .find_map(|p| match &p.value {
+            PropValue::Time(t) => Some(*t),
+            _ => None,
+        });
+    let sent = time_property(PropTag::TagClientSubmitTime)
+        .or_else(|| time_property(PropTag::TagMessageDeliveryTime))
+        .and_then(UtcDateTime::from_filetime);
+
+    let body_text = string_property(message_props, PropTag::TagBody)
+        .map(|b| b.to_owned());
+    let body_html = message_props.iter()
+        .filter(|p| p.tag == PropTag::TagBodyHtml)
+        .find_map(|p| match &p.value {
+            PropValue::String8(s) | PropValue::String(s) => Some(s.trim_end_matches('\0').to_owned()),
+            PropValue::Binary(b) => {
+                // the binary HTML body is in the message's codepage
+                Some(decode_ansi_string(b, encoding).trim_end_matches('\0').to_owned())
+            },
+            _ => None,
+        });
+
+    MessageSummary {
+        subject,
+        from,
+        to,
+        sent,
+        body_text,
+        body_html,
+    }
+}
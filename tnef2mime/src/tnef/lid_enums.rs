@@ -0,0 +1,1210 @@
+// This file has been generated by props_md2attr.
+
+use std::fmt;
+
+use crate::guid::Guid;
+
+
+pub const PSETID_ADDRESS: Guid = Guid {
+    data1: 0x00062004,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_APPOINTMENT: Guid = Guid {
+    data1: 0x00062002,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_COMMON: Guid = Guid {
+    data1: 0x00062008,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_LOG: Guid = Guid {
+    data1: 0x0006200A,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_MEETING: Guid = Guid {
+    data1: 0x6ED8DA90,
+    data2: 0x450B,
+    data3: 0x101B,
+    data4: [0x98, 0xDA, 0x00, 0xAA, 0x00, 0x3F, 0x13, 0x05],
+};
+
+pub const PSETID_NOTE: Guid = Guid {
+    data1: 0x0006200E,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_POSTRSS: Guid = Guid {
+    data1: 0x00062041,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_SHARING: Guid = Guid {
+    data1: 0x00062040,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PSETID_TASK: Guid = Guid {
+    data1: 0x00062003,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+pub const PS_PUBLIC_STRINGS: Guid = Guid {
+    data1: 0x00020329,
+    data2: 0x0000,
+    data3: 0x0000,
+    data4: [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+};
+
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PidLid {
+    FileUnder,
+    FileUnderId,
+    ContactItemData,
+    Department,
+    HasPicture,
+    HomeAddress,
+    WorkAddress,
+    OtherAddress,
+    PostalAddressId,
+    ContactCharacterSet,
+    AutoLog,
+    FileUnderList,
+    AddressBookProviderEmailList,
+    AddressBookProviderArrayType,
+    Html,
+    YomiFirstName,
+    YomiLastName,
+    YomiCompanyName,
+    BusinessCardDisplayDefinition,
+    BusinessCardCardPicture,
+    WorkAddressStreet,
+    WorkAddressCity,
+    WorkAddressState,
+    WorkAddressPostalCode,
+    WorkAddressCountry,
+    WorkAddressPostOfficeBox,
+    DistributionListChecksum,
+    BirthdayEventEntryId,
+    AnniversaryEventEntryId,
+    ContactUserField1,
+    ContactUserField2,
+    ContactUserField3,
+    ContactUserField4,
+    DistributionListName,
+    DistributionListOneOffMembers,
+    DistributionListMembers,
+    InstantMessagingAddress,
+    DistributionListStream,
+    Email1DisplayName,
+    Email1AddressType,
+    Email1EmailAddress,
+    Email1OriginalDisplayName,
+    Email1OriginalEntryId,
+    Email2DisplayName,
+    Email2AddressType,
+    Email2EmailAddress,
+    Email2OriginalDisplayName,
+    Email2OriginalEntryId,
+    Email3DisplayName,
+    Email3AddressType,
+    Email3EmailAddress,
+    Email3OriginalDisplayName,
+    Email3OriginalEntryId,
+    Fax1AddressType,
+    Fax1EmailAddress,
+    Fax1OriginalDisplayName,
+    Fax1OriginalEntryId,
+    Fax2AddressType,
+    Fax2EmailAddress,
+    Fax2OriginalDisplayName,
+    Fax2OriginalEntryId,
+    Fax3AddressType,
+    Fax3EmailAddress,
+    Fax3OriginalDisplayName,
+    Fax3OriginalEntryId,
+    FreeBusyLocation,
+    HomeAddressCountryCode,
+    WorkAddressCountryCode,
+    OtherAddressCountryCode,
+    AddressCountryCode,
+    BirthdayLocal,
+    WeddingAnniversaryLocal,
+    IsContactLinked,
+    ContactLinkedGlobalAddressListEntryId,
+    ContactLinkSMTPAddressCache,
+    ContactLinkLinkRejectHistory,
+    ContactLinkGlobalAddressListLinkState,
+    ContactLinkGlobalAddressListLinkId,
+    AppointmentSequence,
+    AppointmentSequenceTime,
+    AppointmentLastSequence,
+    ChangeHighlight,
+    BusyStatus,
+    FExceptionalBody,
+    AppointmentAuxiliaryFlags,
+    Location,
+    MeetingWorkspaceUrl,
+    ForwardInstance,
+    LinkedTaskItems,
+    AppointmentStartWhole,
+    AppointmentEndWhole,
+    AppointmentStartTime,
+    AppointmentEndTime,
+    AppointmentEndDate,
+    AppointmentStartDate,
+    AppointmentDuration,
+    AppointmentColor,
+    AppointmentSubType,
+    AppointmentRecur,
+    AppointmentStateFlags,
+    ResponseStatus,
+    AppointmentReplyTime,
+    Recurring,
+    IntendedBusyStatus,
+    AppointmentUpdateTime,
+    ExceptionReplaceTime,
+    FInvited,
+    FExceptionalAttendees,
+    OwnerName,
+    FOthersAppointment,
+    AppointmentReplyName,
+    RecurrenceType,
+    RecurrencePattern,
+    TimeZoneStruct,
+    TimeZoneDescription,
+    ClipStart,
+    ClipEnd,
+    OriginalStoreEntryId,
+    AllAttendeesString,
+    AutoFillLocation,
+    ToAttendeesString,
+    CcAttendeesString,
+    ConferencingCheck,
+    ConferencingType,
+    Directory,
+    OrganizerAlias,
+    AutoStartCheck,
+    AllowExternalCheck,
+    CollaborateDoc,
+    NetShowUrl,
+    OnlinePassword,
+    AppointmentProposedStartWhole,
+    AppointmentProposedEndWhole,
+    AppointmentProposedDuration,
+    AppointmentCounterProposal,
+    AppointmentProposalNumber,
+    AppointmentNotAllowPropose,
+    AppointmentUnsendableRecipients,
+    AppointmentTimeZoneDefinitionStartDisplay,
+    AppointmentTimeZoneDefinitionEndDisplay,
+    AppointmentTimeZoneDefinitionRecur,
+    ForwardNotificationRecipients,
+    InboundICalStream,
+    SingleBodyICal,
+    PromptSendUpdate,
+    ReminderDelta,
+    ReminderTime,
+    ReminderSet,
+    ReminderTimeTime,
+    ReminderTimeDate,
+    Private,
+    AgingDontAgeMe,
+    SideEffects,
+    RemoteStatus,
+    SmartNoAttach,
+    CommonStart,
+    CommonEnd,
+    TaskMode,
+    TaskGlobalId,
+    AutoProcessState,
+    ReminderOverride,
+    ReminderType,
+    ReminderPlaySound,
+    ReminderFileParameter,
+    VerbStream,
+    VerbResponse,
+    FlagRequest,
+    Billing,
+    NonSendableTo,
+    NonSendableCc,
+    NonSendableBcc,
+    Companies,
+    Contacts,
+    NonSendToTrackStatus,
+    NonSendCcTrackStatus,
+    NonSendBccTrackStatus,
+    CurrentVersion,
+    CurrentVersionName,
+    ReminderSignalTime,
+    InternetAccountName,
+    InternetAccountStamp,
+    UseTnef,
+    ContactLinkSearchKey,
+    ContactLinkEntry,
+    ContactLinkName,
+    SpamOriginalFolder,
+    ToDoOrdinalDate,
+    ToDoSubOrdinal,
+    ToDoTitle,
+    InfoPathFormName,
+    Classified,
+    Classification,
+    ClassificationDescription,
+    ClassificationGuid,
+    ClassificationKeep,
+    ReferenceEntryId,
+    ValidFlagStringProof,
+    FlagString,
+    ConversationActionMoveFolderEid,
+    ConversationActionMoveStoreEid,
+    ConversationActionMaxDeliveryTime,
+    ConversationProcessed,
+    ConversationActionLastAppliedTime,
+    ConversationActionVersion,
+    ServerProcessed,
+    ServerProcessingActions,
+    PendingStateForSiteMailboxDocument,
+    LogType,
+    LogStart,
+    LogDuration,
+    LogEnd,
+    LogFlags,
+    LogDocumentPrinted,
+    LogDocumentSaved,
+    LogDocumentRouted,
+    LogDocumentPosted,
+    LogTypeDesc,
+    AttendeeCriticalChange,
+    Where,
+    GlobalObjectId,
+    IsSilent,
+    IsRecurring,
+    RequiredAttendees,
+    OptionalAttendees,
+    ResourceAttendees,
+    DelegateMail,
+    IsException,
+    TimeZone,
+    StartRecurrenceDate,
+    StartRecurrenceTime,
+    EndRecurrenceDate,
+    EndRecurrenceTime,
+    DayInterval,
+    WeekInterval,
+    MonthInterval,
+    YearInterval,
+    ClientIntent,
+    MonthOfYearMask,
+    OldRecurrenceType,
+    OwnerCriticalChange,
+    CalendarType,
+    CleanGlobalObjectId,
+    AppointmentMessageClass,
+    MeetingType,
+    OldLocation,
+    OldWhenStartWhole,
+    OldWhenEndWhole,
+    DayOfMonth,
+    ICalendarDayOfWeekMask,
+    Occurrences,
+    MonthOfYear,
+    NoEndDateFlag,
+    RecurrenceDuration,
+    NoteColor,
+    NoteWidth,
+    NoteHeight,
+    NoteX,
+    NoteY,
+    PostRssChannelLink,
+    PostRssItemLink,
+    PostRssItemHash,
+    PostRssItemGuid,
+    PostRssChannel,
+    PostRssItemXml,
+    PostRssSubscription,
+    SharingStatus,
+    SharingProviderGuid,
+    SharingProviderName,
+    SharingProviderUrl,
+    SharingRemotePath,
+    SharingRemoteName,
+    SharingRemoteUid,
+    SharingInitiatorName,
+    SharingInitiatorSmtp,
+    SharingInitiatorEntryId,
+    SharingFlags,
+    SharingProviderExtension,
+    SharingRemoteUser,
+    SharingRemotePass,
+    SharingLocalPath,
+    SharingLocalName,
+    SharingLocalUid,
+    SharingFilter,
+    SharingLocalType,
+    SharingFolderEntryId,
+    SharingCapabilities,
+    SharingFlavor,
+    SharingAnonymity,
+    SharingReciprocation,
+    SharingPermissions,
+    SharingInstanceGuid,
+    SharingRemoteType,
+    SharingParticipants,
+    SharingLastSyncTime,
+    SharingExtensionXml,
+    SharingRemoteLastModificationTime,
+    SharingLocalLastModificationTime,
+    SharingConfigurationUrl,
+    SharingStart,
+    SharingStop,
+    SharingResponseType,
+    SharingResponseTime,
+    SharingOriginalMessageEntryId,
+    SharingSyncInterval,
+    SharingDetail,
+    SharingTimeToLive,
+    SharingBindingEntryId,
+    SharingIndexEntryId,
+    SharingRemoteComment,
+    SharingWorkingHoursStart,
+    SharingWorkingHoursEnd,
+    SharingWorkingHoursDays,
+    SharingWorkingHoursTimeZone,
+    SharingDataRangeStart,
+    SharingDataRangeEnd,
+    SharingRangeStart,
+    SharingRangeEnd,
+    SharingRemoteStoreUid,
+    SharingLocalStoreUid,
+    SharingRemoteByteSize,
+    SharingRemoteCrc,
+    SharingLocalComment,
+    SharingRoamLog,
+    SharingRemoteMessageCount,
+    SharingBrowseUrl,
+    SharingLastAutoSyncTime,
+    SharingTimeToLiveAuto,
+    SharingRemoteVersion,
+    SharingParentBindingEntryId,
+    SharingSyncFlags,
+    TaskStatus,
+    PercentComplete,
+    TeamTask,
+    TaskStartDate,
+    TaskDueDate,
+    TaskResetReminder,
+    TaskAccepted,
+    TaskDeadOccurrence,
+    TaskDateCompleted,
+    TaskActualEffort,
+    TaskEstimatedEffort,
+    TaskVersion,
+    TaskState,
+    TaskLastUpdate,
+    TaskRecurrence,
+    TaskAssigners,
+    TaskStatusOnComplete,
+    TaskHistory,
+    TaskUpdates,
+    TaskComplete,
+    TaskFCreator,
+    TaskOwner,
+    TaskMultipleRecipients,
+    TaskAssigner,
+    TaskLastUser,
+    TaskOrdinal,
+    TaskNoCompute,
+    TaskLastDelegate,
+    TaskFRecurring,
+    TaskRole,
+    TaskOwnership,
+    TaskAcceptanceState,
+    TaskFFixOffline,
+    TaskCustomFlags,
+    Categories,
+}
+impl PidLid {
+    pub fn from_long_id(property_set: &Guid, long_id: u32) -> Option<Self> {
+        LONG_IDS.iter()
+            .find(|(ps, lid, _pid_lid)| ps == property_set && *lid == long_id)
+            .map(|(_ps, _lid, pid_lid)| *pid_lid)
+    }
+
+    pub fn property_set(&self) -> Guid {
+        self.table_entry().0
+    }
+
+    pub fn long_id(&self) -> u32 {
+        self.table_entry().1
+    }
+
+    fn table_entry(&self) -> &'static (Guid, u32, PidLid) {
+        LONG_IDS.iter()
+            .find(|(_ps, _lid, pid_lid)| pid_lid == self)
+            .unwrap()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FileUnder => "PidLidFileUnder",
+            Self::FileUnderId => "PidLidFileUnderId",
+            Self::ContactItemData => "PidLidContactItemData",
+            Self::Department => "PidLidDepartment",
+            Self::HasPicture => "PidLidHasPicture",
+            Self::HomeAddress => "PidLidHomeAddress",
+            Self::WorkAddress => "PidLidWorkAddress",
+            Self::OtherAddress => "PidLidOtherAddress",
+            Self::PostalAddressId => "PidLidPostalAddressId",
+            Self::ContactCharacterSet => "PidLidContactCharacterSet",
+            Self::AutoLog => "PidLidAutoLog",
+            Self::FileUnderList => "PidLidFileUnderList",
+            Self::AddressBookProviderEmailList => "PidLidAddressBookProviderEmailList",
+            Self::AddressBookProviderArrayType => "PidLidAddressBookProviderArrayType",
+            Self::Html => "PidLidHtml",
+            Self::YomiFirstName => "PidLidYomiFirstName",
+            Self::YomiLastName => "PidLidYomiLastName",
+            Self::YomiCompanyName => "PidLidYomiCompanyName",
+            Self::BusinessCardDisplayDefinition => "PidLidBusinessCardDisplayDefinition",
+            Self::BusinessCardCardPicture => "PidLidBusinessCardCardPicture",
+            Self::WorkAddressStreet => "PidLidWorkAddressStreet",
+            Self::WorkAddressCity => "PidLidWorkAddressCity",
+            Self::WorkAddressState => "PidLidWorkAddressState",
+            Self::WorkAddressPostalCode => "PidLidWorkAddressPostalCode",
+            Self::WorkAddressCountry => "PidLidWorkAddressCountry",
+            Self::WorkAddressPostOfficeBox => "PidLidWorkAddressPostOfficeBox",
+            Self::DistributionListChecksum => "PidLidDistributionListChecksum",
+            Self::BirthdayEventEntryId => "PidLidBirthdayEventEntryId",
+            Self::AnniversaryEventEntryId => "PidLidAnniversaryEventEntryId",
+            Self::ContactUserField1 => "PidLidContactUserField1",
+            Self::ContactUserField2 => "PidLidContactUserField2",
+            Self::ContactUserField3 => "PidLidContactUserField3",
+            Self::ContactUserField4 => "PidLidContactUserField4",
+            Self::DistributionListName => "PidLidDistributionListName",
+            Self::DistributionListOneOffMembers => "PidLidDistributionListOneOffMembers",
+            Self::DistributionListMembers => "PidLidDistributionListMembers",
+            Self::InstantMessagingAddress => "PidLidInstantMessagingAddress",
+            Self::DistributionListStream => "PidLidDistributionListStream",
+            Self::Email1DisplayName => "PidLidEmail1DisplayName",
+            Self::Email1AddressType => "PidLidEmail1AddressType",
+            Self::Email1EmailAddress => "PidLidEmail1EmailAddress",
+            Self::Email1OriginalDisplayName => "PidLidEmail1OriginalDisplayName",
+            Self::Email1OriginalEntryId => "PidLidEmail1OriginalEntryId",
+            Self::Email2DisplayName => "PidLidEmail2DisplayName",
+            Self::Email2AddressType => "PidLidEmail2AddressType",
+            Self::Email2EmailAddress => "PidLidEmail2EmailAddress",
+            Self::Email2OriginalDisplayName => "PidLidEmail2OriginalDisplayName",
+            Self::Email2OriginalEntryId => "PidLidEmail2OriginalEntryId",
+            Self::Email3DisplayName => "PidLidEmail3DisplayName",
+            Self::Email3AddressType => "PidLidEmail3AddressType",
+            Self::Email3EmailAddress => "PidLidEmail3EmailAddress",
+            Self::Email3OriginalDisplayName => "PidLidEmail3OriginalDisplayName",
+            Self::Email3OriginalEntryId => "PidLidEmail3OriginalEntryId",
+            Self::Fax1AddressType => "PidLidFax1AddressType",
+            Self::Fax1EmailAddress => "PidLidFax1EmailAddress",
+            Self::Fax1OriginalDisplayName => "PidLidFax1OriginalDisplayName",
+            Self::Fax1OriginalEntryId => "PidLidFax1OriginalEntryId",
+            Self::Fax2AddressType => "PidLidFax2AddressType",
+            Self::Fax2EmailAddress => "PidLidFax2EmailAddress",
+            Self::Fax2OriginalDisplayName => "PidLidFax2OriginalDisplayName",
+            Self::Fax2OriginalEntryId => "PidLidFax2OriginalEntryId",
+            Self::Fax3AddressType => "PidLidFax3AddressType",
+            Self::Fax3EmailAddress => "PidLidFax3EmailAddress",
+            Self::Fax3OriginalDisplayName => "PidLidFax3OriginalDisplayName",
+            Self::Fax3OriginalEntryId => "PidLidFax3OriginalEntryId",
+            Self::FreeBusyLocation => "PidLidFreeBusyLocation",
+            Self::HomeAddressCountryCode => "PidLidHomeAddressCountryCode",
+            Self::WorkAddressCountryCode => "PidLidWorkAddressCountryCode",
+            Self::OtherAddressCountryCode => "PidLidOtherAddressCountryCode",
+            Self::AddressCountryCode => "PidLidAddressCountryCode",
+            Self::BirthdayLocal => "PidLidBirthdayLocal",
+            Self::WeddingAnniversaryLocal => "PidLidWeddingAnniversaryLocal",
+            Self::IsContactLinked => "PidLidIsContactLinked",
+            Self::ContactLinkedGlobalAddressListEntryId => "PidLidContactLinkedGlobalAddressListEntryId",
+            Self::ContactLinkSMTPAddressCache => "PidLidContactLinkSMTPAddressCache",
+            Self::ContactLinkLinkRejectHistory => "PidLidContactLinkLinkRejectHistory",
+            Self::ContactLinkGlobalAddressListLinkState => "PidLidContactLinkGlobalAddressListLinkState",
+            Self::ContactLinkGlobalAddressListLinkId => "PidLidContactLinkGlobalAddressListLinkId",
+            Self::AppointmentSequence => "PidLidAppointmentSequence",
+            Self::AppointmentSequenceTime => "PidLidAppointmentSequenceTime",
+            Self::AppointmentLastSequence => "PidLidAppointmentLastSequence",
+            Self::ChangeHighlight => "PidLidChangeHighlight",
+            Self::BusyStatus => "PidLidBusyStatus",
+            Self::FExceptionalBody => "PidLidFExceptionalBody",
+            Self::AppointmentAuxiliaryFlags => "PidLidAppointmentAuxiliaryFlags",
+            Self::Location => "PidLidLocation",
+            Self::MeetingWorkspaceUrl => "PidLidMeetingWorkspaceUrl",
+            Self::ForwardInstance => "PidLidForwardInstance",
+            Self::LinkedTaskItems => "PidLidLinkedTaskItems",
+            Self::AppointmentStartWhole => "PidLidAppointmentStartWhole",
+            Self::AppointmentEndWhole => "PidLidAppointmentEndWhole",
+            Self::AppointmentStartTime => "PidLidAppointmentStartTime",
+            Self::AppointmentEndTime => "PidLidAppointmentEndTime",
+            Self::AppointmentEndDate => "PidLidAppointmentEndDate",
+            Self::AppointmentStartDate => "PidLidAppointmentStartDate",
+            Self::AppointmentDuration => "PidLidAppointmentDuration",
+            Self::AppointmentColor => "PidLidAppointmentColor",
+            Self::AppointmentSubType => "PidLidAppointmentSubType",
+            Self::AppointmentRecur => "PidLidAppointmentRecur",
+            Self::AppointmentStateFlags => "PidLidAppointmentStateFlags",
+            Self::ResponseStatus => "PidLidResponseStatus",
+            Self::AppointmentReplyTime => "PidLidAppointmentReplyTime",
+            Self::Recurring => "PidLidRecurring",
+            Self::IntendedBusyStatus => "PidLidIntendedBusyStatus",
+            Self::AppointmentUpdateTime => "PidLidAppointmentUpdateTime",
+            Self::ExceptionReplaceTime => "PidLidExceptionReplaceTime",
+            Self::FInvited => "PidLidFInvited",
+            Self::FExceptionalAttendees => "PidLidFExceptionalAttendees",
+            Self::OwnerName => "PidLidOwnerName",
+            Self::FOthersAppointment => "PidLidFOthersAppointment",
+            Self::AppointmentReplyName => "PidLidAppointmentReplyName",
+            Self::RecurrenceType => "PidLidRecurrenceType",
+            Self::RecurrencePattern => "PidLidRecurrencePattern",
+            Self::TimeZoneStruct => "PidLidTimeZoneStruct",
+            Self::TimeZoneDescription => "PidLidTimeZoneDescription",
+            Self::ClipStart => "PidLidClipStart",
+            Self::ClipEnd => "PidLidClipEnd",
+            Self::OriginalStoreEntryId => "PidLidOriginalStoreEntryId",
+            Self::AllAttendeesString => "PidLidAllAttendeesString",
+            Self::AutoFillLocation => "PidLidAutoFillLocation",
+            Self::ToAttendeesString => "PidLidToAttendeesString",
+            Self::CcAttendeesString => "PidLidCcAttendeesString",
+            Self::ConferencingCheck => "PidLidConferencingCheck",
+            Self::ConferencingType => "PidLidConferencingType",
+            Self::Directory => "PidLidDirectory",
+            Self::OrganizerAlias => "PidLidOrganizerAlias",
+            Self::AutoStartCheck => "PidLidAutoStartCheck",
+            Self::AllowExternalCheck => "PidLidAllowExternalCheck",
+            Self::CollaborateDoc => "PidLidCollaborateDoc",
+            Self::NetShowUrl => "PidLidNetShowUrl",
+            Self::OnlinePassword => "PidLidOnlinePassword",
+            Self::AppointmentProposedStartWhole => "PidLidAppointmentProposedStartWhole",
+            Self::AppointmentProposedEndWhole => "PidLidAppointmentProposedEndWhole",
+            Self::AppointmentProposedDuration => "PidLidAppointmentProposedDuration",
+            Self::AppointmentCounterProposal => "PidLidAppointmentCounterProposal",
+            Self::AppointmentProposalNumber => "PidLidAppointmentProposalNumber",
+            Self::AppointmentNotAllowPropose => "PidLidAppointmentNotAllowPropose",
+            Self::AppointmentUnsendableRecipients => "PidLidAppointmentUnsendableRecipients",
+            Self::AppointmentTimeZoneDefinitionStartDisplay => "PidLidAppointmentTimeZoneDefinitionStartDisplay",
+            Self::AppointmentTimeZoneDefinitionEndDisplay => "PidLidAppointmentTimeZoneDefinitionEndDisplay",
+            Self::AppointmentTimeZoneDefinitionRecur => "PidLidAppointmentTimeZoneDefinitionRecur",
+            Self::ForwardNotificationRecipients => "PidLidForwardNotificationRecipients",
+            Self::InboundICalStream => "PidLidInboundICalStream",
+            Self::SingleBodyICal => "PidLidSingleBodyICal",
+            Self::PromptSendUpdate => "PidLidPromptSendUpdate",
+            Self::ReminderDelta => "PidLidReminderDelta",
+            Self::ReminderTime => "PidLidReminderTime",
+            Self::ReminderSet => "PidLidReminderSet",
+            Self::ReminderTimeTime => "PidLidReminderTimeTime",
+            Self::ReminderTimeDate => "PidLidReminderTimeDate",
+            Self::Private => "PidLidPrivate",
+            Self::AgingDontAgeMe => "PidLidAgingDontAgeMe",
+            Self::SideEffects => "PidLidSideEffects",
+            Self::RemoteStatus => "PidLidRemoteStatus",
+            Self::SmartNoAttach => "PidLidSmartNoAttach",
+            Self::CommonStart => "PidLidCommonStart",
+            Self::CommonEnd => "PidLidCommonEnd",
+            Self::TaskMode => "PidLidTaskMode",
+            Self::TaskGlobalId => "PidLidTaskGlobalId",
+            Self::AutoProcessState => "PidLidAutoProcessState",
+            Self::ReminderOverride => "PidLidReminderOverride",
+            Self::ReminderType => "PidLidReminderType",
+            Self::ReminderPlaySound => "PidLidReminderPlaySound",
+            Self::ReminderFileParameter => "PidLidReminderFileParameter",
+            Self::VerbStream => "PidLidVerbStream",
+            Self::VerbResponse => "PidLidVerbResponse",
+            Self::FlagRequest => "PidLidFlagRequest",
+            Self::Billing => "PidLidBilling",
+            Self::NonSendableTo => "PidLidNonSendableTo",
+            Self::NonSendableCc => "PidLidNonSendableCc",
+            Self::NonSendableBcc => "PidLidNonSendableBcc",
+            Self::Companies => "PidLidCompanies",
+            Self::Contacts => "PidLidContacts",
+            Self::NonSendToTrackStatus => "PidLidNonSendToTrackStatus",
+            Self::NonSendCcTrackStatus => "PidLidNonSendCcTrackStatus",
+            Self::NonSendBccTrackStatus => "PidLidNonSendBccTrackStatus",
+            Self::CurrentVersion => "PidLidCurrentVersion",
+            Self::CurrentVersionName => "PidLidCurrentVersionName",
+            Self::ReminderSignalTime => "PidLidReminderSignalTime",
+            Self::InternetAccountName => "PidLidInternetAccountName",
+            Self::InternetAccountStamp => "PidLidInternetAccountStamp",
+            Self::UseTnef => "PidLidUseTnef",
+            Self::ContactLinkSearchKey => "PidLidContactLinkSearchKey",
+            Self::ContactLinkEntry => "PidLidContactLinkEntry",
+            Self::ContactLinkName => "PidLidContactLinkName",
+            Self::SpamOriginalFolder => "PidLidSpamOriginalFolder",
+            Self::ToDoOrdinalDate => "PidLidToDoOrdinalDate",
+            Self::ToDoSubOrdinal => "PidLidToDoSubOrdinal",
+            Self::ToDoTitle => "PidLidToDoTitle",
+            Self::InfoPathFormName => "PidLidInfoPathFormName",
+            Self::Classified => "PidLidClassified",
+            Self::Classification => "PidLidClassification",
+            Self::ClassificationDescription => "PidLidClassificationDescription",
+            Self::ClassificationGuid => "PidLidClassificationGuid",
+            Self::ClassificationKeep => "PidLidClassificationKeep",
+            Self::ReferenceEntryId => "PidLidReferenceEntryId",
+            Self::ValidFlagStringProof => "PidLidValidFlagStringProof",
+            Self::FlagString => "PidLidFlagString",
+            Self::ConversationActionMoveFolderEid => "PidLidConversationActionMoveFolderEid",
+            Self::ConversationActionMoveStoreEid => "PidLidConversationActionMoveStoreEid",
+            Self::ConversationActionMaxDeliveryTime => "PidLidConversationActionMaxDeliveryTime",
+            Self::ConversationProcessed => "PidLidConversationProcessed",
+            Self::ConversationActionLastAppliedTime => "PidLidConversationActionLastAppliedTime",
+            Self::ConversationActionVersion => "PidLidConversationActionVersion",
+            Self::ServerProcessed => "PidLidServerProcessed",
+            Self::ServerProcessingActions => "PidLidServerProcessingActions",
+            Self::PendingStateForSiteMailboxDocument => "PidLidPendingStateForSiteMailboxDocument",
+            Self::LogType => "PidLidLogType",
+            Self::LogStart => "PidLidLogStart",
+            Self::LogDuration => "PidLidLogDuration",
+            Self::LogEnd => "PidLidLogEnd",
+            Self::LogFlags => "PidLidLogFlags",
+            Self::LogDocumentPrinted => "PidLidLogDocumentPrinted",
+            Self::LogDocumentSaved => "PidLidLogDocumentSaved",
+            Self::LogDocumentRouted => "PidLidLogDocumentRouted",
+            Self::LogDocumentPosted => "PidLidLogDocumentPosted",
+            Self::LogTypeDesc => "PidLidLogTypeDesc",
+            Self::AttendeeCriticalChange => "PidLidAttendeeCriticalChange",
+            Self::Where => "PidLidWhere",
+            Self::GlobalObjectId => "PidLidGlobalObjectId",
+            Self::IsSilent => "PidLidIsSilent",
+            Self::IsRecurring => "PidLidIsRecurring",
+            Self::RequiredAttendees => "PidLidRequiredAttendees",
+            Self::OptionalAttendees => "PidLidOptionalAttendees",
+            Self::ResourceAttendees => "PidLidResourceAttendees",
+            Self::DelegateMail => "PidLidDelegateMail",
+            Self::IsException => "PidLidIsException",
+            Self::TimeZone => "PidLidTimeZone",
+            Self::StartRecurrenceDate => "PidLidStartRecurrenceDate",
+            Self::StartRecurrenceTime => "PidLidStartRecurrenceTime",
+            Self::EndRecurrenceDate => "PidLidEndRecurrenceDate",
+            Self::EndRecurrenceTime => "PidLidEndRecurrenceTime",
+            Self::DayInterval => "PidLidDayInterval",
+            Self::WeekInterval => "PidLidWeekInterval",
+            Self::MonthInterval => "PidLidMonthInterval",
+            Self::YearInterval => "PidLidYearInterval",
+            Self::ClientIntent => "PidLidClientIntent",
+            Self::MonthOfYearMask => "PidLidMonthOfYearMask",
+            Self::OldRecurrenceType => "PidLidOldRecurrenceType",
+            Self::OwnerCriticalChange => "PidLidOwnerCriticalChange",
+            Self::CalendarType => "PidLidCalendarType",
+            Self::CleanGlobalObjectId => "PidLidCleanGlobalObjectId",
+            Self::AppointmentMessageClass => "PidLidAppointmentMessageClass",
+            Self::MeetingType => "PidLidMeetingType",
+            Self::OldLocation => "PidLidOldLocation",
+            Self::OldWhenStartWhole => "PidLidOldWhenStartWhole",
+            Self::OldWhenEndWhole => "PidLidOldWhenEndWhole",
+            Self::DayOfMonth => "PidLidDayOfMonth",
+            Self::ICalendarDayOfWeekMask => "PidLidICalendarDayOfWeekMask",
+            Self::Occurrences => "PidLidOccurrences",
+            Self::MonthOfYear => "PidLidMonthOfYear",
+            Self::NoEndDateFlag => "PidLidNoEndDateFlag",
+            Self::RecurrenceDuration => "PidLidRecurrenceDuration",
+            Self::NoteColor => "PidLidNoteColor",
+            Self::NoteWidth => "PidLidNoteWidth",
+            Self::NoteHeight => "PidLidNoteHeight",
+            Self::NoteX => "PidLidNoteX",
+            Self::NoteY => "PidLidNoteY",
+            Self::PostRssChannelLink => "PidLidPostRssChannelLink",
+            Self::PostRssItemLink => "PidLidPostRssItemLink",
+            Self::PostRssItemHash => "PidLidPostRssItemHash",
+            Self::PostRssItemGuid => "PidLidPostRssItemGuid",
+            Self::PostRssChannel => "PidLidPostRssChannel",
+            Self::PostRssItemXml => "PidLidPostRssItemXml",
+            Self::PostRssSubscription => "PidLidPostRssSubscription",
+            Self::SharingStatus => "PidLidSharingStatus",
+            Self::SharingProviderGuid => "PidLidSharingProviderGuid",
+            Self::SharingProviderName => "PidLidSharingProviderName",
+            Self::SharingProviderUrl => "PidLidSharingProviderUrl",
+            Self::SharingRemotePath => "PidLidSharingRemotePath",
+            Self::SharingRemoteName => "PidLidSharingRemoteName",
+            Self::SharingRemoteUid => "PidLidSharingRemoteUid",
+            Self::SharingInitiatorName => "PidLidSharingInitiatorName",
+            Self::SharingInitiatorSmtp => "PidLidSharingInitiatorSmtp",
+            Self::SharingInitiatorEntryId => "PidLidSharingInitiatorEntryId",
+            Self::SharingFlags => "PidLidSharingFlags",
+            Self::SharingProviderExtension => "PidLidSharingProviderExtension",
+            Self::SharingRemoteUser => "PidLidSharingRemoteUser",
+            Self::SharingRemotePass => "PidLidSharingRemotePass",
+            Self::SharingLocalPath => "PidLidSharingLocalPath",
+            Self::SharingLocalName => "PidLidSharingLocalName",
+            Self::SharingLocalUid => "PidLidSharingLocalUid",
+            Self::SharingFilter => "PidLidSharingFilter",
+            Self::SharingLocalType => "PidLidSharingLocalType",
+            Self::SharingFolderEntryId => "PidLidSharingFolderEntryId",
+            Self::SharingCapabilities => "PidLidSharingCapabilities",
+            Self::SharingFlavor => "PidLidSharingFlavor",
+            Self::SharingAnonymity => "PidLidSharingAnonymity",
+            Self::SharingReciprocation => "PidLidSharingReciprocation",
+            Self::SharingPermissions => "PidLidSharingPermissions",
+            Self::SharingInstanceGuid => "PidLidSharingInstanceGuid",
+            Self::SharingRemoteType => "PidLidSharingRemoteType",
+            Self::SharingParticipants => "PidLidSharingParticipants",
+            Self::SharingLastSyncTime => "PidLidSharingLastSyncTime",
+            Self::SharingExtensionXml => "PidLidSharingExtensionXml",
+            Self::SharingRemoteLastModificationTime => "PidLidSharingRemoteLastModificationTime",
+            Self::SharingLocalLastModificationTime => "PidLidSharingLocalLastModificationTime",
+            Self::SharingConfigurationUrl => "PidLidSharingConfigurationUrl",
+            Self::SharingStart => "PidLidSharingStart",
+            Self::SharingStop => "PidLidSharingStop",
+            Self::SharingResponseType => "PidLidSharingResponseType",
+            Self::SharingResponseTime => "PidLidSharingResponseTime",
+            Self::SharingOriginalMessageEntryId => "PidLidSharingOriginalMessageEntryId",
+            Self::SharingSyncInterval => "PidLidSharingSyncInterval",
+            Self::SharingDetail => "PidLidSharingDetail",
+            Self::SharingTimeToLive => "PidLidSharingTimeToLive",
+            Self::SharingBindingEntryId => "PidLidSharingBindingEntryId",
+            Self::SharingIndexEntryId => "PidLidSharingIndexEntryId",
+            Self::SharingRemoteComment => "PidLidSharingRemoteComment",
+            Self::SharingWorkingHoursStart => "PidLidSharingWorkingHoursStart",
+            Self::SharingWorkingHoursEnd => "PidLidSharingWorkingHoursEnd",
+            Self::SharingWorkingHoursDays => "PidLidSharingWorkingHoursDays",
+            Self::SharingWorkingHoursTimeZone => "PidLidSharingWorkingHoursTimeZone",
+            Self::SharingDataRangeStart => "PidLidSharingDataRangeStart",
+            Self::SharingDataRangeEnd => "PidLidSharingDataRangeEnd",
+            Self::SharingRangeStart => "PidLidSharingRangeStart",
+            Self::SharingRangeEnd => "PidLidSharingRangeEnd",
+            Self::SharingRemoteStoreUid => "PidLidSharingRemoteStoreUid",
+            Self::SharingLocalStoreUid => "PidLidSharingLocalStoreUid",
+            Self::SharingRemoteByteSize => "PidLidSharingRemoteByteSize",
+            Self::SharingRemoteCrc => "PidLidSharingRemoteCrc",
+            Self::SharingLocalComment => "PidLidSharingLocalComment",
+            Self::SharingRoamLog => "PidLidSharingRoamLog",
+            Self::SharingRemoteMessageCount => "PidLidSharingRemoteMessageCount",
+            Self::SharingBrowseUrl => "PidLidSharingBrowseUrl",
+            Self::SharingLastAutoSyncTime => "PidLidSharingLastAutoSyncTime",
+            Self::SharingTimeToLiveAuto => "PidLidSharingTimeToLiveAuto",
+            Self::SharingRemoteVersion => "PidLidSharingRemoteVersion",
+            Self::SharingParentBindingEntryId => "PidLidSharingParentBindingEntryId",
+            Self::SharingSyncFlags => "PidLidSharingSyncFlags",
+            Self::TaskStatus => "PidLidTaskStatus",
+            Self::PercentComplete => "PidLidPercentComplete",
+            Self::TeamTask => "PidLidTeamTask",
+            Self::TaskStartDate => "PidLidTaskStartDate",
+            Self::TaskDueDate => "PidLidTaskDueDate",
+            Self::TaskResetReminder => "PidLidTaskResetReminder",
+            Self::TaskAccepted => "PidLidTaskAccepted",
+            Self::TaskDeadOccurrence => "PidLidTaskDeadOccurrence",
+            Self::TaskDateCompleted => "PidLidTaskDateCompleted",
+            Self::TaskActualEffort => "PidLidTaskActualEffort",
+            Self::TaskEstimatedEffort => "PidLidTaskEstimatedEffort",
+            Self::TaskVersion => "PidLidTaskVersion",
+            Self::TaskState => "PidLidTaskState",
+            Self::TaskLastUpdate => "PidLidTaskLastUpdate",
+            Self::TaskRecurrence => "PidLidTaskRecurrence",
+            Self::TaskAssigners => "PidLidTaskAssigners",
+            Self::TaskStatusOnComplete => "PidLidTaskStatusOnComplete",
+            Self::TaskHistory => "PidLidTaskHistory",
+            Self::TaskUpdates => "PidLidTaskUpdates",
+            Self::TaskComplete => "PidLidTaskComplete",
+            Self::TaskFCreator => "PidLidTaskFCreator",
+            Self::TaskOwner => "PidLidTaskOwner",
+            Self::TaskMultipleRecipients => "PidLidTaskMultipleRecipients",
+            Self::TaskAssigner => "PidLidTaskAssigner",
+            Self::TaskLastUser => "PidLidTaskLastUser",
+            Self::TaskOrdinal => "PidLidTaskOrdinal",
+            Self::TaskNoCompute => "PidLidTaskNoCompute",
+            Self::TaskLastDelegate => "PidLidTaskLastDelegate",
+            Self::TaskFRecurring => "PidLidTaskFRecurring",
+            Self::TaskRole => "PidLidTaskRole",
+            Self::TaskOwnership => "PidLidTaskOwnership",
+            Self::TaskAcceptanceState => "PidLidTaskAcceptanceState",
+            Self::TaskFFixOffline => "PidLidTaskFFixOffline",
+            Self::TaskCustomFlags => "PidLidTaskCustomFlags",
+            Self::Categories => "PidLidCategories",
+        }
+    }
+}
+impl fmt::Display for PidLid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+pub const LONG_IDS: &[(Guid, u32, PidLid)] = &[
+    (PSETID_ADDRESS, 0x8005, PidLid::FileUnder),
+    (PSETID_ADDRESS, 0x8006, PidLid::FileUnderId),
+    (PSETID_ADDRESS, 0x8007, PidLid::ContactItemData),
+    (PSETID_ADDRESS, 0x8010, PidLid::Department),
+    (PSETID_ADDRESS, 0x8015, PidLid::HasPicture),
+    (PSETID_ADDRESS, 0x801A, PidLid::HomeAddress),
+    (PSETID_ADDRESS, 0x801B, PidLid::WorkAddress),
+    (PSETID_ADDRESS, 0x801C, PidLid::OtherAddress),
+    (PSETID_ADDRESS, 0x8022, PidLid::PostalAddressId),
+    (PSETID_ADDRESS, 0x8023, PidLid::ContactCharacterSet),
+    (PSETID_ADDRESS, 0x8025, PidLid::AutoLog),
+    (PSETID_ADDRESS, 0x8026, PidLid::FileUnderList),
+    (PSETID_ADDRESS, 0x8028, PidLid::AddressBookProviderEmailList),
+    (PSETID_ADDRESS, 0x8029, PidLid::AddressBookProviderArrayType),
+    (PSETID_ADDRESS, 0x802B, PidLid::Html),
+    (PSETID_ADDRESS, 0x802C, PidLid::YomiFirstName),
+    (PSETID_ADDRESS, 0x802D, PidLid::YomiLastName),
+    (PSETID_ADDRESS, 0x802E, PidLid::YomiCompanyName),
+    (PSETID_ADDRESS, 0x8040, PidLid::BusinessCardDisplayDefinition),
+    (PSETID_ADDRESS, 0x8041, PidLid::BusinessCardCardPicture),
+    (PSETID_ADDRESS, 0x8045, PidLid::WorkAddressStreet),
+    (PSETID_ADDRESS, 0x8046, PidLid::WorkAddressCity),
+    (PSETID_ADDRESS, 0x8047, PidLid::WorkAddressState),
+    (PSETID_ADDRESS, 0x8048, PidLid::WorkAddressPostalCode),
+    (PSETID_ADDRESS, 0x8049, PidLid::WorkAddressCountry),
+    (PSETID_ADDRESS, 0x804A, PidLid::WorkAddressPostOfficeBox),
+    (PSETID_ADDRESS, 0x804C, PidLid::DistributionListChecksum),
+    (PSETID_ADDRESS, 0x804D, PidLid::BirthdayEventEntryId),
+    (PSETID_ADDRESS, 0x804E, PidLid::AnniversaryEventEntryId),
+    (PSETID_ADDRESS, 0x804F, PidLid::ContactUserField1),
+    (PSETID_ADDRESS, 0x8050, PidLid::ContactUserField2),
+    (PSETID_ADDRESS, 0x8051, PidLid::ContactUserField3),
+    (PSETID_ADDRESS, 0x8052, PidLid::ContactUserField4),
+    (PSETID_ADDRESS, 0x8053, PidLid::DistributionListName),
+    (PSETID_ADDRESS, 0x8054, PidLid::DistributionListOneOffMembers),
+    (PSETID_ADDRESS, 0x8055, PidLid::DistributionListMembers),
+    (PSETID_ADDRESS, 0x8062, PidLid::InstantMessagingAddress),
+    (PSETID_ADDRESS, 0x8064, PidLid::DistributionListStream),
+    (PSETID_ADDRESS, 0x8080, PidLid::Email1DisplayName),
+    (PSETID_ADDRESS, 0x8082, PidLid::Email1AddressType),
+    (PSETID_ADDRESS, 0x8083, PidLid::Email1EmailAddress),
+    (PSETID_ADDRESS, 0x8084, PidLid::Email1OriginalDisplayName),
+    (PSETID_ADDRESS, 0x8085, PidLid::Email1OriginalEntryId),
+    (PSETID_ADDRESS, 0x8090, PidLid::Email2DisplayName),
+    (PSETID_ADDRESS, 0x8092, PidLid::Email2AddressType),
+    (PSETID_ADDRESS, 0x8093, PidLid::Email2EmailAddress),
+    (PSETID_ADDRESS, 0x8094, PidLid::Email2OriginalDisplayName),
+    (PSETID_ADDRESS, 0x8095, PidLid::Email2OriginalEntryId),
+    (PSETID_ADDRESS, 0x80A0, PidLid::Email3DisplayName),
+    (PSETID_ADDRESS, 0x80A2, PidLid::Email3AddressType),
+    (PSETID_ADDRESS, 0x80A3, PidLid::Email3EmailAddress),
+    (PSETID_ADDRESS, 0x80A4, PidLid::Email3OriginalDisplayName),
+    (PSETID_ADDRESS, 0x80A5, PidLid::Email3OriginalEntryId),
+    (PSETID_ADDRESS, 0x80B2, PidLid::Fax1AddressType),
+    (PSETID_ADDRESS, 0x80B3, PidLid::Fax1EmailAddress),
+    (PSETID_ADDRESS, 0x80B4, PidLid::Fax1OriginalDisplayName),
+    (PSETID_ADDRESS, 0x80B5, PidLid::Fax1OriginalEntryId),
+    (PSETID_ADDRESS, 0x80C2, PidLid::Fax2AddressType),
+    (PSETID_ADDRESS, 0x80C3, PidLid::Fax2EmailAddress),
+    (PSETID_ADDRESS, 0x80C4, PidLid::Fax2OriginalDisplayName),
+    (PSETID_ADDRESS, 0x80C5, PidLid::Fax2OriginalEntryId),
+    (PSETID_ADDRESS, 0x80D2, PidLid::Fax3AddressType),
+    (PSETID_ADDRESS, 0x80D3, PidLid::Fax3EmailAddress),
+    (PSETID_ADDRESS, 0x80D4, PidLid::Fax3OriginalDisplayName),
+    (PSETID_ADDRESS, 0x80D5, PidLid::Fax3OriginalEntryId),
+    (PSETID_ADDRESS, 0x80D8, PidLid::FreeBusyLocation),
+    (PSETID_ADDRESS, 0x80DA, PidLid::HomeAddressCountryCode),
+    (PSETID_ADDRESS, 0x80DB, PidLid::WorkAddressCountryCode),
+    (PSETID_ADDRESS, 0x80DC, PidLid::OtherAddressCountryCode),
+    (PSETID_ADDRESS, 0x80DD, PidLid::AddressCountryCode),
+    (PSETID_ADDRESS, 0x80DE, PidLid::BirthdayLocal),
+    (PSETID_ADDRESS, 0x80DF, PidLid::WeddingAnniversaryLocal),
+    (PSETID_ADDRESS, 0x80E0, PidLid::IsContactLinked),
+    (PSETID_ADDRESS, 0x80E2, PidLid::ContactLinkedGlobalAddressListEntryId),
+    (PSETID_ADDRESS, 0x80E3, PidLid::ContactLinkSMTPAddressCache),
+    (PSETID_ADDRESS, 0x80E5, PidLid::ContactLinkLinkRejectHistory),
+    (PSETID_ADDRESS, 0x80E6, PidLid::ContactLinkGlobalAddressListLinkState),
+    (PSETID_ADDRESS, 0x80E8, PidLid::ContactLinkGlobalAddressListLinkId),
+    (PSETID_APPOINTMENT, 0x8201, PidLid::AppointmentSequence),
+    (PSETID_APPOINTMENT, 0x8202, PidLid::AppointmentSequenceTime),
+    (PSETID_APPOINTMENT, 0x8203, PidLid::AppointmentLastSequence),
+    (PSETID_APPOINTMENT, 0x8204, PidLid::ChangeHighlight),
+    (PSETID_APPOINTMENT, 0x8205, PidLid::BusyStatus),
+    (PSETID_APPOINTMENT, 0x8206, PidLid::FExceptionalBody),
+    (PSETID_APPOINTMENT, 0x8207, PidLid::AppointmentAuxiliaryFlags),
+    (PSETID_APPOINTMENT, 0x8208, PidLid::Location),
+    (PSETID_APPOINTMENT, 0x8209, PidLid::MeetingWorkspaceUrl),
+    (PSETID_APPOINTMENT, 0x820A, PidLid::ForwardInstance),
+    (PSETID_APPOINTMENT, 0x820C, PidLid::LinkedTaskItems),
+    (PSETID_APPOINTMENT, 0x820D, PidLid::AppointmentStartWhole),
+    (PSETID_APPOINTMENT, 0x820E, PidLid::AppointmentEndWhole),
+    (PSETID_APPOINTMENT, 0x820F, PidLid::AppointmentStartTime),
+    (PSETID_APPOINTMENT, 0x8210, PidLid::AppointmentEndTime),
+    (PSETID_APPOINTMENT, 0x8211, PidLid::AppointmentEndDate),
+    (PSETID_APPOINTMENT, 0x8212, PidLid::AppointmentStartDate),
+    (PSETID_APPOINTMENT, 0x8213, PidLid::AppointmentDuration),
+    (PSETID_APPOINTMENT, 0x8214, PidLid::AppointmentColor),
+    (PSETID_APPOINTMENT, 0x8215, PidLid::AppointmentSubType),
+    (PSETID_APPOINTMENT, 0x8216, PidLid::AppointmentRecur),
+    (PSETID_APPOINTMENT, 0x8217, PidLid::AppointmentStateFlags),
+    (PSETID_APPOINTMENT, 0x8218, PidLid::ResponseStatus),
+    (PSETID_APPOINTMENT, 0x8220, PidLid::AppointmentReplyTime),
+    (PSETID_APPOINTMENT, 0x8223, PidLid::Recurring),
+    (PSETID_APPOINTMENT, 0x8224, PidLid::IntendedBusyStatus),
+    (PSETID_APPOINTMENT, 0x8226, PidLid::AppointmentUpdateTime),
+    (PSETID_APPOINTMENT, 0x8228, PidLid::ExceptionReplaceTime),
+    (PSETID_APPOINTMENT, 0x8229, PidLid::FInvited),
+    (PSETID_APPOINTMENT, 0x822B, PidLid::FExceptionalAttendees),
+    (PSETID_APPOINTMENT, 0x822E, PidLid::OwnerName),
+    (PSETID_APPOINTMENT, 0x822F, PidLid::FOthersAppointment),
+    (PSETID_APPOINTMENT, 0x8230, PidLid::AppointmentReplyName),
+    (PSETID_APPOINTMENT, 0x8231, PidLid::RecurrenceType),
+    (PSETID_APPOINTMENT, 0x8232, PidLid::RecurrencePattern),
+    (PSETID_APPOINTMENT, 0x8233, PidLid::TimeZoneStruct),
+    (PSETID_APPOINTMENT, 0x8234, PidLid::TimeZoneDescription),
+    (PSETID_APPOINTMENT, 0x8235, PidLid::ClipStart),
+    (PSETID_APPOINTMENT, 0x8236, PidLid::ClipEnd),
+    (PSETID_APPOINTMENT, 0x8237, PidLid::OriginalStoreEntryId),
+    (PSETID_APPOINTMENT, 0x8238, PidLid::AllAttendeesString),
+    (PSETID_APPOINTMENT, 0x823A, PidLid::AutoFillLocation),
+    (PSETID_APPOINTMENT, 0x823B, PidLid::ToAttendeesString),
+    (PSETID_APPOINTMENT, 0x823C, PidLid::CcAttendeesString),
+    (PSETID_APPOINTMENT, 0x8240, PidLid::ConferencingCheck),
+    (PSETID_APPOINTMENT, 0x8241, PidLid::ConferencingType),
+    (PSETID_APPOINTMENT, 0x8242, PidLid::Directory),
+    (PSETID_APPOINTMENT, 0x8243, PidLid::OrganizerAlias),
+    (PSETID_APPOINTMENT, 0x8244, PidLid::AutoStartCheck),
+    (PSETID_APPOINTMENT, 0x8246, PidLid::AllowExternalCheck),
+    (PSETID_APPOINTMENT, 0x8247, PidLid::CollaborateDoc),
+    (PSETID_APPOINTMENT, 0x8248, PidLid::NetShowUrl),
+    (PSETID_APPOINTMENT, 0x8249, PidLid::OnlinePassword),
+    (PSETID_APPOINTMENT, 0x8250, PidLid::AppointmentProposedStartWhole),
+    (PSETID_APPOINTMENT, 0x8251, PidLid::AppointmentProposedEndWhole),
+    (PSETID_APPOINTMENT, 0x8256, PidLid::AppointmentProposedDuration),
+    (PSETID_APPOINTMENT, 0x8257, PidLid::AppointmentCounterProposal),
+    (PSETID_APPOINTMENT, 0x8259, PidLid::AppointmentProposalNumber),
+    (PSETID_APPOINTMENT, 0x825A, PidLid::AppointmentNotAllowPropose),
+    (PSETID_APPOINTMENT, 0x825D, PidLid::AppointmentUnsendableRecipients),
+    (PSETID_APPOINTMENT, 0x825E, PidLid::AppointmentTimeZoneDefinitionStartDisplay),
+    (PSETID_APPOINTMENT, 0x825F, PidLid::AppointmentTimeZoneDefinitionEndDisplay),
+    (PSETID_APPOINTMENT, 0x8260, PidLid::AppointmentTimeZoneDefinitionRecur),
+    (PSETID_APPOINTMENT, 0x8261, PidLid::ForwardNotificationRecipients),
+    (PSETID_APPOINTMENT, 0x827A, PidLid::InboundICalStream),
+    (PSETID_APPOINTMENT, 0x827B, PidLid::SingleBodyICal),
+    (PSETID_COMMON, 0x8045, PidLid::PromptSendUpdate),
+    (PSETID_COMMON, 0x8501, PidLid::ReminderDelta),
+    (PSETID_COMMON, 0x8502, PidLid::ReminderTime),
+    (PSETID_COMMON, 0x8503, PidLid::ReminderSet),
+    (PSETID_COMMON, 0x8504, PidLid::ReminderTimeTime),
+    (PSETID_COMMON, 0x8505, PidLid::ReminderTimeDate),
+    (PSETID_COMMON, 0x8506, PidLid::Private),
+    (PSETID_COMMON, 0x850E, PidLid::AgingDontAgeMe),
+    (PSETID_COMMON, 0x8510, PidLid::SideEffects),
+    (PSETID_COMMON, 0x8511, PidLid::RemoteStatus),
+    (PSETID_COMMON, 0x8514, PidLid::SmartNoAttach),
+    (PSETID_COMMON, 0x8516, PidLid::CommonStart),
+    (PSETID_COMMON, 0x8517, PidLid::CommonEnd),
+    (PSETID_COMMON, 0x8518, PidLid::TaskMode),
+    (PSETID_COMMON, 0x8519, PidLid::TaskGlobalId),
+    (PSETID_COMMON, 0x851A, PidLid::AutoProcessState),
+    (PSETID_COMMON, 0x851C, PidLid::ReminderOverride),
+    (PSETID_COMMON, 0x851D, PidLid::ReminderType),
+    (PSETID_COMMON, 0x851E, PidLid::ReminderPlaySound),
+    (PSETID_COMMON, 0x851F, PidLid::ReminderFileParameter),
+    (PSETID_COMMON, 0x8520, PidLid::VerbStream),
+    (PSETID_COMMON, 0x8524, PidLid::VerbResponse),
+    (PSETID_COMMON, 0x8530, PidLid::FlagRequest),
+    (PSETID_COMMON, 0x8535, PidLid::Billing),
+    (PSETID_COMMON, 0x8536, PidLid::NonSendableTo),
+    (PSETID_COMMON, 0x8537, PidLid::NonSendableCc),
+    (PSETID_COMMON, 0x8538, PidLid::NonSendableBcc),
+    (PSETID_COMMON, 0x8539, PidLid::Companies),
+    (PSETID_COMMON, 0x853A, PidLid::Contacts),
+    (PSETID_COMMON, 0x8543, PidLid::NonSendToTrackStatus),
+    (PSETID_COMMON, 0x8544, PidLid::NonSendCcTrackStatus),
+    (PSETID_COMMON, 0x8545, PidLid::NonSendBccTrackStatus),
+    (PSETID_COMMON, 0x8552, PidLid::CurrentVersion),
+    (PSETID_COMMON, 0x8554, PidLid::CurrentVersionName),
+    (PSETID_COMMON, 0x8560, PidLid::ReminderSignalTime),
+    (PSETID_COMMON, 0x8580, PidLid::InternetAccountName),
+    (PSETID_COMMON, 0x8581, PidLid::InternetAccountStamp),
+    (PSETID_COMMON, 0x8582, PidLid::UseTnef),
+    (PSETID_COMMON, 0x8584, PidLid::ContactLinkSearchKey),
+    (PSETID_COMMON, 0x8585, PidLid::ContactLinkEntry),
+    (PSETID_COMMON, 0x8586, PidLid::ContactLinkName),
+    (PSETID_COMMON, 0x859C, PidLid::SpamOriginalFolder),
+    (PSETID_COMMON, 0x85A0, PidLid::ToDoOrdinalDate),
+    (PSETID_COMMON, 0x85A1, PidLid::ToDoSubOrdinal),
+    (PSETID_COMMON, 0x85A4, PidLid::ToDoTitle),
+    (PSETID_COMMON, 0x85B1, PidLid::InfoPathFormName),
+    (PSETID_COMMON, 0x85B5, PidLid::Classified),
+    (PSETID_COMMON, 0x85B6, PidLid::Classification),
+    (PSETID_COMMON, 0x85B7, PidLid::ClassificationDescription),
+    (PSETID_COMMON, 0x85B8, PidLid::ClassificationGuid),
+    (PSETID_COMMON, 0x85BA, PidLid::ClassificationKeep),
+    (PSETID_COMMON, 0x85BD, PidLid::ReferenceEntryId),
+    (PSETID_COMMON, 0x85BF, PidLid::ValidFlagStringProof),
+    (PSETID_COMMON, 0x85C0, PidLid::FlagString),
+    (PSETID_COMMON, 0x85C6, PidLid::ConversationActionMoveFolderEid),
+    (PSETID_COMMON, 0x85C7, PidLid::ConversationActionMoveStoreEid),
+    (PSETID_COMMON, 0x85C8, PidLid::ConversationActionMaxDeliveryTime),
+    (PSETID_COMMON, 0x85C9, PidLid::ConversationProcessed),
+    (PSETID_COMMON, 0x85CA, PidLid::ConversationActionLastAppliedTime),
+    (PSETID_COMMON, 0x85CB, PidLid::ConversationActionVersion),
+    (PSETID_COMMON, 0x85CC, PidLid::ServerProcessed),
+    (PSETID_COMMON, 0x85CD, PidLid::ServerProcessingActions),
+    (PSETID_COMMON, 0x85E0, PidLid::PendingStateForSiteMailboxDocument),
+    (PSETID_LOG, 0x8700, PidLid::LogType),
+    (PSETID_LOG, 0x8706, PidLid::LogStart),
+    (PSETID_LOG, 0x8707, PidLid::LogDuration),
+    (PSETID_LOG, 0x8708, PidLid::LogEnd),
+    (PSETID_LOG, 0x870C, PidLid::LogFlags),
+    (PSETID_LOG, 0x870E, PidLid::LogDocumentPrinted),
+    (PSETID_LOG, 0x870F, PidLid::LogDocumentSaved),
+    (PSETID_LOG, 0x8710, PidLid::LogDocumentRouted),
+    (PSETID_LOG, 0x8711, PidLid::LogDocumentPosted),
+    (PSETID_LOG, 0x8712, PidLid::LogTypeDesc),
+    (PSETID_MEETING, 0x0001, PidLid::AttendeeCriticalChange),
+    (PSETID_MEETING, 0x0002, PidLid::Where),
+    (PSETID_MEETING, 0x0003, PidLid::GlobalObjectId),
+    (PSETID_MEETING, 0x0004, PidLid::IsSilent),
+    (PSETID_MEETING, 0x0005, PidLid::IsRecurring),
+    (PSETID_MEETING, 0x0006, PidLid::RequiredAttendees),
+    (PSETID_MEETING, 0x0007, PidLid::OptionalAttendees),
+    (PSETID_MEETING, 0x0008, PidLid::ResourceAttendees),
+    (PSETID_MEETING, 0x0009, PidLid::DelegateMail),
+    (PSETID_MEETING, 0x000A, PidLid::IsException),
+    (PSETID_MEETING, 0x000C, PidLid::TimeZone),
+    (PSETID_MEETING, 0x000D, PidLid::StartRecurrenceDate),
+    (PSETID_MEETING, 0x000E, PidLid::StartRecurrenceTime),
+    (PSETID_MEETING, 0x000F, PidLid::EndRecurrenceDate),
+    (PSETID_MEETING, 0x0010, PidLid::EndRecurrenceTime),
+    (PSETID_MEETING, 0x0011, PidLid::DayInterval),
+    (PSETID_MEETING, 0x0012, PidLid::WeekInterval),
+    (PSETID_MEETING, 0x0013, PidLid::MonthInterval),
+    (PSETID_MEETING, 0x0014, PidLid::YearInterval),
+    (PSETID_MEETING, 0x0015, PidLid::ClientIntent),
+    (PSETID_MEETING, 0x0017, PidLid::MonthOfYearMask),
+    (PSETID_MEETING, 0x0018, PidLid::OldRecurrenceType),
+    (PSETID_MEETING, 0x001A, PidLid::OwnerCriticalChange),
+    (PSETID_MEETING, 0x001C, PidLid::CalendarType),
+    (PSETID_MEETING, 0x0023, PidLid::CleanGlobalObjectId),
+    (PSETID_MEETING, 0x0024, PidLid::AppointmentMessageClass),
+    (PSETID_MEETING, 0x0026, PidLid::MeetingType),
+    (PSETID_MEETING, 0x0028, PidLid::OldLocation),
+    (PSETID_MEETING, 0x0029, PidLid::OldWhenStartWhole),
+    (PSETID_MEETING, 0x002A, PidLid::OldWhenEndWhole),
+    (PSETID_MEETING, 0x1000, PidLid::DayOfMonth),
+    (PSETID_MEETING, 0x1001, PidLid::ICalendarDayOfWeekMask),
+    (PSETID_MEETING, 0x1005, PidLid::Occurrences),
+    (PSETID_MEETING, 0x1006, PidLid::MonthOfYear),
+    (PSETID_MEETING, 0x100B, PidLid::NoEndDateFlag),
+    (PSETID_MEETING, 0x100D, PidLid::RecurrenceDuration),
+    (PSETID_NOTE, 0x8B00, PidLid::NoteColor),
+    (PSETID_NOTE, 0x8B02, PidLid::NoteWidth),
+    (PSETID_NOTE, 0x8B03, PidLid::NoteHeight),
+    (PSETID_NOTE, 0x8B04, PidLid::NoteX),
+    (PSETID_NOTE, 0x8B05, PidLid::NoteY),
+    (PSETID_POSTRSS, 0x8900, PidLid::PostRssChannelLink),
+    (PSETID_POSTRSS, 0x8901, PidLid::PostRssItemLink),
+    (PSETID_POSTRSS, 0x8902, PidLid::PostRssItemHash),
+    (PSETID_POSTRSS, 0x8903, PidLid::PostRssItemGuid),
+    (PSETID_POSTRSS, 0x8904, PidLid::PostRssChannel),
+    (PSETID_POSTRSS, 0x8905, PidLid::PostRssItemXml),
+    (PSETID_POSTRSS, 0x8906, PidLid::PostRssSubscription),
+    (PSETID_SHARING, 0x8A00, PidLid::SharingStatus),
+    (PSETID_SHARING, 0x8A01, PidLid::SharingProviderGuid),
+    (PSETID_SHARING, 0x8A02, PidLid::SharingProviderName),
+    (PSETID_SHARING, 0x8A03, PidLid::SharingProviderUrl),
+    (PSETID_SHARING, 0x8A04, PidLid::SharingRemotePath),
+    (PSETID_SHARING, 0x8A05, PidLid::SharingRemoteName),
+    (PSETID_SHARING, 0x8A06, PidLid::SharingRemoteUid),
+    (PSETID_SHARING, 0x8A07, PidLid::SharingInitiatorName),
+    (PSETID_SHARING, 0x8A08, PidLid::SharingInitiatorSmtp),
+    (PSETID_SHARING, 0x8A09, PidLid::SharingInitiatorEntryId),
+    (PSETID_SHARING, 0x8A0A, PidLid::SharingFlags),
+    (PSETID_SHARING, 0x8A0B, PidLid::SharingProviderExtension),
+    (PSETID_SHARING, 0x8A0C, PidLid::SharingRemoteUser),
+    (PSETID_SHARING, 0x8A0D, PidLid::SharingRemotePass),
+    (PSETID_SHARING, 0x8A0E, PidLid::SharingLocalPath),
+    (PSETID_SHARING, 0x8A0F, PidLid::SharingLocalName),
+    (PSETID_SHARING, 0x8A10, PidLid::SharingLocalUid),
+    (PSETID_SHARING, 0x8A13, PidLid::SharingFilter),
+    (PSETID_SHARING, 0x8A14, PidLid::SharingLocalType),
+    (PSETID_SHARING, 0x8A15, PidLid::SharingFolderEntryId),
+    (PSETID_SHARING, 0x8A17, PidLid::SharingCapabilities),
+    (PSETID_SHARING, 0x8A18, PidLid::SharingFlavor),
+    (PSETID_SHARING, 0x8A19, PidLid::SharingAnonymity),
+    (PSETID_SHARING, 0x8A1A, PidLid::SharingReciprocation),
+    (PSETID_SHARING, 0x8A1B, PidLid::SharingPermissions),
+    (PSETID_SHARING, 0x8A1C, PidLid::SharingInstanceGuid),
+    (PSETID_SHARING, 0x8A1D, PidLid::SharingRemoteType),
+    (PSETID_SHARING, 0x8A1E, PidLid::SharingParticipants),
+    (PSETID_SHARING, 0x8A1F, PidLid::SharingLastSyncTime),
+    (PSETID_SHARING, 0x8A21, PidLid::SharingExtensionXml),
+    (PSETID_SHARING, 0x8A22, PidLid::SharingRemoteLastModificationTime),
+    (PSETID_SHARING, 0x8A23, PidLid::SharingLocalLastModificationTime),
+    (PSETID_SHARING, 0x8A24, PidLid::SharingConfigurationUrl),
+    (PSETID_SHARING, 0x8A25, PidLid::SharingStart),
+    (PSETID_SHARING, 0x8A26, PidLid::SharingStop),
+    (PSETID_SHARING, 0x8A27, PidLid::SharingResponseType),
+    (PSETID_SHARING, 0x8A28, PidLid::SharingResponseTime),
+    (PSETID_SHARING, 0x8A29, PidLid::SharingOriginalMessageEntryId),
+    (PSETID_SHARING, 0x8A2A, PidLid::SharingSyncInterval),
+    (PSETID_SHARING, 0x8A2B, PidLid::SharingDetail),
+    (PSETID_SHARING, 0x8A2C, PidLid::SharingTimeToLive),
+    (PSETID_SHARING, 0x8A2D, PidLid::SharingBindingEntryId),
+    (PSETID_SHARING, 0x8A2E, PidLid::SharingIndexEntryId),
+    (PSETID_SHARING, 0x8A2F, PidLid::SharingRemoteComment),
+    (PSETID_SHARING, 0x8A40, PidLid::SharingWorkingHoursStart),
+    (PSETID_SHARING, 0x8A41, PidLid::SharingWorkingHoursEnd),
+    (PSETID_SHARING, 0x8A42, PidLid::SharingWorkingHoursDays),
+    (PSETID_SHARING, 0x8A43, PidLid::SharingWorkingHoursTimeZone),
+    (PSETID_SHARING, 0x8A44, PidLid::SharingDataRangeStart),
+    (PSETID_SHARING, 0x8A45, PidLid::SharingDataRangeEnd),
+    (PSETID_SHARING, 0x8A46, PidLid::SharingRangeStart),
+    (PSETID_SHARING, 0x8A47, PidLid::SharingRangeEnd),
+    (PSETID_SHARING, 0x8A48, PidLid::SharingRemoteStoreUid),
+    (PSETID_SHARING, 0x8A49, PidLid::SharingLocalStoreUid),
+    (PSETID_SHARING, 0x8A4B, PidLid::SharingRemoteByteSize),
+    (PSETID_SHARING, 0x8A4C, PidLid::SharingRemoteCrc),
+    (PSETID_SHARING, 0x8A4D, PidLid::SharingLocalComment),
+    (PSETID_SHARING, 0x8A4E, PidLid::SharingRoamLog),
+    (PSETID_SHARING, 0x8A4F, PidLid::SharingRemoteMessageCount),
+    (PSETID_SHARING, 0x8A51, PidLid::SharingBrowseUrl),
+    (PSETID_SHARING, 0x8A55, PidLid::SharingLastAutoSyncTime),
+    (PSETID_SHARING, 0x8A56, PidLid::SharingTimeToLiveAuto),
+    (PSETID_SHARING, 0x8A5B, PidLid::SharingRemoteVersion),
+    (PSETID_SHARING, 0x8A5C, PidLid::SharingParentBindingEntryId),
+    (PSETID_SHARING, 0x8A60, PidLid::SharingSyncFlags),
+    (PSETID_TASK, 0x8101, PidLid::TaskStatus),
+    (PSETID_TASK, 0x8102, PidLid::PercentComplete),
+    (PSETID_TASK, 0x8103, PidLid::TeamTask),
+    (PSETID_TASK, 0x8104, PidLid::TaskStartDate),
+    (PSETID_TASK, 0x8105, PidLid::TaskDueDate),
+    (PSETID_TASK, 0x8107, PidLid::TaskResetReminder),
+    (PSETID_TASK, 0x8108, PidLid::TaskAccepted),
+    (PSETID_TASK, 0x8109, PidLid::TaskDeadOccurrence),
+    (PSETID_TASK, 0x810F, PidLid::TaskDateCompleted),
+    (PSETID_TASK, 0x8110, PidLid::TaskActualEffort),
+    (PSETID_TASK, 0x8111, PidLid::TaskEstimatedEffort),
+    (PSETID_TASK, 0x8112, PidLid::TaskVersion),
+    (PSETID_TASK, 0x8113, PidLid::TaskState),
+    (PSETID_TASK, 0x8115, PidLid::TaskLastUpdate),
+    (PSETID_TASK, 0x8116, PidLid::TaskRecurrence),
+    (PSETID_TASK, 0x8117, PidLid::TaskAssigners),
+    (PSETID_TASK, 0x8119, PidLid::TaskStatusOnComplete),
+    (PSETID_TASK, 0x811A, PidLid::TaskHistory),
+    (PSETID_TASK, 0x811B, PidLid::TaskUpdates),
+    (PSETID_TASK, 0x811C, PidLid::TaskComplete),
+    (PSETID_TASK, 0x811E, PidLid::TaskFCreator),
+    (PSETID_TASK, 0x811F, PidLid::TaskOwner),
+    (PSETID_TASK, 0x8120, PidLid::TaskMultipleRecipients),
+    (PSETID_TASK, 0x8121, PidLid::TaskAssigner),
+    (PSETID_TASK, 0x8122, PidLid::TaskLastUser),
+    (PSETID_TASK, 0x8123, PidLid::TaskOrdinal),
+    (PSETID_TASK, 0x8124, PidLid::TaskNoCompute),
+    (PSETID_TASK, 0x8125, PidLid::TaskLastDelegate),
+    (PSETID_TASK, 0x8126, PidLid::TaskFRecurring),
+    (PSETID_TASK, 0x8127, PidLid::TaskRole),
+    (PSETID_TASK, 0x8129, PidLid::TaskOwnership),
+    (PSETID_TASK, 0x812A, PidLid::TaskAcceptanceState),
+    (PSETID_TASK, 0x812C, PidLid::TaskFFixOffline),
+    (PSETID_TASK, 0x8139, PidLid::TaskCustomFlags),
+    (PS_PUBLIC_STRINGS, 0x9000, PidLid::Categories),
+];
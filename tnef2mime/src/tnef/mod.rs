@@ -1,17 +1,23 @@
+mod lid_enums;
 mod prop_enums;
 mod tnef_enums;
 
 
+use std::collections::HashMap;
 use std::fmt;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Cursor, Write};
 use std::string::FromUtf16Error;
 
-use encoding_rs::Encoding;
+use codepage::to_encoding;
+use encoding_rs::{Encoding, UTF_8};
 use from_to_repr::{from_to_other, FromToRepr};
-use log::{debug, error, warn};
+use log::{debug, warn};
 
-use crate::binread::BinaryReader;
+use crate::binread::{BinaryReader, CountingReader, LimitReader};
+use crate::binwrite::BinaryWriter;
+use crate::datetime::UtcDateTime;
 use crate::guid::Guid;
+pub use crate::tnef::lid_enums::PidLid;
 pub use crate::tnef::prop_enums::PropTag;
 pub use crate::tnef::tnef_enums::{TnefAttributeId, TnefAttributeLevel};
 
@@ -24,6 +30,75 @@ pub struct TnefFile {
     pub legacy_key: u16,
     pub attributes: Vec<TnefAttribute>,
 }
+impl TnefFile {
+    pub fn attribute(&self, id: TnefAttributeId) -> Option<&TnefAttribute> {
+        self.attributes_by_id(id)
+            .next()
+    }
+
+    pub fn attributes_by_id(&self, id: TnefAttributeId) -> impl Iterator<Item = &TnefAttribute> {
+        self.attributes.iter()
+            .filter(move |a| a.id == id)
+    }
+
+    pub fn attachment_groups(&self) -> Vec<Vec<&TnefAttribute>> {
+        // each attachment begins with an attAttachRendData attribute
+        let mut groups: Vec<Vec<&TnefAttribute>> = Vec::new();
+        for attribute in &self.attributes {
+            if attribute.level != TnefAttributeLevel::Attachment {
+                continue;
+            }
+            if attribute.id == TnefAttributeId::AttachRendData || groups.is_empty() {
+                groups.push(Vec::new());
+            }
+            groups.last_mut().unwrap().push(attribute);
+        }
+        groups
+    }
+
+    pub fn encoding(&self) -> &'static Encoding {
+        // the codepage applies to all strings, even those in attributes preceding it
+        self.attribute(TnefAttributeId::OemCodepage)
+            .and_then(|a| a.data.get(0..2))
+            .and_then(|cp| to_encoding(u16::from_le_bytes([cp[0], cp[1]])))
+            .unwrap_or(UTF_8)
+    }
+
+    fn attribute_string(&self, id: TnefAttributeId) -> Option<String> {
+        let attribute = self.attribute(id)?;
+        let decoded = decode_ansi_string(&attribute.data, self.encoding());
+        Some(decoded.trim_end_matches('\0').to_owned())
+    }
+
+    pub fn message_class(&self) -> Option<String> {
+        self.attribute_string(TnefAttributeId::MessageClass)
+    }
+
+    pub fn subject(&self) -> Option<String> {
+        self.attribute_string(TnefAttributeId::Subject)
+    }
+
+    fn attribute_date(&self, id: TnefAttributeId) -> Option<UtcDateTime> {
+        let attribute = self.attribute(id)?;
+        parse_tnef_date(&attribute.data)
+    }
+
+    pub fn date_sent(&self) -> Option<UtcDateTime> {
+        self.attribute_date(TnefAttributeId::DateSent)
+    }
+
+    pub fn date_modified(&self) -> Option<UtcDateTime> {
+        self.attribute_date(TnefAttributeId::DateModified)
+    }
+
+    pub fn date_start(&self) -> Option<UtcDateTime> {
+        self.attribute_date(TnefAttributeId::DateStart)
+    }
+
+    pub fn date_end(&self) -> Option<UtcDateTime> {
+        self.attribute_date(TnefAttributeId::DateEnd)
+    }
+}
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct TnefAttribute {
@@ -32,12 +107,54 @@ pub struct TnefAttribute {
     pub data: Vec<u8>,
     pub checksum: u16,
 }
+impl TnefAttribute {
+    pub fn data_reader(&self) -> LimitReader<Cursor<&[u8]>> {
+        // property decoding must stop at the end of this attribute's payload
+        LimitReader::new(Cursor::new(&self.data[..]), u64::try_from(self.data.len()).unwrap())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TnefReadOptions {
+    pub verify_checksums: bool,
+    pub allow_odd_string_lengths: bool,
+    pub verify_padding: bool,
+}
+impl Default for TnefReadOptions {
+    fn default() -> Self {
+        Self {
+            verify_checksums: true,
+            allow_odd_string_lengths: false,
+            verify_padding: false,
+        }
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct Property {
     pub tag: PropTag,
     pub id: Option<(Guid, PropId)>,
     pub value: PropValue,
+    // the undecoded bytes of string values; exposed only through raw_bytes()
+    raw_value: Option<Vec<u8>>,
+}
+impl Property {
+    pub fn new(tag: PropTag, id: Option<(Guid, PropId)>, value: PropValue) -> Self {
+        Self {
+            tag,
+            id,
+            value,
+            raw_value: None,
+        }
+    }
+
+    pub fn raw_bytes(&self) -> Option<&[u8]> {
+        match &self.value {
+            PropValue::Binary(b) => Some(b),
+            PropValue::Object(b) => Some(b),
+            _ => self.raw_value.as_deref(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -74,8 +191,153 @@ pub enum PropType {
     MultipleBinary = 0x1102,
     Other(u16),
 }
+impl PropType {
+    const MULTIPLE_BIT: u16 = 0x1000;
+    const CODEPAGE_STRING_BIT: u16 = 0x8000;
+
+    pub fn is_multiple(&self) -> bool {
+        let value = u16::from(*self);
+        // codepage-specific string types use the lower bits for the codepage
+        value & Self::CODEPAGE_STRING_BIT == 0 && value & Self::MULTIPLE_BIT != 0
+    }
+
+    pub fn single_type(&self) -> PropType {
+        if self.is_multiple() {
+            PropType::from(u16::from(*self) & !Self::MULTIPLE_BIT)
+        } else {
+            *self
+        }
+    }
+
+    pub fn multiple_type(&self) -> Option<PropType> {
+        let value = u16::from(*self);
+        if value & Self::CODEPAGE_STRING_BIT != 0 {
+            return None;
+        }
+        match PropType::from(value | Self::MULTIPLE_BIT) {
+            PropType::Other(_) => None,
+            multiple => Some(multiple),
+        }
+    }
+}
+
+// property categories follow the property ID ranges of [MS-OXPROPS]
+impl PropTag {
+    pub fn is_attachment_tag(&self) -> bool {
+        (0x3700..=0x38FF).contains(&u16::from(*self))
+    }
+
+    pub fn is_recipient_tag(&self) -> bool {
+        // recipient rows also carry address book properties
+        let value = u16::from(*self);
+        (0x0C00..=0x0DFF).contains(&value) || (0x3900..=0x39FF).contains(&value)
+    }
+
+    pub fn is_body_tag(&self) -> bool {
+        matches!(
+            self,
+            PropTag::TagBody | PropTag::TagBodyHtml | PropTag::TagRtfCompressed | PropTag::TagNativeBody
+                | PropTag::TagRtfInSync | PropTag::TagRtfSyncBodyCrc | PropTag::TagRtfSyncBodyCount
+                | PropTag::TagRtfSyncBodyTag | PropTag::TagRtfSyncPrefixCount | PropTag::TagRtfSyncTrailingCount
+                | PropTag::TagBodyCrc | PropTag::TagBodyContentLocation | PropTag::TagBodyContentId
+        )
+    }
+}
+
+// expected types of well-known properties according to [MS-OXPROPS]; tags whose
+// property ID is shared by differently-typed properties are left out
+const EXPECTED_PROP_TYPES: &[(PropTag, PropType)] = &[
+    (PropTag::TagAccess, PropType::Integer32),
+    (PropTag::TagAccessLevel, PropType::Integer32),
+    (PropTag::TagAddressType, PropType::String),
+    (PropTag::TagAttachContentId, PropType::String),
+    (PropTag::TagAttachExtension, PropType::String),
+    (PropTag::TagAttachFilename, PropType::String),
+    (PropTag::TagAttachLongFilename, PropType::String),
+    (PropTag::TagAttachMethod, PropType::Integer32),
+    (PropTag::TagAttachMimeTag, PropType::String),
+    (PropTag::TagAttachNumber, PropType::Integer32),
+    (PropTag::TagAttachSize, PropType::Integer32),
+    (PropTag::TagBody, PropType::String),
+    (PropTag::TagClientSubmitTime, PropType::Time),
+    (PropTag::TagConversationIndex, PropType::Binary),
+    (PropTag::TagConversationTopic, PropType::String),
+    (PropTag::TagCreationTime, PropType::Time),
+    (PropTag::TagDisplayBcc, PropType::String),
+    (PropTag::TagDisplayCc, PropType::String),
+    (PropTag::TagDisplayName, PropType::String),
+    (PropTag::TagDisplayTo, PropType::String),
+    (PropTag::TagEmailAddress, PropType::String),
+    (PropTag::TagEndDate, PropType::Time),
+    (PropTag::TagEntryId, PropType::Binary),
+    (PropTag::TagHasAttachments, PropType::Boolean),
+    (PropTag::TagImportance, PropType::Integer32),
+    (PropTag::TagInReplyToId, PropType::String),
+    (PropTag::TagInternetCodepage, PropType::Integer32),
+    (PropTag::TagInternetMessageId, PropType::String),
+    (PropTag::TagInternetReferences, PropType::String),
+    (PropTag::TagLastModificationTime, PropType::Time),
+    (PropTag::TagMessageClass, PropType::String),
+    (PropTag::TagMessageCodepage, PropType::Integer32),
+    (PropTag::TagMessageDeliveryTime, PropType::Time),
+    (PropTag::TagMessageFlags, PropType::Integer32),
+    (PropTag::TagMessageLocaleId, PropType::Integer32),
+    (PropTag::TagMessageSize, PropType::Integer32),
+    (PropTag::TagNativeBody, PropType::Integer32),
+    (PropTag::TagNormalizedSubject, PropType::String),
+    (PropTag::TagObjectType, PropType::Integer32),
+    (PropTag::TagOriginatorDeliveryReportRequested, PropType::Boolean),
+    (PropTag::TagPriority, PropType::Integer32),
+    (PropTag::TagReadReceiptRequested, PropType::Boolean),
+    (PropTag::TagRecipientType, PropType::Integer32),
+    (PropTag::TagRecordKey, PropType::Binary),
+    (PropTag::TagRenderingPosition, PropType::Integer32),
+    (PropTag::TagRowid, PropType::Integer32),
+    (PropTag::TagRtfCompressed, PropType::Binary),
+    (PropTag::TagRtfInSync, PropType::Boolean),
+    (PropTag::TagSearchKey, PropType::Binary),
+    (PropTag::TagSenderAddressType, PropType::String),
+    (PropTag::TagSenderEmailAddress, PropType::String),
+    (PropTag::TagSenderEntryId, PropType::Binary),
+    (PropTag::TagSenderName, PropType::String),
+    (PropTag::TagSenderSmtpAddress, PropType::String),
+    (PropTag::TagSensitivity, PropType::Integer32),
+    (PropTag::TagSentRepresentingEmailAddress, PropType::String),
+    (PropTag::TagSentRepresentingName, PropType::String),
+    (PropTag::TagSentRepresentingSmtpAddress, PropType::String),
+    (PropTag::TagSmtpAddress, PropType::String),
+    (PropTag::TagStartDate, PropType::Time),
+    (PropTag::TagSubject, PropType::String),
+    (PropTag::TagSubjectPrefix, PropType::String),
+    (PropTag::TagTransportMessageHeaders, PropType::String),
+];
+
+pub fn expected_type(tag: PropTag) -> Option<PropType> {
+    EXPECTED_PROP_TYPES.iter()
+        .find(|(t, _pt)| *t == tag)
+        .map(|(_t, pt)| *pt)
+}
+
+fn is_string_type(prop_type: PropType) -> bool {
+    prop_type == PropType::String || prop_type == PropType::String8
+        || u16::from(prop_type) & PropType::CODEPAGE_STRING_BIT != 0
+}
+
+fn check_expected_type(tag: PropTag, prop_type: PropType) {
+    let expected = match expected_type(tag) {
+        Some(e) => e,
+        None => return,
+    };
+    // 8-bit, Unicode and codepage-specific strings are interchangeable
+    let matches = expected == prop_type
+        || (is_string_type(expected) && is_string_type(prop_type));
+    if !matches {
+        warn!("property {} has type {:?}, expected {:?}", tag, prop_type, expected);
+    }
+}
 
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropValue {
     Unspecified,
     Null,
@@ -87,12 +349,14 @@ pub enum PropValue {
     FloatingTime(f64),
     ErrorCode(u64),
     Boolean(bool),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::base64_bytes"))]
     Object(Vec<u8>),
     Integer64(i64),
     String8(String),
     String(String),
     Time(i64),
     Guid(Guid),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::base64_bytes"))]
     Binary(Vec<u8>),
     MultipleInteger16(Vec<i16>),
     MultipleInteger32(Vec<i32>),
@@ -105,8 +369,157 @@ pub enum PropValue {
     MultipleString(Vec<String>),
     MultipleTime(Vec<i64>),
     MultipleGuid(Vec<Guid>),
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::base64_byte_vecs"))]
     MultipleBinary(Vec<Vec<u8>>),
 }
+const CURRENCY_SCALE: f64 = 10_000.0;
+
+impl PropValue {
+    pub fn as_currency_f64(&self) -> Option<f64> {
+        match self {
+            Self::Currency(v) => Some(*v as f64 / CURRENCY_SCALE),
+            _ => None,
+        }
+    }
+
+    pub fn as_multiple_currency_f64(&self) -> Option<Vec<f64>> {
+        match self {
+            Self::MultipleCurrency(vs) => Some(vs.iter().map(|v| *v as f64 / CURRENCY_SCALE).collect()),
+            _ => None,
+        }
+    }
+}
+
+const BINARY_PREVIEW_LENGTH: usize = 16;
+
+fn fmt_currency(f: &mut fmt::Formatter<'_>, value: i64) -> fmt::Result {
+    // ten-thousandths of the unit
+    let sign = if value < 0 { "-" } else { "" };
+    let abs_value = value.unsigned_abs();
+    write!(f, "{}{}.{:04}", sign, abs_value / 10_000, abs_value % 10_000)
+}
+
+fn fmt_time(f: &mut fmt::Formatter<'_>, value: i64) -> fmt::Result {
+    match UtcDateTime::from_filetime(value) {
+        Some(dt) => write!(f, "{}", dt),
+        None => write!(f, "{} (invalid time)", value),
+    }
+}
+
+fn fmt_binary(f: &mut fmt::Formatter<'_>, value: &[u8]) -> fmt::Result {
+    write!(f, "<{} bytes", value.len())?;
+    if !value.is_empty() {
+        write!(f, ":")?;
+        for b in value.iter().take(BINARY_PREVIEW_LENGTH) {
+            write!(f, " {:02X}", b)?;
+        }
+        if value.len() > BINARY_PREVIEW_LENGTH {
+            write!(f, " ...")?;
+        }
+    }
+    write!(f, ">")
+}
+
+fn fmt_list<T, F: Fn(&mut fmt::Formatter<'_>, &T) -> fmt::Result>(f: &mut fmt::Formatter<'_>, values: &[T], fmt_value: F) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt_value(f, value)?;
+    }
+    write!(f, "]")
+}
+
+impl fmt::Display for PropValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unspecified => write!(f, "(unspecified)"),
+            Self::Null => write!(f, "(null)"),
+            Self::Integer16(v) => write!(f, "{}", v),
+            Self::Integer32(v) => write!(f, "{}", v),
+            Self::Floating32(v) => write!(f, "{}", v),
+            Self::Floating64(v) => write!(f, "{}", v),
+            Self::Currency(v) => fmt_currency(f, *v),
+            Self::FloatingTime(v) => write!(f, "{}", v),
+            Self::ErrorCode(v) => write!(f, "error 0x{:08X}", v),
+            Self::Boolean(v) => write!(f, "{}", v),
+            Self::Object(v) => fmt_binary(f, v),
+            Self::Integer64(v) => write!(f, "{}", v),
+            Self::String8(v) => write!(f, "{}", v),
+            Self::String(v) => write!(f, "{}", v),
+            Self::Time(v) => fmt_time(f, *v),
+            Self::Guid(v) => write!(f, "{}", v),
+            Self::Binary(v) => fmt_binary(f, v),
+            Self::MultipleInteger16(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleInteger32(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleFloating32(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleFloating64(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleCurrency(vs) => fmt_list(f, vs, |f, v| fmt_currency(f, *v)),
+            Self::MultipleFloatingTime(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleInteger64(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleString8(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleString(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleTime(vs) => fmt_list(f, vs, |f, v| fmt_time(f, *v)),
+            Self::MultipleGuid(vs) => fmt_list(f, vs, |f, v| write!(f, "{}", v)),
+            Self::MultipleBinary(vs) => fmt_list(f, vs, |f, v| fmt_binary(f, v)),
+        }
+    }
+}
+impl From<i16> for PropValue {
+    fn from(v: i16) -> Self { Self::Integer16(v) }
+}
+impl From<i32> for PropValue {
+    fn from(v: i32) -> Self { Self::Integer32(v) }
+}
+impl From<i64> for PropValue {
+    fn from(v: i64) -> Self { Self::Integer64(v) }
+}
+impl From<f32> for PropValue {
+    fn from(v: f32) -> Self { Self::Floating32(v) }
+}
+impl From<f64> for PropValue {
+    fn from(v: f64) -> Self { Self::Floating64(v) }
+}
+impl From<bool> for PropValue {
+    fn from(v: bool) -> Self { Self::Boolean(v) }
+}
+impl From<String> for PropValue {
+    fn from(v: String) -> Self { Self::String(v) }
+}
+impl<'a> From<&'a str> for PropValue {
+    fn from(v: &'a str) -> Self { Self::String(v.to_owned()) }
+}
+impl From<Guid> for PropValue {
+    fn from(v: Guid) -> Self { Self::Guid(v) }
+}
+impl From<Vec<u8>> for PropValue {
+    fn from(v: Vec<u8>) -> Self { Self::Binary(v) }
+}
+impl From<Vec<i16>> for PropValue {
+    fn from(v: Vec<i16>) -> Self { Self::MultipleInteger16(v) }
+}
+impl From<Vec<i32>> for PropValue {
+    fn from(v: Vec<i32>) -> Self { Self::MultipleInteger32(v) }
+}
+impl From<Vec<i64>> for PropValue {
+    fn from(v: Vec<i64>) -> Self { Self::MultipleInteger64(v) }
+}
+impl From<Vec<f32>> for PropValue {
+    fn from(v: Vec<f32>) -> Self { Self::MultipleFloating32(v) }
+}
+impl From<Vec<f64>> for PropValue {
+    fn from(v: Vec<f64>) -> Self { Self::MultipleFloating64(v) }
+}
+impl From<Vec<String>> for PropValue {
+    fn from(v: Vec<String>) -> Self { Self::MultipleString(v) }
+}
+impl From<Vec<Guid>> for PropValue {
+    fn from(v: Vec<Guid>) -> Self { Self::MultipleGuid(v) }
+}
+impl From<Vec<Vec<u8>>> for PropValue {
+    fn from(v: Vec<Vec<u8>>) -> Self { Self::MultipleBinary(v) }
+}
 
 #[derive(Clone, Debug, Eq, FromToRepr, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u32)]
@@ -134,6 +547,20 @@ pub enum TnefReadError {
     MultipleValuesSingleType { prop_type: PropType, count: u32 },
     InvalidString { obtained: Vec<u16>, error: FromUtf16Error },
     OddStringLength { byte_length: usize },
+    InvalidPropertyType { property_type: u16 },
+    UnknownCodepage { codepage: u16 },
+    UnsupportedMultiType { base: u16 },
+    AtOffset { offset: u64, error: Box<TnefReadError> },
+    InProperty { tag: PropTag, error: Box<TnefReadError> },
+}
+impl TnefReadError {
+    pub fn at_offset(self, offset: u64) -> Self {
+        match self {
+            // keep the innermost (most precise) offset
+            Self::AtOffset { .. } => self,
+            other => Self::AtOffset { offset, error: Box::new(other) },
+        }
+    }
 }
 impl fmt::Display for TnefReadError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -157,6 +584,16 @@ impl fmt::Display for TnefReadError {
                 => write!(f, "invalid UTF-16 string: {} (obtained {:?})", error, obtained),
             Self::OddStringLength { byte_length }
                 => write!(f, "odd length {} of UTF-16 string", byte_length),
+            Self::InvalidPropertyType { property_type }
+                => write!(f, "invalid property type 0x{:04X}", property_type),
+            Self::UnknownCodepage { codepage }
+                => write!(f, "unknown codepage {}", codepage),
+            Self::UnsupportedMultiType { base }
+                => write!(f, "unsupported multi-value property of base type 0x{:04X}", base),
+            Self::AtOffset { offset, error }
+                => write!(f, "at offset {} (0x{:X}): {}", offset, offset, error),
+            Self::InProperty { tag, error }
+                => write!(f, "in property {}: {}", tag, error),
         }
     }
 }
@@ -167,7 +604,37 @@ impl From<std::io::Error> for TnefReadError {
 }
 
 
-pub fn read_tnef<R: BufRead>(mut reader: R) -> Result<TnefFile, TnefReadError> {
+pub fn read_tnef<R: BufRead>(reader: R) -> Result<TnefFile, TnefReadError> {
+    read_tnef_with_options(reader, TnefReadOptions::default())
+}
+
+pub fn read_tnef_with_options<R: BufRead>(reader: R, options: TnefReadOptions) -> Result<TnefFile, TnefReadError> {
+    let mut attribute_iter = tnef_attributes_with_options(reader, options)?;
+    let attributes = (&mut attribute_iter)
+        .collect::<Result<Vec<TnefAttribute>, TnefReadError>>()?;
+    Ok(TnefFile {
+        legacy_key: attribute_iter.legacy_key(),
+        attributes,
+    })
+}
+
+pub fn tnef_attributes<R: BufRead>(reader: R) -> Result<TnefAttributes<R>, TnefReadError> {
+    tnef_attributes_with_options(reader, TnefReadOptions::default())
+}
+
+pub fn tnef_attributes_with_options<R: BufRead>(reader: R, options: TnefReadOptions) -> Result<TnefAttributes<R>, TnefReadError> {
+    let mut counting_reader = CountingReader::new(reader);
+    let legacy_key = read_tnef_header(&mut counting_reader)
+        .map_err(|e| e.at_offset(counting_reader.position()))?;
+    Ok(TnefAttributes {
+        reader: counting_reader,
+        options,
+        legacy_key,
+        finished: false,
+    })
+}
+
+fn read_tnef_header<R: BufRead>(mut reader: R) -> Result<u16, TnefReadError> {
     // read signature
     let signature = reader.read_u32_le()?;
     if signature != TNEF_SIGNATURE {
@@ -176,15 +643,31 @@ pub fn read_tnef<R: BufRead>(mut reader: R) -> Result<TnefFile, TnefReadError> {
 
     // obtain legacy key
     let legacy_key = reader.read_u16_le()?;
+    Ok(legacy_key)
+}
+
+
+// yields the attributes of a TNEF stream as they are read
+pub struct TnefAttributes<R> {
+    reader: CountingReader<R>,
+    options: TnefReadOptions,
+    legacy_key: u16,
+    finished: bool,
+}
+impl<R: BufRead> TnefAttributes<R> {
+    pub fn legacy_key(&self) -> u16 {
+        self.legacy_key
+    }
+
+    fn read_attribute(&mut self) -> Result<Option<TnefAttribute>, TnefReadError> {
+        let reader = &mut self.reader;
 
-    let mut attributes = Vec::new();
-    loop {
         // anything left?
         let attrib_level_u8 = match reader.read_u8() {
             Ok(al) => al,
             Err(e) => {
                 if e.kind() == io::ErrorKind::UnexpectedEof {
-                    break;
+                    return Ok(None);
                 } else {
                     return Err(e.into());
                 }
@@ -201,8 +684,7 @@ pub fn read_tnef<R: BufRead>(mut reader: R) -> Result<TnefFile, TnefReadError> {
             Err(_) => return Err(TnefReadError::LengthConversion { obtained: length_i32 }),
         };
 
-        let mut data_buf = vec![0u8; length];
-        reader.read_exact(&mut data_buf)?;
+        let data_buf = reader.read_vec(length)?;
 
         let checksum = reader.read_u16_le()?;
 
@@ -213,24 +695,78 @@ pub fn read_tnef<R: BufRead>(mut reader: R) -> Result<TnefFile, TnefReadError> {
         }
 
         if checksum != my_checksum {
-            return Err(TnefReadError::ChecksumMismatch { obtained: checksum, calculated: my_checksum });
+            if self.options.verify_checksums {
+                return Err(TnefReadError::ChecksumMismatch { obtained: checksum, calculated: my_checksum });
+            }
+            warn!(
+                "checksum mismatch in attribute {:?}.{:?}: calculated 0x{:04X}, obtained 0x{:04X}; keeping it anyway",
+                attrib_level, attrib_id, my_checksum, checksum,
+            );
         }
 
-        attributes.push(TnefAttribute {
+        Ok(Some(TnefAttribute {
             level: attrib_level,
             id: attrib_id,
             data: data_buf,
             checksum,
-        })
+        }))
     }
+}
+impl<R: BufRead> Iterator for TnefAttributes<R> {
+    type Item = Result<TnefAttribute, TnefReadError>;
 
-    Ok(TnefFile {
-        legacy_key,
-        attributes,
-    })
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.read_attribute() {
+            Ok(Some(attribute)) => Some(Ok(attribute)),
+            Ok(None) => {
+                self.finished = true;
+                None
+            },
+            Err(e) => {
+                // an error leaves the stream at an unknown position
+                self.finished = true;
+                Some(Err(e.at_offset(self.reader.position())))
+            },
+        }
+    }
 }
 
-fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Result<Property, TnefReadError> {
+pub fn write_tnef<W: Write>(writer: &mut W, file: &TnefFile) -> Result<(), io::Error> {
+    writer.write_u32_le(TNEF_SIGNATURE)?;
+    writer.write_u16_le(file.legacy_key)?;
+
+    for attribute in &file.attributes {
+        let level: u8 = attribute.level.into();
+        let id: u32 = attribute.id.into();
+        let length: i32 = match attribute.data.len().try_into() {
+            Ok(l) => l,
+            Err(_) => return Err(io::Error::new(io::ErrorKind::InvalidInput, "attribute data too long")),
+        };
+
+        // the stored checksum might be wrong; always write the correct one
+        let mut checksum = 0u16;
+        for &b in &attribute.data {
+            checksum = checksum.wrapping_add(b.into());
+        }
+
+        writer.write_u8(level)?;
+        writer.write_u32_le(id)?;
+        writer.write_i32_le(length)?;
+        writer.write_all(&attribute.data)?;
+        writer.write_u16_le(checksum)?;
+    }
+
+    Ok(())
+}
+
+pub fn decode_property<R: BufRead>(reader: R, encoding: &'static Encoding) -> Result<Property, TnefReadError> {
+    decode_property_with_options(reader, encoding, TnefReadOptions::default())
+}
+
+pub fn decode_property_with_options<R: BufRead>(mut reader: R, encoding: &'static Encoding, options: TnefReadOptions) -> Result<Property, TnefReadError> {
     debug!("new property");
 
     let prop_type_u16 = reader.read_u16_le()?;
@@ -245,8 +781,7 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
 
     let prop_full_id = if prop_tag_u16 >= 0x8000 {
         // named property
-        let mut guid_buf = [0u8; 16];
-        reader.read_exact(&mut guid_buf)?;
+        let guid_buf: [u8; 16] = BinaryReader::read_array(&mut reader)?;
         let guid = Guid::from_le_bytes(&guid_buf).unwrap();
         debug!("guid: {}", guid);
 
@@ -261,7 +796,7 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
         let id = match id_type {
             PropIdType::Number => {
                 let prop_id = reader.read_u32_le()?;
-                reader.pad_to_4(4)?;
+                skip_padding(&mut reader, 4, options)?;
                 debug!("numeric prop id: {0} (0x{0:08x})", prop_id);
                 PropId::Number(prop_id)
             },
@@ -279,7 +814,7 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
                 }
 
                 // swallow padding
-                reader.pad_to_4(length_bytes.try_into().unwrap())?;
+                skip_padding(&mut reader, length_bytes.try_into().unwrap(), options)?;
 
                 let prop_id = match String::from_utf16(&chars) {
                     Ok(pi) => pi,
@@ -292,45 +827,88 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
 
         Some((guid, id))
     } else {
+        check_expected_type(prop_tag, prop_type);
         None
     };
 
+    let (prop_value, raw_value) = decode_property_value(&mut reader, prop_type, encoding, options)
+        .map_err(|e| TnefReadError::InProperty { tag: prop_tag, error: Box::new(e) })?;
+
+    let prop = Property {
+        tag: prop_tag,
+        id: prop_full_id,
+        value: prop_value,
+        raw_value,
+    };
+    Ok(prop)
+}
+
+pub fn decode_ansi_string(bytes: &[u8], encoding: &'static Encoding) -> String {
+    // undecodable sequences become U+FFFD
+    let (decoded, _bad_sequences) = encoding.decode_with_bom_removal(bytes);
+    decoded.into_owned()
+}
+
+
+fn skip_padding<R: BufRead>(reader: &mut R, bytes_read: usize, options: TnefReadOptions) -> Result<(), io::Error> {
+    if options.verify_padding {
+        reader.pad_to_4_strict(bytes_read)
+    } else {
+        reader.pad_to_4(bytes_read)
+    }
+}
+
+fn read_multi_fixed<R: BufRead, T, F: FnMut(&mut R) -> Result<T, io::Error>>(reader: &mut R, value_size: usize, options: TnefReadOptions, mut read_one: F) -> Result<Vec<T>, TnefReadError> {
+    // value count, then the values; [MS-OXTNEF] pads each value to a multiple of 4 bytes
+    // instead of the array as a whole, which only makes a difference for 16-bit values
+    let value_count = reader.read_u32_le()?;
+    let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
+    for _ in 0..value_count {
+        let val = read_one(reader)?;
+        skip_padding(reader, value_size, options)?;
+        vals.push(val);
+    }
+    Ok(vals)
+}
+
+fn decode_property_value<R: BufRead>(mut reader: R, prop_type: PropType, encoding: &'static Encoding, options: TnefReadOptions) -> Result<(PropValue, Option<Vec<u8>>), TnefReadError> {
+    let mut raw_value = None;
     let prop_value = match prop_type {
         PropType::Unspecified => PropValue::Unspecified,
         PropType::Null => PropValue::Null,
         PropType::Integer16 => {
             let val = reader.read_i16_le()?;
-            reader.pad_to_4(2)?;
+            skip_padding(&mut reader, 2, options)?;
             PropValue::Integer16(val)
         },
         PropType::Integer32 => {
             let val = reader.read_i32_le()?;
-            reader.pad_to_4(4)?;
+            skip_padding(&mut reader, 4, options)?;
             PropValue::Integer32(val)
         },
         PropType::Floating32 => {
             let val = reader.read_f32_le()?;
-            reader.pad_to_4(4)?;
+            skip_padding(&mut reader, 4, options)?;
             PropValue::Floating32(val)
         },
         PropType::Floating64 => {
             let val = reader.read_f64_le()?;
-            reader.pad_to_4(8)?;
+            skip_padding(&mut reader, 8, options)?;
             PropValue::Floating64(val)
         },
         PropType::Currency => {
             let val = reader.read_i64_le()?;
-            reader.pad_to_4(8)?;
+            skip_padding(&mut reader, 8, options)?;
             PropValue::Currency(val)
         },
         PropType::FloatingTime => {
             let val = reader.read_f64_le()?;
-            reader.pad_to_4(8)?;
+            skip_padding(&mut reader, 8, options)?;
             PropValue::FloatingTime(val)
         },
         PropType::ErrorCode => {
             let val = reader.read_u64_le()?;
-            reader.pad_to_4(8)?;
+            skip_padding(&mut reader, 8, options)?;
             PropValue::ErrorCode(val)
         },
         PropType::Boolean => {
@@ -340,7 +918,7 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
                 0x01 => true,
                 other => return Err(TnefReadError::InvalidBoolean { obtained: other }),
             };
-            reader.pad_to_4(1)?;
+            skip_padding(&mut reader, 1, options)?;
             PropValue::Boolean(val)
         },
         PropType::Object => {
@@ -351,98 +929,54 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
 
             let byte_count_u32 = reader.read_u32_le()?;
             let byte_count: usize = byte_count_u32.try_into().unwrap();
-            let mut bytes = vec![0u8; byte_count];
-            reader.read_exact(&mut bytes)?;
+            let bytes = reader.read_vec(byte_count)?;
 
             // possible padding
-            reader.pad_to_4(byte_count)?;
+            skip_padding(&mut reader, byte_count, options)?;
 
             PropValue::Object(bytes)
         },
         PropType::Integer64 => {
             let val = reader.read_i64_le()?;
-            reader.pad_to_4(8)?;
+            skip_padding(&mut reader, 8, options)?;
             PropValue::Integer64(val)
         },
         PropType::Time => {
             let val = reader.read_i64_le()?;
-            reader.pad_to_4(8)?;
+            skip_padding(&mut reader, 8, options)?;
             PropValue::Time(val)
         },
         PropType::Guid => {
-            let mut buf = [0u8; 16];
-            reader.read_exact(&mut buf)?;
+            let buf: [u8; 16] = BinaryReader::read_array(&mut reader)?;
             let guid = Guid::from_le_bytes(&buf).unwrap();
             PropValue::Guid(guid)
         },
         PropType::MultipleInteger16 => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_i16_le()?;
-                reader.pad_to_4(2)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 2, options, |r| r.read_i16_le())?;
             PropValue::MultipleInteger16(vals)
         },
         PropType::MultipleInteger32 => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_i32_le()?;
-                reader.pad_to_4(4)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 4, options, |r| r.read_i32_le())?;
             PropValue::MultipleInteger32(vals)
         },
         PropType::MultipleFloating32 => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_f32_le()?;
-                reader.pad_to_4(4)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 4, options, |r| r.read_f32_le())?;
             PropValue::MultipleFloating32(vals)
         },
         PropType::MultipleFloating64 => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_f64_le()?;
-                reader.pad_to_4(8)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 8, options, |r| r.read_f64_le())?;
             PropValue::MultipleFloating64(vals)
         },
         PropType::MultipleCurrency => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_i64_le()?;
-                reader.pad_to_4(8)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 8, options, |r| r.read_i64_le())?;
             PropValue::MultipleCurrency(vals)
         },
         PropType::MultipleFloatingTime => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_f64_le()?;
-                reader.pad_to_4(8)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 8, options, |r| r.read_f64_le())?;
             PropValue::MultipleFloatingTime(vals)
         },
         PropType::MultipleInteger64 => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_i64_le()?;
-                reader.pad_to_4(4)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 8, options, |r| r.read_i64_le())?;
             PropValue::MultipleInteger64(vals)
         },
         PropType::String8|PropType::MultipleString8 => {
@@ -455,14 +989,15 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
             for _ in 0..value_count {
                 let byte_count_u32 = reader.read_u32_le()?;
                 let byte_count: usize = byte_count_u32.try_into().unwrap();
-                let mut bytes = vec![0u8; byte_count];
-                reader.read_exact(&mut bytes)?;
+                let bytes = reader.read_vec(byte_count)?;
 
-                let (cow_string, _bad_sequences) = encoding.decode_with_bom_removal(&bytes);
-                let string = cow_string.into_owned();
+                let string = decode_ansi_string(&bytes, encoding);
+                if prop_type == PropType::String8 {
+                    raw_value = Some(bytes);
+                }
 
                 // possible padding
-                reader.pad_to_4(byte_count)?;
+                skip_padding(&mut reader, byte_count, options)?;
 
                 values.push(string);
             }
@@ -486,7 +1021,7 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
                 let byte_count_u32 = reader.read_u32_le()?;
                 let byte_count: usize = byte_count_u32.try_into().unwrap();
                 debug!("string value has {} bytes", byte_count);
-                if byte_count % 2 != 0 {
+                if byte_count % 2 != 0 && !options.allow_odd_string_lengths {
                     return Err(TnefReadError::OddStringLength { byte_length: byte_count });
                 }
                 let char_count = byte_count / 2;
@@ -495,6 +1030,14 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
                     let char = reader.read_u16_le()?;
                     chars.push(char);
                 }
+                if byte_count % 2 != 0 {
+                    warn!("UTF-16 string value has odd length {}; dropping the stray byte", byte_count);
+                    reader.skip(1)?;
+                }
+
+                if prop_type == PropType::String {
+                    raw_value = Some(chars.iter().flat_map(|c| c.to_le_bytes()).collect());
+                }
 
                 let string = match String::from_utf16(&chars) {
                     Ok(s) => s,
@@ -502,7 +1045,7 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
                 };
 
                 // possible padding
-                reader.pad_to_4(char_count * 2)?;
+                skip_padding(&mut reader, byte_count, options)?;
 
                 values.push(string);
             }
@@ -515,24 +1058,14 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
             }
         },
         PropType::MultipleTime => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let val = reader.read_i64_le()?;
-                reader.pad_to_4(4)?;
-                vals.push(val);
-            }
+            let vals = read_multi_fixed(&mut reader, 8, options, |r| r.read_i64_le())?;
             PropValue::MultipleTime(vals)
         },
         PropType::MultipleGuid => {
-            let value_count = reader.read_u32_le()?;
-            let mut vals = Vec::with_capacity(value_count.try_into().unwrap());
-            for _ in 0..value_count {
-                let mut buf = [0u8; 16];
-                reader.read_exact(&mut buf)?;
-                let guid = Guid::from_le_bytes(&buf).unwrap();
-                vals.push(guid)
-            }
+            let vals = read_multi_fixed(&mut reader, 16, options, |r| {
+                let buf: [u8; 16] = BinaryReader::read_array(r)?;
+                Ok(Guid::from_le_bytes(&buf).unwrap())
+            })?;
             PropValue::MultipleGuid(vals)
         },
         PropType::Binary|PropType::MultipleBinary => {
@@ -547,11 +1080,10 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
                 let byte_count_u32 = reader.read_u32_le()?;
                 let byte_count: usize = byte_count_u32.try_into().unwrap();
                 debug!("byte count: {}", byte_count);
-                let mut bytes = vec![0u8; byte_count];
-                reader.read_exact(&mut bytes)?;
+                let bytes = reader.read_vec(byte_count)?;
 
                 // possible padding
-                reader.pad_to_4(byte_count)?;
+                skip_padding(&mut reader, byte_count, options)?;
 
                 values.push(bytes);
             }
@@ -564,39 +1096,614 @@ fn decode_property<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Re
             }
         },
         PropType::Other(other) => {
-            let mut buf = [0u8; 128];
-            reader.read_exact(&mut buf)?;
-            error!("unknown type {}", other);
-            crate::hexdump(&buf, "");
-            panic!();
+            if other & 0x8000 == 0 {
+                if prop_type.is_multiple() {
+                    return Err(TnefReadError::UnsupportedMultiType { base: prop_type.single_type().into() });
+                }
+                return Err(TnefReadError::InvalidPropertyType { property_type: other });
+            }
+
+            // string in a specific codepage
+            let codepage = other & 0x7FFF;
+            debug!("codepage string: codepage {}", codepage);
+            let codepage_encoding = match to_encoding(codepage) {
+                Some(e) => e,
+                None => return Err(TnefReadError::UnknownCodepage { codepage }),
+            };
+
+            let value_count = reader.read_u32_le()?;
+            if value_count != 1 {
+                return Err(TnefReadError::MultipleValuesSingleType { prop_type, count: value_count });
+            }
+
+            let byte_count_u32 = reader.read_u32_le()?;
+            let byte_count: usize = byte_count_u32.try_into().unwrap();
+            let bytes = reader.read_vec(byte_count)?;
+
+            let string = decode_ansi_string(&bytes, codepage_encoding);
+            raw_value = Some(bytes);
+
+            // possible padding
+            skip_padding(&mut reader, byte_count, options)?;
+
+            PropValue::String(string)
         },
     };
+    Ok((prop_value, raw_value))
+}
 
-    let prop = Property {
-        tag: prop_tag,
-        id: prop_full_id,
-        value: prop_value,
+pub fn resolve_named_property(guid: &Guid, id: &PropId) -> Option<&'static str> {
+    match id {
+        PropId::Number(long_id) => PidLid::from_long_id(guid, *long_id)
+            .map(|pid_lid| pid_lid.name()),
+        PropId::String(_) => None,
+    }
+}
+
+const MESSAGE_FLAGS: &[(i32, &str)] = &[
+    (0x0000_0001, "mfRead"),
+    (0x0000_0002, "mfUnmodified"),
+    (0x0000_0004, "mfSubmitted"),
+    (0x0000_0008, "mfUnsent"),
+    (0x0000_0010, "mfHasAttach"),
+    (0x0000_0020, "mfFromMe"),
+    (0x0000_0040, "mfFAI"),
+    (0x0000_0080, "mfResend"),
+    (0x0000_0100, "mfNotifyRead"),
+    (0x0000_0200, "mfNotifyUnread"),
+    (0x0000_0400, "mfEverRead"),
+    (0x0000_2000, "mfInternet"),
+    (0x0000_8000, "mfUntrusted"),
+];
+const MESSAGE_STATUS_FLAGS: &[(i32, &str)] = &[
+    (0x0000_0800, "msInConflict"),
+    (0x0000_1000, "msRemoteDownload"),
+    (0x0000_2000, "msRemoteDelete"),
+];
+const ATTACH_FLAGS: &[(i32, &str)] = &[
+    (0x0000_0001, "afInvisibleInHtml"),
+    (0x0000_0002, "afInvisibleInRtf"),
+    (0x0000_0004, "afRenderedInBody"),
+];
+const ACCESS_FLAGS: &[(i32, &str)] = &[
+    (0x0000_0001, "Modify"),
+    (0x0000_0002, "Read"),
+    (0x0000_0004, "Delete"),
+    (0x0000_0008, "CreateHierarchy"),
+    (0x0000_0010, "CreateContents"),
+    (0x0000_0020, "CreateAssociated"),
+];
+const RECIPIENT_FLAGS: &[(i32, &str)] = &[
+    (0x0000_0001, "recipSendable"),
+    (0x0000_0002, "recipOrganizer"),
+    (0x0000_0010, "recipExceptionalResponse"),
+    (0x0000_0020, "recipExceptionalDeleted"),
+    (0x0000_0100, "recipOriginal"),
+];
+
+pub fn decode_flags(tag: PropTag, value: i32) -> Vec<&'static str> {
+    let table = match tag {
+        PropTag::TagMessageFlags => MESSAGE_FLAGS,
+        PropTag::TagMessageStatus => MESSAGE_STATUS_FLAGS,
+        PropTag::TagAttachFlags => ATTACH_FLAGS,
+        PropTag::TagAccess => ACCESS_FLAGS,
+        PropTag::TagRecipientFlags => RECIPIENT_FLAGS,
+        _ => return Vec::new(),
     };
-    Ok(prop)
+    table.iter()
+        .filter(|(bit, _name)| value & bit != 0)
+        .map(|(_bit, name)| *name)
+        .collect()
+}
+
+const ONE_OFF_PROVIDER_UID: [u8; 16] = [
+    0x81, 0x2B, 0x1F, 0xA4, 0xBE, 0xA3, 0x10, 0x19, 0x9D, 0x6E, 0x00, 0xDD, 0x01, 0x0F, 0x54, 0x02,
+];
+const ONE_OFF_UNICODE: u16 = 0x8000;
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OneOffEntryId {
+    pub display_name: String,
+    pub address_type: String,
+    pub email_address: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AttachRenderData {
+    pub attach_type: u16,
+    pub position: u32,
+    pub width: u16,
+    pub height: u16,
+    pub flags: u32,
+}
+impl AttachRenderData {
+    pub const TYPE_FILE: u16 = 0x0001;
+    pub const TYPE_OLE: u16 = 0x0002;
+
+    pub fn is_ole(&self) -> bool {
+        self.attach_type == Self::TYPE_OLE
+    }
+}
+
+pub fn parse_attach_render_data(data: &[u8]) -> Option<AttachRenderData> {
+    if data.len() < 14 {
+        return None;
+    }
+    Some(AttachRenderData {
+        attach_type: u16::from_le_bytes([data[0], data[1]]),
+        position: u32::from_le_bytes([data[2], data[3], data[4], data[5]]),
+        width: u16::from_le_bytes([data[6], data[7]]),
+        height: u16::from_le_bytes([data[8], data[9]]),
+        flags: u32::from_le_bytes([data[10], data[11], data[12], data[13]]),
+    })
+}
+
+pub fn parse_tnef_date(data: &[u8]) -> Option<UtcDateTime> {
+    // year, month, day, hour, minute, second and day of week as 16-bit values
+    if data.len() < 14 {
+        return None;
+    }
+    let field = |i: usize| u16::from_le_bytes([data[2*i], data[2*i + 1]]);
+    let narrow = |i: usize| u8::try_from(field(i)).ok();
+
+    // the day of week is redundant and computed anew
+    UtcDateTime::from_parts(
+        i64::from(field(0)),
+        narrow(1)?,
+        narrow(2)?,
+        narrow(3)?,
+        narrow(4)?,
+        narrow(5)?,
+    )
 }
 
-pub fn decode_properties<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Result<Vec<Property>, TnefReadError> {
+
+pub fn parse_one_off_entry_id(entry_id: &[u8], encoding: &'static Encoding) -> Option<OneOffEntryId> {
+    // flags (4), provider UID (16), version (2), flags (2), then three strings
+    if entry_id.len() < 24 || entry_id[4..20] != ONE_OFF_PROVIDER_UID {
+        return None;
+    }
+    let flags = u16::from_le_bytes([entry_id[22], entry_id[23]]);
+    let mut rest = &entry_id[24..];
+
+    let mut strings = Vec::with_capacity(3);
+    for _ in 0..3 {
+        if flags & ONE_OFF_UNICODE != 0 {
+            let units: Vec<u16> = rest.chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .take_while(|&u| u != 0)
+                .collect();
+            let byte_length = units.len() * 2;
+            if rest.len() < byte_length + 2 {
+                return None;
+            }
+            strings.push(String::from_utf16(&units).ok()?);
+            rest = &rest[byte_length+2..];
+        } else {
+            let nul_pos = rest.iter().position(|&b| b == 0)?;
+            strings.push(decode_ansi_string(&rest[..nul_pos], encoding));
+            rest = &rest[nul_pos+1..];
+        }
+    }
+
+    let email_address = strings.pop().unwrap();
+    let address_type = strings.pop().unwrap();
+    let display_name = strings.pop().unwrap();
+    Some(OneOffEntryId {
+        display_name,
+        address_type,
+        email_address,
+    })
+}
+
+pub fn group_by_property_set(properties: &[Property]) -> HashMap<Guid, Vec<&Property>> {
+    let mut groups: HashMap<Guid, Vec<&Property>> = HashMap::new();
+    for property in properties {
+        if let Some((guid, _id)) = &property.id {
+            groups.entry(*guid).or_default().push(property);
+        }
+    }
+    groups
+}
+
+pub fn group_by_property_set_with_tagged(properties: &[Property]) -> HashMap<Option<Guid>, Vec<&Property>> {
+    // tagged properties are collected under None
+    let mut groups: HashMap<Option<Guid>, Vec<&Property>> = HashMap::new();
+    for property in properties {
+        let guid = property.id.as_ref().map(|(guid, _id)| *guid);
+        groups.entry(guid).or_default().push(property);
+    }
+    groups
+}
+
+pub fn raw_property_bytes(properties: &[Property], tag: PropTag) -> Option<&[u8]> {
+    properties.iter()
+        .filter(|p| p.tag == tag)
+        .filter_map(|p| p.raw_bytes())
+        .next()
+}
+
+pub fn decode_properties<R: BufRead>(reader: R, encoding: &'static Encoding) -> Result<Vec<Property>, TnefReadError> {
+    decode_properties_with_options(reader, encoding, TnefReadOptions::default())
+}
+
+pub fn decode_properties_with_options<R: BufRead>(reader: R, encoding: &'static Encoding, options: TnefReadOptions) -> Result<Vec<Property>, TnefReadError> {
+    let mut counting_reader = CountingReader::new(reader);
+    decode_properties_counting(&mut counting_reader, encoding, options)
+        .map_err(|e| e.at_offset(counting_reader.position()))
+}
+
+fn decode_properties_counting<R: BufRead>(mut reader: R, encoding: &'static Encoding, options: TnefReadOptions) -> Result<Vec<Property>, TnefReadError> {
     let prop_count: usize = reader.read_u32_le()?.try_into().unwrap();
     debug!("prop count: {}", prop_count);
     let mut properties = Vec::with_capacity(prop_count);
     for _ in 0..prop_count {
-        let property = decode_property(&mut reader, encoding)?;
+        let property = decode_property_with_options(&mut reader, encoding, options)?;
         properties.push(property);
     }
     Ok(properties)
 }
 
-pub fn decode_property_lists<R: BufRead>(mut reader: R, encoding: &'static Encoding) -> Result<Vec<Vec<Property>>, TnefReadError> {
+pub fn decode_properties_lenient<R: BufRead>(reader: R, encoding: &'static Encoding, options: TnefReadOptions) -> (Vec<Property>, Option<TnefReadError>) {
+    // the properties are not framed individually, so decoding cannot resume after a bad one;
+    // return those preceding it
+    let mut counting_reader = CountingReader::new(reader);
+    let mut properties = Vec::new();
+    let prop_count = match counting_reader.read_u32_le() {
+        Ok(pc) => pc,
+        Err(e) => return (properties, Some(TnefReadError::from(e).at_offset(counting_reader.position()))),
+    };
+    for index in 0..prop_count {
+        match decode_property_with_options(&mut counting_reader, encoding, options) {
+            Ok(property) => properties.push(property),
+            Err(e) => {
+                warn!("failed to decode property {} of {}: {}; keeping the preceding ones", index, prop_count, e);
+                return (properties, Some(e.at_offset(counting_reader.position())));
+            },
+        }
+    }
+    (properties, None)
+}
+
+pub fn decode_property_lists<R: BufRead>(reader: R, encoding: &'static Encoding) -> Result<Vec<Vec<Property>>, TnefReadError> {
+    decode_property_lists_with_options(reader, encoding, TnefReadOptions::default())
+}
+
+pub fn decode_property_lists_with_options<R: BufRead>(reader: R, encoding: &'static Encoding, options: TnefReadOptions) -> Result<Vec<Vec<Property>>, TnefReadError> {
+    let mut counting_reader = CountingReader::new(reader);
+    decode_property_lists_counting(&mut counting_reader, encoding, options)
+        .map_err(|e| e.at_offset(counting_reader.position()))
+}
+
+fn decode_property_lists_counting<R: BufRead>(mut reader: R, encoding: &'static Encoding, options: TnefReadOptions) -> Result<Vec<Vec<Property>>, TnefReadError> {
     let list_count: usize = reader.read_u32_le()?.try_into().unwrap();
     let mut property_lists = Vec::with_capacity(list_count);
     for _ in 0..list_count {
-        let property_list = decode_properties(&mut reader, encoding)?;
+        let property_list = decode_properties_counting(&mut reader, encoding, options)?;
         property_lists.push(property_list);
     }
     Ok(property_lists)
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(level: TnefAttributeLevel, id: TnefAttributeId, data: &[u8]) -> TnefAttribute {
+        let checksum = data.iter()
+            .fold(0u16, |sum, &b| sum.wrapping_add(b.into()));
+        TnefAttribute { level, id, data: data.to_vec(), checksum }
+    }
+
+    fn sample_file() -> TnefFile {
+        TnefFile {
+            legacy_key: 0x1234,
+            attributes: vec![
+                attribute(TnefAttributeLevel::Message, TnefAttributeId::OemCodepage, &[0xE4, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+                attribute(TnefAttributeLevel::Message, TnefAttributeId::MessageClass, b"IPM.Note\0"),
+                attribute(TnefAttributeLevel::Message, TnefAttributeId::Subject, b"Gr\xFC\xDFe\0"),
+            ],
+        }
+    }
+
+    #[test]
+    fn write_tnef_round_trip() {
+        let file = sample_file();
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &file).unwrap();
+        let read_back = read_tnef(Cursor::new(&buf)).unwrap();
+        assert_eq!(read_back, file);
+    }
+
+    #[test]
+    fn write_tnef_recomputes_checksum() {
+        let mut file = sample_file();
+        file.attributes[1].checksum = 0xDEAD;
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &file).unwrap();
+        let read_back = read_tnef(Cursor::new(&buf)).unwrap();
+        assert_eq!(read_back, sample_file());
+    }
+
+    #[test]
+    fn checksum_mismatch_is_fatal_only_when_verifying() {
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &sample_file()).unwrap();
+        // the stream ends with the last attribute's checksum
+        let checksum_pos = buf.len() - 2;
+        buf[checksum_pos] ^= 0xFF;
+
+        match read_tnef(Cursor::new(&buf)) {
+            Err(TnefReadError::AtOffset { error, .. }) => assert!(matches!(*error, TnefReadError::ChecksumMismatch { .. })),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let lenient = TnefReadOptions {
+            verify_checksums: false,
+            ..TnefReadOptions::default()
+        };
+        let read_back = read_tnef_with_options(Cursor::new(&buf), lenient).unwrap();
+        assert_eq!(read_back.attributes.len(), 3);
+        assert_eq!(read_back.attributes[2].data, b"Gr\xFC\xDFe\0");
+    }
+
+    #[test]
+    fn attribute_decoding_stops_at_the_attribute_end() {
+        // a string claiming more bytes than its attribute holds
+        let mut props = Vec::new();
+        props.extend_from_slice(&1u32.to_le_bytes());
+        props.extend_from_slice(&[0x1E, 0x00, 0x37, 0x00]);
+        props.extend_from_slice(&1u32.to_le_bytes());
+        props.extend_from_slice(&64u32.to_le_bytes());
+        props.extend_from_slice(b"abcd");
+        let file = TnefFile {
+            legacy_key: 0,
+            attributes: vec![
+                attribute(TnefAttributeLevel::Message, TnefAttributeId::MsgProps, &props),
+                attribute(TnefAttributeLevel::Attachment, TnefAttributeId::AttachData, &[0x41; 128]),
+            ],
+        };
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &file).unwrap();
+
+        // signature, legacy key, then level, ID and length of the first attribute
+        let payload = &buf[4 + 2 + 1 + 4 + 4..];
+        let props_len = u64::try_from(props.len()).unwrap();
+        assert!(decode_properties(Cursor::new(payload), UTF_8).is_ok());
+        assert!(matches!(
+            decode_properties(LimitReader::new(Cursor::new(payload), props_len), UTF_8),
+            Err(TnefReadError::AtOffset { .. }),
+        ));
+
+        let read_back = read_tnef(Cursor::new(&buf)).unwrap();
+        assert!(decode_properties(read_back.attributes[0].data_reader(), UTF_8).is_err());
+    }
+
+    #[test]
+    fn verifies_padding_only_when_asked() {
+        // PT_I2 PidTagImportance with garbage in its padding
+        let buf = [0x02, 0x00, 0x17, 0x00, 0x01, 0x00, 0xAB, 0xCD];
+        let property = decode_property(Cursor::new(&buf), UTF_8).unwrap();
+        assert_eq!(property.value, PropValue::Integer16(1));
+
+        let strict = TnefReadOptions {
+            verify_padding: true,
+            ..TnefReadOptions::default()
+        };
+        match decode_property_with_options(Cursor::new(&buf), UTF_8, strict) {
+            Err(TnefReadError::InProperty { error, .. }) => assert!(matches!(
+                *error,
+                TnefReadError::Io(ref e) if e.kind() == io::ErrorKind::InvalidData,
+            )),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let zero_padded = [0x02, 0x00, 0x17, 0x00, 0x01, 0x00, 0x00, 0x00];
+        assert!(decode_property_with_options(Cursor::new(&zero_padded), UTF_8, strict).is_ok());
+    }
+
+    #[test]
+    fn tnef_attributes_stops_after_error() {
+        let mut buf = Vec::new();
+        write_tnef(&mut buf, &sample_file()).unwrap();
+        // cut the last attribute short
+        buf.truncate(buf.len() - 4);
+
+        let mut attributes = tnef_attributes(Cursor::new(&buf)).unwrap();
+        assert_eq!(attributes.legacy_key(), 0x1234);
+        assert_eq!(attributes.next().unwrap().unwrap().id, TnefAttributeId::OemCodepage);
+        assert_eq!(attributes.next().unwrap().unwrap().id, TnefAttributeId::MessageClass);
+        assert!(attributes.next().unwrap().is_err());
+        assert!(attributes.next().is_none());
+    }
+
+    #[test]
+    fn tnef_attributes_rejects_bad_signature() {
+        let buf = [0x78, 0x9F, 0x3E, 0x23, 0x34, 0x12];
+        match tnef_attributes(Cursor::new(&buf)) {
+            Err(TnefReadError::AtOffset { error, .. }) => assert!(matches!(*error, TnefReadError::Signature { obtained: 0x233E9F78, .. })),
+            Err(other) => panic!("unexpected error {:?}", other),
+            Ok(_) => panic!("bad signature accepted"),
+        }
+    }
+
+    fn tagged(tag: PropTag, value: PropValue) -> Property {
+        Property::new(tag, None, value)
+    }
+
+    fn named(guid: Guid, id: u32, value: PropValue) -> Property {
+        Property::new(PropTag::Other(0x8000), Some((guid, PropId::Number(id))), value)
+    }
+
+    #[test]
+    fn groups_properties_by_set() {
+        let public_strings = Guid::parse_str("00020329-0000-0000-C000-000000000046").unwrap();
+        let common = Guid::parse_str("00062008-0000-0000-C000-000000000046").unwrap();
+        let properties = vec![
+            tagged(PropTag::TagSubject, "Hello".into()),
+            named(public_strings, 0x8001, 1i32.into()),
+            named(common, 0x8501, 15i32.into()),
+            named(public_strings, 0x8002, 2i32.into()),
+        ];
+
+        let groups = group_by_property_set(&properties);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&public_strings], vec![&properties[1], &properties[3]]);
+        assert_eq!(groups[&common], vec![&properties[2]]);
+
+        let groups = group_by_property_set_with_tagged(&properties);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&None], vec![&properties[0]]);
+        assert_eq!(groups[&Some(public_strings)], vec![&properties[1], &properties[3]]);
+    }
+
+    #[test]
+    fn scales_currency_values() {
+        assert_eq!(PropValue::Currency(12_3450).as_currency_f64(), Some(12.345));
+        assert_eq!(PropValue::Currency(-5000).as_currency_f64(), Some(-0.5));
+        assert_eq!(PropValue::Integer64(12_3450).as_currency_f64(), None);
+
+        assert_eq!(PropValue::MultipleCurrency(vec![10000, 25]).as_multiple_currency_f64(), Some(vec![1.0, 0.0025]));
+        assert_eq!(PropValue::Currency(10000).as_multiple_currency_f64(), None);
+    }
+
+    #[test]
+    fn keeps_raw_bytes_of_8bit_strings() {
+        // PT_STRING8 PidTagSubject, one value of 6 bytes padded to 8
+        let mut buf = vec![0x1E, 0x00, 0x37, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00];
+        buf.extend_from_slice(b"Gr\xFC\xDFe\0\0\0");
+
+        let windows_1252 = to_encoding(1252).unwrap();
+        let property = decode_property(Cursor::new(&buf), windows_1252).unwrap();
+        assert_eq!(property.tag, PropTag::TagSubject);
+        assert_eq!(property.value, PropValue::String8("Gr\u{FC}\u{DF}e\0".to_owned()));
+        assert_eq!(property.raw_bytes(), Some(&b"Gr\xFC\xDFe\0"[..]));
+
+        let properties = vec![tagged(PropTag::TagBody, "text".into()), property];
+        assert_eq!(raw_property_bytes(&properties, PropTag::TagSubject), Some(&b"Gr\xFC\xDFe\0"[..]));
+        assert_eq!(raw_property_bytes(&properties, PropTag::TagBody), None);
+    }
+
+    #[test]
+    fn accessors_decode_attributes() {
+        // 2021-08-17 12:34:56, a Tuesday
+        let date = [0xE5, 0x07, 0x08, 0x00, 0x11, 0x00, 0x0C, 0x00, 0x22, 0x00, 0x38, 0x00, 0x02, 0x00];
+        let mut file = sample_file();
+        file.attributes.push(attribute(TnefAttributeLevel::Message, TnefAttributeId::DateSent, &date));
+
+        assert_eq!(file.encoding(), to_encoding(1252).unwrap());
+        assert_eq!(file.message_class().as_deref(), Some("IPM.Note"));
+        assert_eq!(file.subject().as_deref(), Some("Gr\u{FC}\u{DF}e"));
+
+        let sent = file.date_sent().unwrap();
+        assert_eq!(sent.to_string(), "2021-08-17T12:34:56Z");
+        assert_eq!(sent.weekday, 1);
+        assert_eq!(file.date_modified(), None);
+        assert_eq!(file.date_start(), None);
+        assert_eq!(file.date_end(), None);
+    }
+
+    #[test]
+    fn parses_attach_render_data() {
+        let data = [0x02, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0x20, 0x00, 0x10, 0x00, 0x01, 0x00, 0x00, 0x00];
+        let render_data = parse_attach_render_data(&data).unwrap();
+        assert_eq!(render_data, AttachRenderData {
+            attach_type: AttachRenderData::TYPE_OLE,
+            position: 0xFFFFFFFF,
+            width: 32,
+            height: 16,
+            flags: 1,
+        });
+        assert!(render_data.is_ole());
+
+        let file_data = AttachRenderData { attach_type: AttachRenderData::TYPE_FILE, ..render_data };
+        assert!(!file_data.is_ole());
+
+        assert_eq!(parse_attach_render_data(&data[..13]), None);
+    }
+
+    #[test]
+    fn parses_tnef_dates() {
+        let date = |fields: [u16; 7]| -> Vec<u8> {
+            fields.iter().flat_map(|f| f.to_le_bytes()).collect()
+        };
+
+        // the stored day of week is ignored
+        let parsed = parse_tnef_date(&date([2024, 2, 29, 13, 5, 7, 0])).unwrap();
+        assert_eq!(parsed.to_string(), "2024-02-29T13:05:07Z");
+        assert_eq!(parsed.weekday, 3);
+
+        assert_eq!(parse_tnef_date(&date([2023, 2, 29, 13, 5, 7, 0])), None);
+        assert_eq!(parse_tnef_date(&date([2024, 2, 29, 0x100 + 13, 5, 7, 0])), None);
+        assert_eq!(parse_tnef_date(&date([2024, 2, 29, 13, 5, 7, 0])[..13]), None);
+    }
+
+    #[test]
+    fn decodes_flags() {
+        assert_eq!(decode_flags(PropTag::TagMessageFlags, 0x0000_0011), vec!["mfRead", "mfHasAttach"]);
+        assert_eq!(decode_flags(PropTag::TagAttachFlags, 0x0000_0004), vec!["afRenderedInBody"]);
+        assert!(decode_flags(PropTag::TagMessageFlags, 0).is_empty());
+        assert!(decode_flags(PropTag::TagSubject, 0x0000_0011).is_empty());
+    }
+
+    #[test]
+    fn decodes_codepage_strings() {
+        // PidTagSubject as a string in codepage 1251
+        let mut buf = vec![0xE3, 0x84, 0x37, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00];
+        buf.extend_from_slice(b"\xCF\xF0\xE8\0");
+        let property = decode_property(Cursor::new(&buf), UTF_8).unwrap();
+        assert_eq!(property.value, PropValue::String("\u{41F}\u{440}\u{438}".to_owned()));
+
+        let unknown_type = [0xFF, 0x0F, 0x37, 0x00];
+        match decode_property(Cursor::new(&unknown_type), UTF_8) {
+            Err(TnefReadError::InProperty { error, .. }) => assert!(matches!(*error, TnefReadError::InvalidPropertyType { property_type: 0x0FFF })),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let unknown_codepage = [0x01, 0x80, 0x37, 0x00];
+        match decode_property(Cursor::new(&unknown_codepage), UTF_8) {
+            Err(TnefReadError::InProperty { error, .. }) => assert!(matches!(*error, TnefReadError::UnknownCodepage { codepage: 1 })),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn converts_into_prop_values() {
+        assert_eq!(PropValue::from(7i16), PropValue::Integer16(7));
+        assert_eq!(PropValue::from(7i32), PropValue::Integer32(7));
+        assert_eq!(PropValue::from(7i64), PropValue::Integer64(7));
+        assert_eq!(PropValue::from(0.5f32), PropValue::Floating32(0.5));
+        assert_eq!(PropValue::from(0.5f64), PropValue::Floating64(0.5));
+        assert_eq!(PropValue::from(true), PropValue::Boolean(true));
+        assert_eq!(PropValue::from("text"), PropValue::String("text".to_owned()));
+        assert_eq!(PropValue::from("text".to_owned()), PropValue::String("text".to_owned()));
+        assert_eq!(PropValue::from(vec![1u8, 2]), PropValue::Binary(vec![1, 2]));
+        assert_eq!(PropValue::from(vec![1i16, 2]), PropValue::MultipleInteger16(vec![1, 2]));
+        assert_eq!(PropValue::from(vec!["a".to_owned()]), PropValue::MultipleString(vec!["a".to_owned()]));
+        assert_eq!(PropValue::from(vec![vec![1u8]]), PropValue::MultipleBinary(vec![vec![1]]));
+
+        let guid = Guid::parse_str("00020329-0000-0000-C000-000000000046").unwrap();
+        assert_eq!(PropValue::from(guid), PropValue::Guid(guid));
+        assert_eq!(PropValue::from(vec![guid]), PropValue::MultipleGuid(vec![guid]));
+    }
+
+    #[test]
+    fn pads_each_multi_value() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&2u32.to_le_bytes());
+        // PT_MV_SHORT 0x6000 with three values, each padded to 4 bytes
+        buf.extend_from_slice(&[0x02, 0x10, 0x00, 0x60]);
+        buf.extend_from_slice(&3u32.to_le_bytes());
+        buf.extend_from_slice(&[0x01, 0x00, 0x00, 0x00, 0xFE, 0xFF, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00]);
+        // PT_MV_LONG 0x6001 with two values
+        buf.extend_from_slice(&[0x03, 0x10, 0x01, 0x60]);
+        buf.extend_from_slice(&2u32.to_le_bytes());
+        buf.extend_from_slice(&[0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00]);
+
+        let properties = decode_properties(Cursor::new(&buf), UTF_8).unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0].value, PropValue::MultipleInteger16(vec![1, -2, 3]));
+        assert_eq!(properties[1].value, PropValue::MultipleInteger32(vec![5, 6]));
+    }
+}
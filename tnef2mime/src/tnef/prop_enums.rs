@@ -1,5 +1,7 @@
 // This file has been generated by props_md2attr.
 
+use std::fmt;
+
 use from_to_repr::from_to_other;
 
 
@@ -7,47 +9,45 @@ use from_to_repr::from_to_other;
 #[from_to_other(base_type = u16, derive_compare = "as_int")]
 pub enum PropTag {
     TagNull = 0x0000,
-    LidAttendeeCriticalChange = 0x0001,
-    LidWhere = 0x0002,
-    LidGlobalObjectId = 0x0003,
-    LidIsSilent = 0x0004,
-    LidIsRecurring = 0x0005,
-    LidRequiredAttendees = 0x0006,
-    LidOptionalAttendees = 0x0007,
-    LidResourceAttendees = 0x0008,
-    LidDelegateMail = 0x0009,
-    LidIsException = 0x000A,
+    TagAcknowledgementMode = 0x0001,
+    TagAlternateRecipientAllowed = 0x0002,
+    TagAuthorizingUsers = 0x0003,
+    TagAutoForwardComment = 0x0004,
+    TagAutoForwarded = 0x0005,
+    TagContentConfidentialityAlgorithmId = 0x0006,
+    TagContentCorrelator = 0x0007,
+    TagContentIdentifier = 0x0008,
+    TagContentLength = 0x0009,
+    TagContentReturnRequested = 0x000A,
     TagConversationKey = 0x000B,
-    LidTimeZone = 0x000C,
-    LidStartRecurrenceDate = 0x000D,
-    LidStartRecurrenceTime = 0x000E,
-    LidEndRecurrenceDate = 0x000F,
-    LidEndRecurrenceTime = 0x0010,
-    LidDayInterval = 0x0011,
-    LidWeekInterval = 0x0012,
-    LidMonthInterval = 0x0013,
-    LidYearInterval = 0x0014,
-    LidClientIntent = 0x0015,
+    TagConversionEits = 0x000C,
+    TagConversionWithLossProhibited = 0x000D,
+    TagConvertedEits = 0x000E,
+    TagDeferredDeliveryTime = 0x000F,
+    TagDeliverTime = 0x0010,
+    TagDiscardReason = 0x0011,
+    TagDisclosureOfRecipients = 0x0012,
+    TagDistributionListExpansionHistory = 0x0013,
+    TagDistributionListExpansionProhibited = 0x0014,
+    TagExpiryTime = 0x0015,
     TagImplicitConversionProhibited = 0x0016,
-    LidMonthOfYearMask = 0x0017,
-    LidOldRecurrenceType = 0x0018,
+    TagImportance = 0x0017,
     TagLatestDeliveryTime = 0x0019,
-    LidOwnerCriticalChange = 0x001A,
+    TagMessageClass = 0x001A,
     TagMessageDeliveryId = 0x001B,
-    LidCalendarType = 0x001C,
     TagMessageSecurityLabel = 0x001E,
     TagObsoletedMessageIds = 0x001F,
     TagOriginallyIntendedRecipientName = 0x0020,
     TagOriginalEits = 0x0021,
     TagOriginatorCertificate = 0x0022,
-    LidCleanGlobalObjectId = 0x0023,
-    LidAppointmentMessageClass = 0x0024,
+    TagOriginatorDeliveryReportRequested = 0x0023,
+    TagOriginatorReturnAddress = 0x0024,
     TagParentKey = 0x0025,
-    LidMeetingType = 0x0026,
+    TagPriority = 0x0026,
     TagOriginCheck = 0x0027,
-    LidOldLocation = 0x0028,
-    LidOldWhenStartWhole = 0x0029,
-    LidOldWhenEndWhole = 0x002A,
+    TagProofOfSubmissionRequested = 0x0028,
+    TagReadReceiptRequested = 0x0029,
+    TagReceiptTime = 0x002A,
     TagRecipientReassignmentProhibited = 0x002B,
     TagRedirectionHistory = 0x002C,
     TagRelatedMessageIds = 0x002D,
@@ -232,18 +232,15 @@ pub enum PropTag {
     TagIcon = 0x0FFD,
     TagObjectType = 0x0FFE,
     TagEntryId = 0x0FFF,
-    LidDayOfMonth = 0x1000,
-    LidICalendarDayOfWeekMask = 0x1001,
+    TagBody = 0x1000,
+    TagReportText = 0x1001,
     TagOriginatorAndDistributionListExpansionHistory = 0x1002,
     TagReportingDistributionListName = 0x1003,
     TagReportingMessageTransferAgentCertificate = 0x1004,
-    LidOccurrences = 0x1005,
-    LidMonthOfYear = 0x1006,
+    TagRtfSyncBodyCrc = 0x1006,
     TagRtfSyncBodyCount = 0x1007,
     TagRtfSyncBodyTag = 0x1008,
     TagRtfCompressed = 0x1009,
-    LidNoEndDateFlag = 0x100B,
-    LidRecurrenceDuration = 0x100D,
     TagRtfSyncPrefixCount = 0x1010,
     TagRtfSyncTrailingCount = 0x1011,
     TagOriginallyIntendedRecipEntryId = 0x1012,
@@ -732,31 +729,18 @@ pub enum PropTag {
     TagAttachmentHidden = 0x7FFE,
     TagAttachmentContactPhoto = 0x7FFF,
     TagAddressBookFolderPathname = 0x8004,
-    LidFileUnder = 0x8005,
-    LidFileUnderId = 0x8006,
-    LidContactItemData = 0x8007,
+    TagAddressBookManager = 0x8005,
+    TagAddressBookHomeMessageDatabase = 0x8006,
     TagAddressBookIsMemberOfDistributionList = 0x8008,
     TagAddressBookMember = 0x8009,
     TagAddressBookOwner = 0x800C,
     TagAddressBookReports = 0x800E,
     TagAddressBookProxyAddresses = 0x800F,
-    LidDepartment = 0x8010,
     TagAddressBookTargetAddress = 0x8011,
-    LidHasPicture = 0x8015,
-    LidHomeAddress = 0x801A,
-    LidWorkAddress = 0x801B,
-    LidOtherAddress = 0x801C,
-    LidPostalAddressId = 0x8022,
-    LidContactCharacterSet = 0x8023,
+    TagAddressBookPublicDelegates = 0x8015,
     TagAddressBookOwnerBackLink = 0x8024,
-    LidAutoLog = 0x8025,
-    LidFileUnderList = 0x8026,
-    LidAddressBookProviderEmailList = 0x8028,
-    LidAddressBookProviderArrayType = 0x8029,
-    LidHtml = 0x802B,
-    LidYomiFirstName = 0x802C,
-    LidYomiLastName = 0x802D,
-    LidYomiCompanyName = 0x802E,
+    TagAddressBookExtensionAttribute1 = 0x802D,
+    TagAddressBookExtensionAttribute2 = 0x802E,
     TagAddressBookExtensionAttribute3 = 0x802F,
     TagAddressBookExtensionAttribute4 = 0x8030,
     TagAddressBookExtensionAttribute5 = 0x8031,
@@ -766,318 +750,9 @@ pub enum PropTag {
     TagAddressBookExtensionAttribute9 = 0x8035,
     TagAddressBookExtensionAttribute10 = 0x8036,
     TagAddressBookObjectDistinguishedName = 0x803C,
-    LidBusinessCardDisplayDefinition = 0x8040,
-    LidBusinessCardCardPicture = 0x8041,
-    LidPromptSendUpdate = 0x8045,
-    LidWorkAddressCity = 0x8046,
-    LidWorkAddressState = 0x8047,
-    LidWorkAddressPostalCode = 0x8048,
-    LidWorkAddressCountry = 0x8049,
-    LidWorkAddressPostOfficeBox = 0x804A,
-    LidDistributionListChecksum = 0x804C,
-    LidBirthdayEventEntryId = 0x804D,
-    LidAnniversaryEventEntryId = 0x804E,
-    LidContactUserField1 = 0x804F,
-    LidContactUserField2 = 0x8050,
-    LidContactUserField3 = 0x8051,
-    LidContactUserField4 = 0x8052,
-    LidDistributionListName = 0x8053,
-    LidDistributionListOneOffMembers = 0x8054,
-    LidDistributionListMembers = 0x8055,
-    LidInstantMessagingAddress = 0x8062,
-    LidDistributionListStream = 0x8064,
     TagAddressBookDeliveryContentLength = 0x806A,
     TagAddressBookDistributionListMemberSubmitAccepted = 0x8073,
-    LidEmail1DisplayName = 0x8080,
-    LidEmail1AddressType = 0x8082,
-    LidEmail1EmailAddress = 0x8083,
-    LidEmail1OriginalDisplayName = 0x8084,
-    LidEmail1OriginalEntryId = 0x8085,
-    LidEmail2DisplayName = 0x8090,
-    LidEmail2AddressType = 0x8092,
-    LidEmail2EmailAddress = 0x8093,
-    LidEmail2OriginalDisplayName = 0x8094,
-    LidEmail2OriginalEntryId = 0x8095,
-    LidEmail3DisplayName = 0x80A0,
-    LidEmail3AddressType = 0x80A2,
-    LidEmail3EmailAddress = 0x80A3,
-    LidEmail3OriginalDisplayName = 0x80A4,
-    LidEmail3OriginalEntryId = 0x80A5,
-    LidFax1AddressType = 0x80B2,
-    LidFax1EmailAddress = 0x80B3,
-    LidFax1OriginalDisplayName = 0x80B4,
-    LidFax1OriginalEntryId = 0x80B5,
-    LidFax2AddressType = 0x80C2,
-    LidFax2EmailAddress = 0x80C3,
-    LidFax2OriginalDisplayName = 0x80C4,
-    LidFax2OriginalEntryId = 0x80C5,
-    LidFax3AddressType = 0x80D2,
-    LidFax3EmailAddress = 0x80D3,
-    LidFax3OriginalDisplayName = 0x80D4,
-    LidFax3OriginalEntryId = 0x80D5,
-    LidFreeBusyLocation = 0x80D8,
-    LidHomeAddressCountryCode = 0x80DA,
-    LidWorkAddressCountryCode = 0x80DB,
-    LidOtherAddressCountryCode = 0x80DC,
-    LidAddressCountryCode = 0x80DD,
-    LidBirthdayLocal = 0x80DE,
-    LidWeddingAnniversaryLocal = 0x80DF,
-    LidIsContactLinked = 0x80E0,
-    LidContactLinkedGlobalAddressListEntryId = 0x80E2,
-    LidContactLinkSMTPAddressCache = 0x80E3,
-    LidContactLinkLinkRejectHistory = 0x80E5,
-    LidContactLinkGlobalAddressListLinkState = 0x80E6,
-    LidContactLinkGlobalAddressListLinkId = 0x80E8,
-    LidTaskStatus = 0x8101,
-    LidPercentComplete = 0x8102,
-    LidTeamTask = 0x8103,
-    LidTaskStartDate = 0x8104,
-    LidTaskDueDate = 0x8105,
-    LidTaskResetReminder = 0x8107,
-    LidTaskAccepted = 0x8108,
-    LidTaskDeadOccurrence = 0x8109,
-    LidTaskDateCompleted = 0x810F,
-    LidTaskActualEffort = 0x8110,
-    LidTaskEstimatedEffort = 0x8111,
-    LidTaskVersion = 0x8112,
-    LidTaskState = 0x8113,
-    LidTaskLastUpdate = 0x8115,
-    LidTaskRecurrence = 0x8116,
-    LidTaskAssigners = 0x8117,
-    LidTaskStatusOnComplete = 0x8119,
-    LidTaskHistory = 0x811A,
-    LidTaskUpdates = 0x811B,
-    LidTaskComplete = 0x811C,
-    LidTaskFCreator = 0x811E,
-    LidTaskOwner = 0x811F,
-    LidTaskMultipleRecipients = 0x8120,
-    LidTaskAssigner = 0x8121,
-    LidTaskLastUser = 0x8122,
-    LidTaskOrdinal = 0x8123,
-    LidTaskNoCompute = 0x8124,
-    LidTaskLastDelegate = 0x8125,
-    LidTaskFRecurring = 0x8126,
-    LidTaskRole = 0x8127,
-    LidTaskOwnership = 0x8129,
-    LidTaskAcceptanceState = 0x812A,
-    LidTaskFFixOffline = 0x812C,
-    LidTaskCustomFlags = 0x8139,
     TagAddressBookNetworkAddress = 0x8170,
-    LidAppointmentSequence = 0x8201,
-    LidAppointmentSequenceTime = 0x8202,
-    LidAppointmentLastSequence = 0x8203,
-    LidChangeHighlight = 0x8204,
-    LidBusyStatus = 0x8205,
-    LidFExceptionalBody = 0x8206,
-    LidAppointmentAuxiliaryFlags = 0x8207,
-    LidLocation = 0x8208,
-    LidMeetingWorkspaceUrl = 0x8209,
-    LidForwardInstance = 0x820A,
-    LidLinkedTaskItems = 0x820C,
-    LidAppointmentStartWhole = 0x820D,
-    LidAppointmentEndWhole = 0x820E,
-    LidAppointmentStartTime = 0x820F,
-    LidAppointmentEndTime = 0x8210,
-    LidAppointmentEndDate = 0x8211,
-    LidAppointmentStartDate = 0x8212,
-    LidAppointmentDuration = 0x8213,
-    LidAppointmentColor = 0x8214,
-    LidAppointmentSubType = 0x8215,
-    LidAppointmentRecur = 0x8216,
-    LidAppointmentStateFlags = 0x8217,
-    LidResponseStatus = 0x8218,
-    LidAppointmentReplyTime = 0x8220,
-    LidRecurring = 0x8223,
-    LidIntendedBusyStatus = 0x8224,
-    LidAppointmentUpdateTime = 0x8226,
-    LidExceptionReplaceTime = 0x8228,
-    LidFInvited = 0x8229,
-    LidFExceptionalAttendees = 0x822B,
-    LidOwnerName = 0x822E,
-    LidFOthersAppointment = 0x822F,
-    LidAppointmentReplyName = 0x8230,
-    LidRecurrenceType = 0x8231,
-    LidRecurrencePattern = 0x8232,
-    LidTimeZoneStruct = 0x8233,
-    LidTimeZoneDescription = 0x8234,
-    LidClipStart = 0x8235,
-    LidClipEnd = 0x8236,
-    LidOriginalStoreEntryId = 0x8237,
-    LidAllAttendeesString = 0x8238,
-    LidAutoFillLocation = 0x823A,
-    LidToAttendeesString = 0x823B,
-    LidCcAttendeesString = 0x823C,
-    LidConferencingCheck = 0x8240,
-    LidConferencingType = 0x8241,
-    LidDirectory = 0x8242,
-    LidOrganizerAlias = 0x8243,
-    LidAutoStartCheck = 0x8244,
-    LidAllowExternalCheck = 0x8246,
-    LidCollaborateDoc = 0x8247,
-    LidNetShowUrl = 0x8248,
-    LidOnlinePassword = 0x8249,
-    LidAppointmentProposedStartWhole = 0x8250,
-    LidAppointmentProposedEndWhole = 0x8251,
-    LidAppointmentProposedDuration = 0x8256,
-    LidAppointmentCounterProposal = 0x8257,
-    LidAppointmentProposalNumber = 0x8259,
-    LidAppointmentNotAllowPropose = 0x825A,
-    LidAppointmentUnsendableRecipients = 0x825D,
-    LidAppointmentTimeZoneDefinitionStartDisplay = 0x825E,
-    LidAppointmentTimeZoneDefinitionEndDisplay = 0x825F,
-    LidAppointmentTimeZoneDefinitionRecur = 0x8260,
-    LidForwardNotificationRecipients = 0x8261,
-    LidInboundICalStream = 0x827A,
-    LidSingleBodyICal = 0x827B,
-    LidReminderDelta = 0x8501,
-    LidReminderTime = 0x8502,
-    LidReminderSet = 0x8503,
-    LidReminderTimeTime = 0x8504,
-    LidReminderTimeDate = 0x8505,
-    LidPrivate = 0x8506,
-    LidAgingDontAgeMe = 0x850E,
-    LidSideEffects = 0x8510,
-    LidRemoteStatus = 0x8511,
-    LidSmartNoAttach = 0x8514,
-    LidCommonStart = 0x8516,
-    LidCommonEnd = 0x8517,
-    LidTaskMode = 0x8518,
-    LidTaskGlobalId = 0x8519,
-    LidAutoProcessState = 0x851A,
-    LidReminderOverride = 0x851C,
-    LidReminderType = 0x851D,
-    LidReminderPlaySound = 0x851E,
-    LidReminderFileParameter = 0x851F,
-    LidVerbStream = 0x8520,
-    LidVerbResponse = 0x8524,
-    LidFlagRequest = 0x8530,
-    LidBilling = 0x8535,
-    LidNonSendableTo = 0x8536,
-    LidNonSendableCc = 0x8537,
-    LidNonSendableBcc = 0x8538,
-    LidCompanies = 0x8539,
-    LidContacts = 0x853A,
-    LidNonSendToTrackStatus = 0x8543,
-    LidNonSendCcTrackStatus = 0x8544,
-    LidNonSendBccTrackStatus = 0x8545,
-    LidCurrentVersion = 0x8552,
-    LidCurrentVersionName = 0x8554,
-    LidReminderSignalTime = 0x8560,
-    LidInternetAccountName = 0x8580,
-    LidInternetAccountStamp = 0x8581,
-    LidUseTnef = 0x8582,
-    LidContactLinkSearchKey = 0x8584,
-    LidContactLinkEntry = 0x8585,
-    LidContactLinkName = 0x8586,
-    LidSpamOriginalFolder = 0x859C,
-    LidToDoOrdinalDate = 0x85A0,
-    LidToDoSubOrdinal = 0x85A1,
-    LidToDoTitle = 0x85A4,
-    LidInfoPathFormName = 0x85B1,
-    LidClassified = 0x85B5,
-    LidClassification = 0x85B6,
-    LidClassificationDescription = 0x85B7,
-    LidClassificationGuid = 0x85B8,
-    LidClassificationKeep = 0x85BA,
-    LidReferenceEntryId = 0x85BD,
-    LidValidFlagStringProof = 0x85BF,
-    LidFlagString = 0x85C0,
-    LidConversationActionMoveFolderEid = 0x85C6,
-    LidConversationActionMoveStoreEid = 0x85C7,
-    LidConversationActionMaxDeliveryTime = 0x85C8,
-    LidConversationProcessed = 0x85C9,
-    LidConversationActionLastAppliedTime = 0x85CA,
-    LidConversationActionVersion = 0x85CB,
-    LidServerProcessed = 0x85CC,
-    LidServerProcessingActions = 0x85CD,
-    LidPendingStateForSiteMailboxDocument = 0x85E0,
-    LidLogType = 0x8700,
-    LidLogStart = 0x8706,
-    LidLogDuration = 0x8707,
-    LidLogEnd = 0x8708,
-    LidLogFlags = 0x870C,
-    LidLogDocumentPrinted = 0x870E,
-    LidLogDocumentSaved = 0x870F,
-    LidLogDocumentRouted = 0x8710,
-    LidLogDocumentPosted = 0x8711,
-    LidLogTypeDesc = 0x8712,
-    LidPostRssChannelLink = 0x8900,
-    LidPostRssItemLink = 0x8901,
-    LidPostRssItemHash = 0x8902,
-    LidPostRssItemGuid = 0x8903,
-    LidPostRssChannel = 0x8904,
-    LidPostRssItemXml = 0x8905,
-    LidPostRssSubscription = 0x8906,
-    LidSharingStatus = 0x8A00,
-    LidSharingProviderGuid = 0x8A01,
-    LidSharingProviderName = 0x8A02,
-    LidSharingProviderUrl = 0x8A03,
-    LidSharingRemotePath = 0x8A04,
-    LidSharingRemoteName = 0x8A05,
-    LidSharingRemoteUid = 0x8A06,
-    LidSharingInitiatorName = 0x8A07,
-    LidSharingInitiatorSmtp = 0x8A08,
-    LidSharingInitiatorEntryId = 0x8A09,
-    LidSharingFlags = 0x8A0A,
-    LidSharingProviderExtension = 0x8A0B,
-    LidSharingRemoteUser = 0x8A0C,
-    LidSharingRemotePass = 0x8A0D,
-    LidSharingLocalPath = 0x8A0E,
-    LidSharingLocalName = 0x8A0F,
-    LidSharingLocalUid = 0x8A10,
-    LidSharingFilter = 0x8A13,
-    LidSharingLocalType = 0x8A14,
-    LidSharingFolderEntryId = 0x8A15,
-    LidSharingCapabilities = 0x8A17,
-    LidSharingFlavor = 0x8A18,
-    LidSharingAnonymity = 0x8A19,
-    LidSharingReciprocation = 0x8A1A,
-    LidSharingPermissions = 0x8A1B,
-    LidSharingInstanceGuid = 0x8A1C,
-    LidSharingRemoteType = 0x8A1D,
-    LidSharingParticipants = 0x8A1E,
-    LidSharingLastSyncTime = 0x8A1F,
-    LidSharingExtensionXml = 0x8A21,
-    LidSharingRemoteLastModificationTime = 0x8A22,
-    LidSharingLocalLastModificationTime = 0x8A23,
-    LidSharingConfigurationUrl = 0x8A24,
-    LidSharingStart = 0x8A25,
-    LidSharingStop = 0x8A26,
-    LidSharingResponseType = 0x8A27,
-    LidSharingResponseTime = 0x8A28,
-    LidSharingOriginalMessageEntryId = 0x8A29,
-    LidSharingSyncInterval = 0x8A2A,
-    LidSharingDetail = 0x8A2B,
-    LidSharingTimeToLive = 0x8A2C,
-    LidSharingBindingEntryId = 0x8A2D,
-    LidSharingIndexEntryId = 0x8A2E,
-    LidSharingRemoteComment = 0x8A2F,
-    LidSharingWorkingHoursStart = 0x8A40,
-    LidSharingWorkingHoursEnd = 0x8A41,
-    LidSharingWorkingHoursDays = 0x8A42,
-    LidSharingWorkingHoursTimeZone = 0x8A43,
-    LidSharingDataRangeStart = 0x8A44,
-    LidSharingDataRangeEnd = 0x8A45,
-    LidSharingRangeStart = 0x8A46,
-    LidSharingRangeEnd = 0x8A47,
-    LidSharingRemoteStoreUid = 0x8A48,
-    LidSharingLocalStoreUid = 0x8A49,
-    LidSharingRemoteByteSize = 0x8A4B,
-    LidSharingRemoteCrc = 0x8A4C,
-    LidSharingLocalComment = 0x8A4D,
-    LidSharingRoamLog = 0x8A4E,
-    LidSharingRemoteMessageCount = 0x8A4F,
-    LidSharingBrowseUrl = 0x8A51,
-    LidSharingLastAutoSyncTime = 0x8A55,
-    LidSharingTimeToLiveAuto = 0x8A56,
-    LidSharingRemoteVersion = 0x8A5B,
-    LidSharingParentBindingEntryId = 0x8A5C,
-    LidSharingSyncFlags = 0x8A60,
-    LidNoteColor = 0x8B00,
-    LidNoteWidth = 0x8B02,
-    LidNoteHeight = 0x8B03,
-    LidNoteX = 0x8B04,
-    LidNoteY = 0x8B05,
     TagAddressBookExtensionAttribute11 = 0x8C57,
     TagAddressBookExtensionAttribute12 = 0x8C58,
     TagAddressBookExtensionAttribute13 = 0x8C59,
@@ -1110,212 +785,35 @@ pub enum PropTag {
     TagAddressBookHierarchicalIsHierarchicalGroup = 0x8CDD,
     TagAddressBookDistributionListMemberCount = 0x8CE2,
     TagAddressBookDistributionListExternalMemberCount = 0x8CE3,
-    LidCategories = 0x9000,
     TagAddressBookIsMaster = 0xFFFB,
     TagAddressBookParentEntryId = 0xFFFC,
     TagAddressBookContainerId = 0xFFFD,
     TagEmsAbServer = 0xFFFE,
-    // LidWorkAddressStreet = LidPromptSendUpdate
-    // NameAcceptLanguage = LidYomiLastName
-    // NameApplicationName = LidYomiLastName
-    // NameAttachmentMacContentType = LidYomiLastName
-    // NameAttachmentMacInfo = LidYomiLastName
-    // NameAttachmentOriginalPermissionType = LidYomiLastName
-    // NameAttachmentPermissionType = LidYomiLastName
-    // NameAttachmentProviderType = LidYomiLastName
-    // NameAudioNotes = LidYomiLastName
-    // NameAuthor = LidYomiLastName
-    // NameAutomaticSpeechRecognitionData = LidYomiLastName
-    // NameBirthdayContactAttributionDisplayName = LidYomiLastName
-    // NameBirthdayContactEntryId = LidYomiLastName
-    // NameBirthdayContactPersonGuid = LidYomiLastName
-    // NameByteCount = LidYomiLastName
-    // NameCalendarAttendeeRole = LidYomiLastName
-    // NameCalendarBusystatus = LidYomiLastName
-    // NameCalendarContact = LidYomiLastName
-    // NameCalendarContactUrl = LidYomiLastName
-    // NameCalendarCreated = LidYomiLastName
-    // NameCalendarDescriptionUrl = LidYomiLastName
-    // NameCalendarDuration = LidYomiLastName
-    // NameCalendarExceptionDate = LidYomiLastName
-    // NameCalendarExceptionRule = LidYomiLastName
-    // NameCalendarGeoLatitude = LidYomiLastName
-    // NameCalendarGeoLongitude = LidYomiLastName
-    // NameCalendarInstanceType = LidYomiLastName
-    // NameCalendarIsOrganizer = LidYomiLastName
-    // NameCalendarLastModified = LidYomiLastName
-    // NameCalendarLocationUrl = LidYomiLastName
-    // NameCalendarMeetingStatus = LidYomiLastName
-    // NameCalendarMethod = LidYomiLastName
-    // NameCalendarProductId = LidYomiLastName
-    // NameCalendarRecurrenceIdRange = LidYomiLastName
-    // NameCalendarReminderOffset = LidYomiLastName
-    // NameCalendarResources = LidYomiLastName
-    // NameCalendarRsvp = LidYomiLastName
-    // NameCalendarSequence = LidYomiLastName
-    // NameCalendarTimeZone = LidYomiLastName
-    // NameCalendarTimeZoneId = LidYomiLastName
-    // NameCalendarTransparent = LidYomiLastName
-    // NameCalendarUid = LidYomiLastName
-    // NameCalendarVersion = LidYomiLastName
-    // NameCategory = LidYomiLastName
-    // NameCharacterCount = LidYomiLastName
-    // NameComments = LidYomiLastName
-    // NameCompany = LidYomiLastName
-    // NameContentBase = LidYomiLastName
-    // NameContentClass = LidYomiLastName
-    // NameContentType = LidYomiLastName
-    // NameCreateDateTimeReadOnly = LidYomiLastName
-    // NameCrossReference = LidYomiLastName
-    // NameDavId = LidYomiLastName
-    // NameDavIsCollection = LidYomiLastName
-    // NameDavIsStructuredDocument = LidYomiLastName
-    // NameDavParentName = LidYomiLastName
-    // NameDavUid = LidYomiLastName
-    // NameDocumentParts = LidYomiLastName
-    // NameEditTime = LidYomiLastName
-    // NameExchDataExpectedContentClass = LidYomiLastName
-    // NameExchDataSchemaCollectionReference = LidYomiLastName
-    // NameExchDatabaseSchema = LidYomiLastName
-    // NameExchangeIntendedBusyStatus = LidYomiLastName
-    // NameExchangeJunkEmailMoveStamp = LidYomiLastName
-    // NameExchangeModifyExceptionStructure = LidYomiLastName
-    // NameExchangeNoModifyExceptions = LidYomiLastName
-    // NameExchangePatternEnd = LidYomiLastName
-    // NameExchangePatternStart = LidYomiLastName
-    // NameExchangeReminderInterval = LidYomiLastName
-    // NameExtractedAddresses = LidYomiLastName
-    // NameExtractedContacts = LidYomiLastName
-    // NameExtractedEmails = LidYomiLastName
-    // NameExtractedMeetings = LidYomiLastName
-    // NameExtractedPhones = LidYomiLastName
-    // NameExtractedTasks = LidYomiLastName
-    // NameExtractedUrls = LidYomiLastName
-    // NameFrom = LidYomiLastName
-    // NameHeadingPairs = LidYomiLastName
-    // NameHiddenCount = LidYomiLastName
-    // NameHttpmailCalendar = LidYomiLastName
-    // NameHttpmailHtmlDescription = LidYomiLastName
-    // NameHttpmailSendMessage = LidYomiLastName
-    // NameICalendarRecurrenceDate = LidYomiLastName
-    // NameICalendarRecurrenceRule = LidYomiLastName
-    // NameInternetSubject = LidYomiLastName
-    // NameIsBirthdayContactWritable = LidYomiLastName
-    // NameKeywords = LidYomiLastName
-    // NameLastAuthor = LidYomiLastName
-    // NameLastPrinted = LidYomiLastName
-    // NameLastSaveDateTime = LidYomiLastName
-    // NameLineCount = LidYomiLastName
-    // NameLinksDirty = LidYomiLastName
-    // NameLocationUrl = LidYomiLastName
-    // NameMSIPLabels = LidYomiLastName
-    // NameManager = LidYomiLastName
-    // NameMeetingDoNotForward = LidYomiLastName
-    // NameMultimediaClipCount = LidYomiLastName
-    // NameNoteCount = LidYomiLastName
-    // NameOMSAccountGuid = LidYomiLastName
-    // NameOMSMobileModel = LidYomiLastName
-    // NameOMSScheduleTime = LidYomiLastName
-    // NameOMSServiceType = LidYomiLastName
-    // NameOMSSourceType = LidYomiLastName
-    // NamePageCount = LidYomiLastName
-    // NameParagraphCount = LidYomiLastName
-    // NamePhishingStamp = LidYomiLastName
-    // NamePresentationFormat = LidYomiLastName
-    // NameQuarantineOriginalSender = LidYomiLastName
-    // NameRevisionNumber = LidYomiLastName
-    // NameRightsManagementLicense = LidYomiLastName
-    // NameScale = LidYomiLastName
-    // NameSecurity = LidYomiLastName
-    // NameSlideCount = LidYomiLastName
-    // NameSubject = LidYomiLastName
-    // NameTemplate = LidYomiLastName
-    // NameThumbnail = LidYomiLastName
-    // NameTitle = LidYomiLastName
-    // NameWordCount = LidYomiLastName
-    // NameXCallId = LidYomiLastName
-    // NameXFaxNumberOfPages = LidYomiLastName
-    // NameXRequireProtectedPlayOnPhone = LidYomiLastName
-    // NameXSenderTelephoneNumber = LidYomiLastName
-    // NameXSharingBrowseUrl = LidYomiLastName
-    // NameXSharingCapabilities = LidYomiLastName
-    // NameXSharingConfigUrl = LidYomiLastName
-    // NameXSharingExendedCaps = LidYomiLastName
-    // NameXSharingFlavor = LidYomiLastName
-    // NameXSharingInstanceGuid = LidYomiLastName
-    // NameXSharingLocalType = LidYomiLastName
-    // NameXSharingProviderGuid = LidYomiLastName
-    // NameXSharingProviderName = LidYomiLastName
-    // NameXSharingProviderUrl = LidYomiLastName
-    // NameXSharingRemoteName = LidYomiLastName
-    // NameXSharingRemotePath = LidYomiLastName
-    // NameXSharingRemoteStoreUid = LidYomiLastName
-    // NameXSharingRemoteType = LidYomiLastName
-    // NameXSharingRemoteUid = LidYomiLastName
-    // NameXVoiceMessageAttachmentOrder = LidYomiLastName
-    // NameXVoiceMessageDuration = LidYomiLastName
-    // NameXVoiceMessageSenderName = LidYomiLastName
     // Tag7BitDisplayName = TagAddressBookDisplayNamePrintable
     // TagAccessControlListTable = TagAccessControlListData
-    // TagAcknowledgementMode = LidAttendeeCriticalChange
-    // TagAddressBookExtensionAttribute1 = LidYomiLastName
-    // TagAddressBookExtensionAttribute2 = LidYomiCompanyName
-    // TagAddressBookHomeMessageDatabase = LidFileUnderId
-    // TagAddressBookManager = LidFileUnder
-    // TagAddressBookManagerDistinguishedName = LidFileUnder
-    // TagAddressBookPublicDelegates = LidHasPicture
-    // TagAlternateRecipientAllowed = LidWhere
+    // TagAddressBookManagerDistinguishedName = TagAddressBookManager
     // TagAttachDataObject = TagAttachDataBinary
-    // TagAuthorizingUsers = LidGlobalObjectId
-    // TagAutoForwardComment = LidIsSilent
-    // TagAutoForwarded = LidIsRecurring
-    // TagBody = LidDayOfMonth
     // TagBusiness2TelephoneNumbers = TagBusiness2TelephoneNumber
     // TagContactAddressBookStoreNames = TagSchedulePlusFreeBusyEntryId
-    // TagContentConfidentialityAlgorithmId = LidRequiredAttendees
-    // TagContentCorrelator = LidOptionalAttendees
-    // TagContentIdentifier = LidResourceAttendees
-    // TagContentLength = LidDelegateMail
-    // TagContentReturnRequested = LidIsException
-    // TagConversionEits = LidTimeZone
-    // TagConversionWithLossProhibited = LidStartRecurrenceDate
-    // TagConvertedEits = LidStartRecurrenceTime
-    // TagDeferredDeliveryTime = LidEndRecurrenceDate
-    // TagDeliverTime = LidEndRecurrenceTime
-    // TagDiscardReason = LidDayInterval
-    // TagDisclosureOfRecipients = LidWeekInterval
-    // TagDistributionListExpansionHistory = LidMonthInterval
-    // TagDistributionListExpansionProhibited = LidYearInterval
-    // TagExpiryTime = LidClientIntent
     // TagHome2TelephoneNumbers = TagHome2TelephoneNumber
     // TagHtml = TagBodyHtml
-    // TagImportance = LidMonthOfYearMask
     // TagInternetReturnPath = TagOriginalMessageId
     // TagMemberEntryId = TagEntryId
-    // TagMessageClass = LidOwnerCriticalChange
     // TagMessageSizeExtended = TagMessageSize
     // TagNonIpmSubtreeEntryId = TagContactAddressBookFolderEntryIds
     // TagNonReceiptNotificationRequested = TagNonDeliveryReportStatusCode
     // TagOfflineAddressBookDistinguishedName = TagFaxNumberOfPages
-    // TagOriginatorDeliveryReportRequested = LidCleanGlobalObjectId
-    // TagOriginatorReturnAddress = LidAppointmentMessageClass
     // TagPreferredByName = TagReferredByName
-    // TagPriority = LidMeetingType
     // TagProfileServerFullVersion = TagAddressBookEntryId
     // TagProfileServerVersion = TagMailboxOwnerEntryId
-    // TagProofOfSubmissionRequested = LidOldLocation
     // TagPstPasswordSzNew = TagAddressBookManageDistributionList
-    // TagReadReceiptRequested = LidOldWhenStartWhole
-    // TagReceiptTime = LidOldWhenEndWhole
-    // TagReportText = LidICalendarDayOfWeekMask
     // TagRpcOverHttpFlags = TagContactAddressBookFolderNames
     // TagRpcOverHttpProxyPrincipalName = TagContactAddressBookMultipleAddressFlags
     // TagRpcOverHttpProxyServer = TagSchedulePlusFreeBusyEntryId
-    // TagRtfSyncBodyCrc = LidMonthOfYear
     // TagRuleMsgName = TagRuleMessageName
     // TagRuleMsgProvider = TagRuleMessageProvider
     // TagRwRulesStream = TagOfflineAddressBookContainerGuid
-    // TagScriptData = LidIsSilent
+    // TagScriptData = TagAutoForwardComment
     // TagSearchFolderDefinition = TagScheduleInfoDelegateEntryIds
     // TagSearchFolderEfpFlags = TagFreeBusyPublishEnd
     // TagSearchFolderId = TagScheduleInfoDelegatorWantsCopy
@@ -1324,7 +822,7 @@ pub enum PropTag {
     // TagSearchFolderTag = TagFreeBusyPublishStart
     // TagSearchFolderTemplateId = TagScheduleInfoResourceType
     // TagSenderTelephoneNumber = TagOfflineAddressBookContainerGuid
-    // TagTemplateData = LidAttendeeCriticalChange
+    // TagTemplateData = TagAcknowledgementMode
     // TagTtyTddPhoneNumber = TagTelecommunicationsDeviceForDeafTelephoneNumber
     // TagVoiceMessageAttachmentOrder = TagOfflineAddressBookTruncatedProperties
     // TagVoiceMessageDuration = TagOfflineAddressBookSequence
@@ -1344,3 +842,799 @@ pub enum PropTag {
     // TagWlinkType = TagFreeBusyMessageEmailAddress
     Other(u16),
 }
+impl PropTag {
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::TagNull => Some("PidTagNull"),
+            Self::TagAcknowledgementMode => Some("PidTagAcknowledgementMode"),
+            Self::TagAlternateRecipientAllowed => Some("PidTagAlternateRecipientAllowed"),
+            Self::TagAuthorizingUsers => Some("PidTagAuthorizingUsers"),
+            Self::TagAutoForwardComment => Some("PidTagAutoForwardComment"),
+            Self::TagAutoForwarded => Some("PidTagAutoForwarded"),
+            Self::TagContentConfidentialityAlgorithmId => Some("PidTagContentConfidentialityAlgorithmId"),
+            Self::TagContentCorrelator => Some("PidTagContentCorrelator"),
+            Self::TagContentIdentifier => Some("PidTagContentIdentifier"),
+            Self::TagContentLength => Some("PidTagContentLength"),
+            Self::TagContentReturnRequested => Some("PidTagContentReturnRequested"),
+            Self::TagConversationKey => Some("PidTagConversationKey"),
+            Self::TagConversionEits => Some("PidTagConversionEits"),
+            Self::TagConversionWithLossProhibited => Some("PidTagConversionWithLossProhibited"),
+            Self::TagConvertedEits => Some("PidTagConvertedEits"),
+            Self::TagDeferredDeliveryTime => Some("PidTagDeferredDeliveryTime"),
+            Self::TagDeliverTime => Some("PidTagDeliverTime"),
+            Self::TagDiscardReason => Some("PidTagDiscardReason"),
+            Self::TagDisclosureOfRecipients => Some("PidTagDisclosureOfRecipients"),
+            Self::TagDistributionListExpansionHistory => Some("PidTagDistributionListExpansionHistory"),
+            Self::TagDistributionListExpansionProhibited => Some("PidTagDistributionListExpansionProhibited"),
+            Self::TagExpiryTime => Some("PidTagExpiryTime"),
+            Self::TagImplicitConversionProhibited => Some("PidTagImplicitConversionProhibited"),
+            Self::TagImportance => Some("PidTagImportance"),
+            Self::TagLatestDeliveryTime => Some("PidTagLatestDeliveryTime"),
+            Self::TagMessageClass => Some("PidTagMessageClass"),
+            Self::TagMessageDeliveryId => Some("PidTagMessageDeliveryId"),
+            Self::TagMessageSecurityLabel => Some("PidTagMessageSecurityLabel"),
+            Self::TagObsoletedMessageIds => Some("PidTagObsoletedMessageIds"),
+            Self::TagOriginallyIntendedRecipientName => Some("PidTagOriginallyIntendedRecipientName"),
+            Self::TagOriginalEits => Some("PidTagOriginalEits"),
+            Self::TagOriginatorCertificate => Some("PidTagOriginatorCertificate"),
+            Self::TagOriginatorDeliveryReportRequested => Some("PidTagOriginatorDeliveryReportRequested"),
+            Self::TagOriginatorReturnAddress => Some("PidTagOriginatorReturnAddress"),
+            Self::TagParentKey => Some("PidTagParentKey"),
+            Self::TagPriority => Some("PidTagPriority"),
+            Self::TagOriginCheck => Some("PidTagOriginCheck"),
+            Self::TagProofOfSubmissionRequested => Some("PidTagProofOfSubmissionRequested"),
+            Self::TagReadReceiptRequested => Some("PidTagReadReceiptRequested"),
+            Self::TagReceiptTime => Some("PidTagReceiptTime"),
+            Self::TagRecipientReassignmentProhibited => Some("PidTagRecipientReassignmentProhibited"),
+            Self::TagRedirectionHistory => Some("PidTagRedirectionHistory"),
+            Self::TagRelatedMessageIds => Some("PidTagRelatedMessageIds"),
+            Self::TagOriginalSensitivity => Some("PidTagOriginalSensitivity"),
+            Self::TagLanguages => Some("PidTagLanguages"),
+            Self::TagReplyTime => Some("PidTagReplyTime"),
+            Self::TagReportTag => Some("PidTagReportTag"),
+            Self::TagReportTime => Some("PidTagReportTime"),
+            Self::TagReturnedMessageid => Some("PidTagReturnedMessageid"),
+            Self::TagIncompleteCopy => Some("PidTagIncompleteCopy"),
+            Self::TagSensitivity => Some("PidTagSensitivity"),
+            Self::TagSubject => Some("PidTagSubject"),
+            Self::TagSubjectMessageId => Some("PidTagSubjectMessageId"),
+            Self::TagClientSubmitTime => Some("PidTagClientSubmitTime"),
+            Self::TagReportName => Some("PidTagReportName"),
+            Self::TagSentRepresentingSearchKey => Some("PidTagSentRepresentingSearchKey"),
+            Self::TagX400ContentType => Some("PidTagX400ContentType"),
+            Self::TagSubjectPrefix => Some("PidTagSubjectPrefix"),
+            Self::TagNonReceiptReason => Some("PidTagNonReceiptReason"),
+            Self::TagReceivedByEntryId => Some("PidTagReceivedByEntryId"),
+            Self::TagReceivedByName => Some("PidTagReceivedByName"),
+            Self::TagSentRepresentingEntryId => Some("PidTagSentRepresentingEntryId"),
+            Self::TagSentRepresentingName => Some("PidTagSentRepresentingName"),
+            Self::TagReceivedRepresentingEntryId => Some("PidTagReceivedRepresentingEntryId"),
+            Self::TagReceivedRepresentingName => Some("PidTagReceivedRepresentingName"),
+            Self::TagReportEntryId => Some("PidTagReportEntryId"),
+            Self::TagReadReceiptEntryId => Some("PidTagReadReceiptEntryId"),
+            Self::TagMessageSubmissionId => Some("PidTagMessageSubmissionId"),
+            Self::TagProviderSubmitTime => Some("PidTagProviderSubmitTime"),
+            Self::TagOriginalSubject => Some("PidTagOriginalSubject"),
+            Self::TagOriginalMessageClass => Some("PidTagOriginalMessageClass"),
+            Self::TagOriginalAuthorEntryId => Some("PidTagOriginalAuthorEntryId"),
+            Self::TagOriginalAuthorName => Some("PidTagOriginalAuthorName"),
+            Self::TagOriginalSubmitTime => Some("PidTagOriginalSubmitTime"),
+            Self::TagReplyRecipientEntries => Some("PidTagReplyRecipientEntries"),
+            Self::TagReplyRecipientNames => Some("PidTagReplyRecipientNames"),
+            Self::TagReceivedBySearchKey => Some("PidTagReceivedBySearchKey"),
+            Self::TagReceivedRepresentingSearchKey => Some("PidTagReceivedRepresentingSearchKey"),
+            Self::TagReadReceiptSearchKey => Some("PidTagReadReceiptSearchKey"),
+            Self::TagReportSearchKey => Some("PidTagReportSearchKey"),
+            Self::TagOriginalDeliveryTime => Some("PidTagOriginalDeliveryTime"),
+            Self::TagOriginalAuthorSearchKey => Some("PidTagOriginalAuthorSearchKey"),
+            Self::TagMessageToMe => Some("PidTagMessageToMe"),
+            Self::TagMessageCcMe => Some("PidTagMessageCcMe"),
+            Self::TagMessageRecipientMe => Some("PidTagMessageRecipientMe"),
+            Self::TagOriginalSenderName => Some("PidTagOriginalSenderName"),
+            Self::TagOriginalSenderEntryId => Some("PidTagOriginalSenderEntryId"),
+            Self::TagOriginalSenderSearchKey => Some("PidTagOriginalSenderSearchKey"),
+            Self::TagOriginalSentRepresentingName => Some("PidTagOriginalSentRepresentingName"),
+            Self::TagOriginalSentRepresentingEntryId => Some("PidTagOriginalSentRepresentingEntryId"),
+            Self::TagOriginalSentRepresentingSearchKey => Some("PidTagOriginalSentRepresentingSearchKey"),
+            Self::TagStartDate => Some("PidTagStartDate"),
+            Self::TagEndDate => Some("PidTagEndDate"),
+            Self::TagOwnerAppointmentId => Some("PidTagOwnerAppointmentId"),
+            Self::TagResponseRequested => Some("PidTagResponseRequested"),
+            Self::TagSentRepresentingAddressType => Some("PidTagSentRepresentingAddressType"),
+            Self::TagSentRepresentingEmailAddress => Some("PidTagSentRepresentingEmailAddress"),
+            Self::TagOriginalSenderAddressType => Some("PidTagOriginalSenderAddressType"),
+            Self::TagOriginalSenderEmailAddress => Some("PidTagOriginalSenderEmailAddress"),
+            Self::TagOriginalSentRepresentingAddressType => Some("PidTagOriginalSentRepresentingAddressType"),
+            Self::TagOriginalSentRepresentingEmailAddress => Some("PidTagOriginalSentRepresentingEmailAddress"),
+            Self::TagConversationTopic => Some("PidTagConversationTopic"),
+            Self::TagConversationIndex => Some("PidTagConversationIndex"),
+            Self::TagOriginalDisplayBcc => Some("PidTagOriginalDisplayBcc"),
+            Self::TagOriginalDisplayCc => Some("PidTagOriginalDisplayCc"),
+            Self::TagOriginalDisplayTo => Some("PidTagOriginalDisplayTo"),
+            Self::TagReceivedByAddressType => Some("PidTagReceivedByAddressType"),
+            Self::TagReceivedByEmailAddress => Some("PidTagReceivedByEmailAddress"),
+            Self::TagReceivedRepresentingAddressType => Some("PidTagReceivedRepresentingAddressType"),
+            Self::TagReceivedRepresentingEmailAddress => Some("PidTagReceivedRepresentingEmailAddress"),
+            Self::TagOriginalAuthorAddressType => Some("PidTagOriginalAuthorAddressType"),
+            Self::TagOriginalAuthorEmailAddress => Some("PidTagOriginalAuthorEmailAddress"),
+            Self::TagOriginallyIntendedRecipAddrtype => Some("PidTagOriginallyIntendedRecipAddrtype"),
+            Self::TagOriginallyIntendedRecipEmailAddress => Some("PidTagOriginallyIntendedRecipEmailAddress"),
+            Self::TagTransportMessageHeaders => Some("PidTagTransportMessageHeaders"),
+            Self::TagTnefCorrelationKey => Some("PidTagTnefCorrelationKey"),
+            Self::TagReportDisposition => Some("PidTagReportDisposition"),
+            Self::TagReportDispositionMode => Some("PidTagReportDispositionMode"),
+            Self::TagReportOriginalSender => Some("PidTagReportOriginalSender"),
+            Self::TagReportDispositionToNames => Some("PidTagReportDispositionToNames"),
+            Self::TagReportDispositionToEmailAddresses => Some("PidTagReportDispositionToEmailAddresses"),
+            Self::TagReportDispositionOptions => Some("PidTagReportDispositionOptions"),
+            Self::TagSpamTrustedSenders => Some("PidTagSpamTrustedSenders"),
+            Self::TagSpamTrustedRecipients => Some("PidTagSpamTrustedRecipients"),
+            Self::TagSpamJunkSenders => Some("PidTagSpamJunkSenders"),
+            Self::TagSpamThreshold => Some("PidTagSpamThreshold"),
+            Self::TagAddressBookRoomCapacity => Some("PidTagAddressBookRoomCapacity"),
+            Self::TagAddressBookRoomDescription => Some("PidTagAddressBookRoomDescription"),
+            Self::TagContentIntegrityCheck => Some("PidTagContentIntegrityCheck"),
+            Self::TagExplicitConversion => Some("PidTagExplicitConversion"),
+            Self::TagIpmReturnRequested => Some("PidTagIpmReturnRequested"),
+            Self::TagMessageToken => Some("PidTagMessageToken"),
+            Self::TagNonDeliveryReportReasonCode => Some("PidTagNonDeliveryReportReasonCode"),
+            Self::TagNonDeliveryReportDiagCode => Some("PidTagNonDeliveryReportDiagCode"),
+            Self::TagNonDeliveryReportStatusCode => Some("PidTagNonDeliveryReportStatusCode"),
+            Self::TagDeliveryPoint => Some("PidTagDeliveryPoint"),
+            Self::TagOriginatorNonDeliveryReportRequested => Some("PidTagOriginatorNonDeliveryReportRequested"),
+            Self::TagOriginatorRequestedAlternateRecipient => Some("PidTagOriginatorRequestedAlternateRecipient"),
+            Self::TagPhysicalDeliveryBureauFaxDelivery => Some("PidTagPhysicalDeliveryBureauFaxDelivery"),
+            Self::TagPhysicalDeliveryMode => Some("PidTagPhysicalDeliveryMode"),
+            Self::TagPhysicalDeliveryReportRequest => Some("PidTagPhysicalDeliveryReportRequest"),
+            Self::TagPhysicalForwardingAddress => Some("PidTagPhysicalForwardingAddress"),
+            Self::TagPhysicalForwardingAddressRequested => Some("PidTagPhysicalForwardingAddressRequested"),
+            Self::TagPhysicalForwardingProhibited => Some("PidTagPhysicalForwardingProhibited"),
+            Self::TagPhysicalRenditionAttributes => Some("PidTagPhysicalRenditionAttributes"),
+            Self::TagProofOfDelivery => Some("PidTagProofOfDelivery"),
+            Self::TagProofOfDeliveryRequested => Some("PidTagProofOfDeliveryRequested"),
+            Self::TagRecipientCertificate => Some("PidTagRecipientCertificate"),
+            Self::TagRecipientNumberForAdvice => Some("PidTagRecipientNumberForAdvice"),
+            Self::TagRecipientType => Some("PidTagRecipientType"),
+            Self::TagRegisteredMailType => Some("PidTagRegisteredMailType"),
+            Self::TagReplyRequested => Some("PidTagReplyRequested"),
+            Self::TagRequestedDeliveryMethod => Some("PidTagRequestedDeliveryMethod"),
+            Self::TagSenderEntryId => Some("PidTagSenderEntryId"),
+            Self::TagSenderName => Some("PidTagSenderName"),
+            Self::TagSupplementaryInfo => Some("PidTagSupplementaryInfo"),
+            Self::TagTypeOfX400User => Some("PidTagTypeOfX400User"),
+            Self::TagSenderSearchKey => Some("PidTagSenderSearchKey"),
+            Self::TagSenderAddressType => Some("PidTagSenderAddressType"),
+            Self::TagSenderEmailAddress => Some("PidTagSenderEmailAddress"),
+            Self::TagRemoteMessageTransferAgent => Some("PidTagRemoteMessageTransferAgent"),
+            Self::TagDeleteAfterSubmit => Some("PidTagDeleteAfterSubmit"),
+            Self::TagDisplayBcc => Some("PidTagDisplayBcc"),
+            Self::TagDisplayCc => Some("PidTagDisplayCc"),
+            Self::TagDisplayTo => Some("PidTagDisplayTo"),
+            Self::TagParentDisplay => Some("PidTagParentDisplay"),
+            Self::TagMessageDeliveryTime => Some("PidTagMessageDeliveryTime"),
+            Self::TagMessageFlags => Some("PidTagMessageFlags"),
+            Self::TagMessageSize => Some("PidTagMessageSize"),
+            Self::TagParentEntryId => Some("PidTagParentEntryId"),
+            Self::TagSentMailEntryId => Some("PidTagSentMailEntryId"),
+            Self::TagCorrelate => Some("PidTagCorrelate"),
+            Self::TagCorrelateMtsid => Some("PidTagCorrelateMtsid"),
+            Self::TagDiscreteValues => Some("PidTagDiscreteValues"),
+            Self::TagResponsibility => Some("PidTagResponsibility"),
+            Self::TagSpoolerStatus => Some("PidTagSpoolerStatus"),
+            Self::TagMessageRecipients => Some("PidTagMessageRecipients"),
+            Self::TagMessageAttachments => Some("PidTagMessageAttachments"),
+            Self::TagSubmitFlags => Some("PidTagSubmitFlags"),
+            Self::TagRecipientStatus => Some("PidTagRecipientStatus"),
+            Self::TagTransportKey => Some("PidTagTransportKey"),
+            Self::TagMessageStatus => Some("PidTagMessageStatus"),
+            Self::TagMessageDownloadTime => Some("PidTagMessageDownloadTime"),
+            Self::TagHasAttachments => Some("PidTagHasAttachments"),
+            Self::TagBodyCrc => Some("PidTagBodyCrc"),
+            Self::TagNormalizedSubject => Some("PidTagNormalizedSubject"),
+            Self::TagRtfInSync => Some("PidTagRtfInSync"),
+            Self::TagAttachSize => Some("PidTagAttachSize"),
+            Self::TagAttachNumber => Some("PidTagAttachNumber"),
+            Self::TagPreprocess => Some("PidTagPreprocess"),
+            Self::TagInternetArticleNumber => Some("PidTagInternetArticleNumber"),
+            Self::TagOriginatingMtaCertificate => Some("PidTagOriginatingMtaCertificate"),
+            Self::TagProofOfSubmission => Some("PidTagProofOfSubmission"),
+            Self::TagPrimarySendAccount => Some("PidTagPrimarySendAccount"),
+            Self::TagNextSendAcct => Some("PidTagNextSendAcct"),
+            Self::TagToDoItemFlags => Some("PidTagToDoItemFlags"),
+            Self::TagSwappedToDoStore => Some("PidTagSwappedToDoStore"),
+            Self::TagSwappedToDoData => Some("PidTagSwappedToDoData"),
+            Self::TagRead => Some("PidTagRead"),
+            Self::TagSecurityDescriptorAsXml => Some("PidTagSecurityDescriptorAsXml"),
+            Self::TagTrustSender => Some("PidTagTrustSender"),
+            Self::TagExchangeNTSecurityDescriptor => Some("PidTagExchangeNTSecurityDescriptor"),
+            Self::TagExtendedRuleMessageActions => Some("PidTagExtendedRuleMessageActions"),
+            Self::TagExtendedRuleMessageCondition => Some("PidTagExtendedRuleMessageCondition"),
+            Self::TagExtendedRuleSizeLimit => Some("PidTagExtendedRuleSizeLimit"),
+            Self::TagTnefUnprocessedProps => Some("PidTagTnefUnprocessedProps"),
+            Self::TagProviderItemId => Some("PidTagProviderItemId"),
+            Self::TagProviderParentItemId => Some("PidTagProviderParentItemId"),
+            Self::TagSearchAttachments => Some("PidTagSearchAttachments"),
+            Self::TagSearchRecipientEmailTo => Some("PidTagSearchRecipientEmailTo"),
+            Self::TagSearchRecipientEmailCc => Some("PidTagSearchRecipientEmailCc"),
+            Self::TagSearchRecipientEmailBcc => Some("PidTagSearchRecipientEmailBcc"),
+            Self::TagAccess => Some("PidTagAccess"),
+            Self::TagRowType => Some("PidTagRowType"),
+            Self::TagInstanceKey => Some("PidTagInstanceKey"),
+            Self::TagAccessLevel => Some("PidTagAccessLevel"),
+            Self::TagMappingSignature => Some("PidTagMappingSignature"),
+            Self::TagRecordKey => Some("PidTagRecordKey"),
+            Self::TagStoreRecordKey => Some("PidTagStoreRecordKey"),
+            Self::TagStoreEntryId => Some("PidTagStoreEntryId"),
+            Self::TagMiniIcon => Some("PidTagMiniIcon"),
+            Self::TagIcon => Some("PidTagIcon"),
+            Self::TagObjectType => Some("PidTagObjectType"),
+            Self::TagEntryId => Some("PidTagEntryId"),
+            Self::TagBody => Some("PidTagBody"),
+            Self::TagReportText => Some("PidTagReportText"),
+            Self::TagOriginatorAndDistributionListExpansionHistory => Some("PidTagOriginatorAndDistributionListExpansionHistory"),
+            Self::TagReportingDistributionListName => Some("PidTagReportingDistributionListName"),
+            Self::TagReportingMessageTransferAgentCertificate => Some("PidTagReportingMessageTransferAgentCertificate"),
+            Self::TagRtfSyncBodyCrc => Some("PidTagRtfSyncBodyCrc"),
+            Self::TagRtfSyncBodyCount => Some("PidTagRtfSyncBodyCount"),
+            Self::TagRtfSyncBodyTag => Some("PidTagRtfSyncBodyTag"),
+            Self::TagRtfCompressed => Some("PidTagRtfCompressed"),
+            Self::TagRtfSyncPrefixCount => Some("PidTagRtfSyncPrefixCount"),
+            Self::TagRtfSyncTrailingCount => Some("PidTagRtfSyncTrailingCount"),
+            Self::TagOriginallyIntendedRecipEntryId => Some("PidTagOriginallyIntendedRecipEntryId"),
+            Self::TagBodyHtml => Some("PidTagBodyHtml"),
+            Self::TagBodyContentLocation => Some("PidTagBodyContentLocation"),
+            Self::TagBodyContentId => Some("PidTagBodyContentId"),
+            Self::TagNativeBody => Some("PidTagNativeBody"),
+            Self::TagInternetApproved => Some("PidTagInternetApproved"),
+            Self::TagInternetControl => Some("PidTagInternetControl"),
+            Self::TagInternetDistribution => Some("PidTagInternetDistribution"),
+            Self::TagInternetFollowupTo => Some("PidTagInternetFollowupTo"),
+            Self::TagInternetLines => Some("PidTagInternetLines"),
+            Self::TagInternetMessageId => Some("PidTagInternetMessageId"),
+            Self::TagInternetOrganization => Some("PidTagInternetOrganization"),
+            Self::TagInternetNntpPath => Some("PidTagInternetNntpPath"),
+            Self::TagInternetReferences => Some("PidTagInternetReferences"),
+            Self::TagInReplyToId => Some("PidTagInReplyToId"),
+            Self::TagListHelp => Some("PidTagListHelp"),
+            Self::TagListSubscribe => Some("PidTagListSubscribe"),
+            Self::TagListUnsubscribe => Some("PidTagListUnsubscribe"),
+            Self::TagOriginalMessageId => Some("PidTagOriginalMessageId"),
+            Self::TagIconIndex => Some("PidTagIconIndex"),
+            Self::TagLastVerbExecuted => Some("PidTagLastVerbExecuted"),
+            Self::TagLastVerbExecutionTime => Some("PidTagLastVerbExecutionTime"),
+            Self::TagFlagStatus => Some("PidTagFlagStatus"),
+            Self::TagFlagCompleteTime => Some("PidTagFlagCompleteTime"),
+            Self::TagFollowupIcon => Some("PidTagFollowupIcon"),
+            Self::TagBlockStatus => Some("PidTagBlockStatus"),
+            Self::TagItemTemporaryflags => Some("PidTagItemTemporaryflags"),
+            Self::TagConflictItems => Some("PidTagConflictItems"),
+            Self::TagICalendarStartTime => Some("PidTagICalendarStartTime"),
+            Self::TagICalendarEndTime => Some("PidTagICalendarEndTime"),
+            Self::TagCdoRecurrenceid => Some("PidTagCdoRecurrenceid"),
+            Self::TagICalendarReminderNextTime => Some("PidTagICalendarReminderNextTime"),
+            Self::TagImapCachedMsgsize => Some("PidTagImapCachedMsgsize"),
+            Self::TagUrlComponentName => Some("PidTagUrlComponentName"),
+            Self::TagAttributeHidden => Some("PidTagAttributeHidden"),
+            Self::TagAttributeReadOnly => Some("PidTagAttributeReadOnly"),
+            Self::TagRowid => Some("PidTagRowid"),
+            Self::TagDisplayName => Some("PidTagDisplayName"),
+            Self::TagAddressType => Some("PidTagAddressType"),
+            Self::TagEmailAddress => Some("PidTagEmailAddress"),
+            Self::TagComment => Some("PidTagComment"),
+            Self::TagDepth => Some("PidTagDepth"),
+            Self::TagProviderDisplay => Some("PidTagProviderDisplay"),
+            Self::TagCreationTime => Some("PidTagCreationTime"),
+            Self::TagLastModificationTime => Some("PidTagLastModificationTime"),
+            Self::TagResourceFlags => Some("PidTagResourceFlags"),
+            Self::TagProviderDllName => Some("PidTagProviderDllName"),
+            Self::TagSearchKey => Some("PidTagSearchKey"),
+            Self::TagProviderUid => Some("PidTagProviderUid"),
+            Self::TagProviderOrdinal => Some("PidTagProviderOrdinal"),
+            Self::TagTargetEntryId => Some("PidTagTargetEntryId"),
+            Self::TagConversationId => Some("PidTagConversationId"),
+            Self::TagConversationIndexTracking => Some("PidTagConversationIndexTracking"),
+            Self::TagArchiveTag => Some("PidTagArchiveTag"),
+            Self::TagPolicyTag => Some("PidTagPolicyTag"),
+            Self::TagRetentionPeriod => Some("PidTagRetentionPeriod"),
+            Self::TagStartDateEtc => Some("PidTagStartDateEtc"),
+            Self::TagRetentionDate => Some("PidTagRetentionDate"),
+            Self::TagRetentionFlags => Some("PidTagRetentionFlags"),
+            Self::TagArchivePeriod => Some("PidTagArchivePeriod"),
+            Self::TagArchiveDate => Some("PidTagArchiveDate"),
+            Self::TagFormVersion => Some("PidTagFormVersion"),
+            Self::TagFormClassId => Some("PidTagFormClassId"),
+            Self::TagFormContactName => Some("PidTagFormContactName"),
+            Self::TagFormCategory => Some("PidTagFormCategory"),
+            Self::TagFormCategorySub => Some("PidTagFormCategorySub"),
+            Self::TagFormHostMap => Some("PidTagFormHostMap"),
+            Self::TagFormHidden => Some("PidTagFormHidden"),
+            Self::TagFormDesignerName => Some("PidTagFormDesignerName"),
+            Self::TagFormDesignerGuid => Some("PidTagFormDesignerGuid"),
+            Self::TagFormMessageBehavior => Some("PidTagFormMessageBehavior"),
+            Self::TagDefaultStore => Some("PidTagDefaultStore"),
+            Self::TagStoreSupportMask => Some("PidTagStoreSupportMask"),
+            Self::TagStoreState => Some("PidTagStoreState"),
+            Self::TagStoreUnicodeMask => Some("PidTagStoreUnicodeMask"),
+            Self::TagStoreProvider => Some("PidTagStoreProvider"),
+            Self::TagReceiveFolderSettings => Some("PidTagReceiveFolderSettings"),
+            Self::TagProviderIcon => Some("PidTagProviderIcon"),
+            Self::TagProviderDisplayName => Some("PidTagProviderDisplayName"),
+            Self::TagSearchOwnerId => Some("PidTagSearchOwnerId"),
+            Self::TagServerTypeDisplayName => Some("PidTagServerTypeDisplayName"),
+            Self::TagServerConnectedIcon => Some("PidTagServerConnectedIcon"),
+            Self::TagServerAccountIcon => Some("PidTagServerAccountIcon"),
+            Self::TagValidFolderMask => Some("PidTagValidFolderMask"),
+            Self::TagIpmSubtreeEntryId => Some("PidTagIpmSubtreeEntryId"),
+            Self::TagIpmOutboxEntryId => Some("PidTagIpmOutboxEntryId"),
+            Self::TagIpmWastebasketEntryId => Some("PidTagIpmWastebasketEntryId"),
+            Self::TagIpmSentMailEntryId => Some("PidTagIpmSentMailEntryId"),
+            Self::TagViewsEntryId => Some("PidTagViewsEntryId"),
+            Self::TagCommonViewsEntryId => Some("PidTagCommonViewsEntryId"),
+            Self::TagFinderEntryId => Some("PidTagFinderEntryId"),
+            Self::TagIpmArchiveEntryId => Some("PidTagIpmArchiveEntryId"),
+            Self::TagContainerFlags => Some("PidTagContainerFlags"),
+            Self::TagFolderType => Some("PidTagFolderType"),
+            Self::TagContentCount => Some("PidTagContentCount"),
+            Self::TagContentUnreadCount => Some("PidTagContentUnreadCount"),
+            Self::TagCreateTemplates => Some("PidTagCreateTemplates"),
+            Self::TagDetailsTable => Some("PidTagDetailsTable"),
+            Self::TagSearch => Some("PidTagSearch"),
+            Self::TagSelectable => Some("PidTagSelectable"),
+            Self::TagSubfolders => Some("PidTagSubfolders"),
+            Self::TagStatus => Some("PidTagStatus"),
+            Self::TagAnr => Some("PidTagAnr"),
+            Self::TagContainerHierarchy => Some("PidTagContainerHierarchy"),
+            Self::TagContainerContents => Some("PidTagContainerContents"),
+            Self::TagFolderAssociatedContents => Some("PidTagFolderAssociatedContents"),
+            Self::TagDefCreateDl => Some("PidTagDefCreateDl"),
+            Self::TagDefCreateMailuser => Some("PidTagDefCreateMailuser"),
+            Self::TagContainerClass => Some("PidTagContainerClass"),
+            Self::TagAbProviderId => Some("PidTagAbProviderId"),
+            Self::TagDefaultViewEntryId => Some("PidTagDefaultViewEntryId"),
+            Self::TagAssociatedContentCount => Some("PidTagAssociatedContentCount"),
+            Self::TagIpmAppointmentEntryId => Some("PidTagIpmAppointmentEntryId"),
+            Self::TagIpmContactEntryId => Some("PidTagIpmContactEntryId"),
+            Self::TagIpmJournalEntryId => Some("PidTagIpmJournalEntryId"),
+            Self::TagIpmNoteEntryId => Some("PidTagIpmNoteEntryId"),
+            Self::TagIpmTaskEntryId => Some("PidTagIpmTaskEntryId"),
+            Self::TagRemindersOnlineEntryId => Some("PidTagRemindersOnlineEntryId"),
+            Self::TagIpmDraftsEntryId => Some("PidTagIpmDraftsEntryId"),
+            Self::TagAdditionalRenEntryIds => Some("PidTagAdditionalRenEntryIds"),
+            Self::TagAdditionalRenEntryIdsEx => Some("PidTagAdditionalRenEntryIdsEx"),
+            Self::TagExtendedFolderFlags => Some("PidTagExtendedFolderFlags"),
+            Self::TagFolderWebViewInfo => Some("PidTagFolderWebViewInfo"),
+            Self::TagOrdinalMost => Some("PidTagOrdinalMost"),
+            Self::TagUserFields => Some("PidTagUserFields"),
+            Self::TagFreeBusyEntryIds => Some("PidTagFreeBusyEntryIds"),
+            Self::TagDefaultPostMessageClass => Some("PidTagDefaultPostMessageClass"),
+            Self::TagAgingPeriod => Some("PidTagAgingPeriod"),
+            Self::TagAgingGranularity => Some("PidTagAgingGranularity"),
+            Self::TagClientActivelyEditingUntil => Some("PidTagClientActivelyEditingUntil"),
+            Self::TagAttachDataBinary => Some("PidTagAttachDataBinary"),
+            Self::TagAttachEncoding => Some("PidTagAttachEncoding"),
+            Self::TagAttachExtension => Some("PidTagAttachExtension"),
+            Self::TagAttachFilename => Some("PidTagAttachFilename"),
+            Self::TagAttachMethod => Some("PidTagAttachMethod"),
+            Self::TagAttachLongFilename => Some("PidTagAttachLongFilename"),
+            Self::TagAttachPathname => Some("PidTagAttachPathname"),
+            Self::TagAttachRendering => Some("PidTagAttachRendering"),
+            Self::TagAttachTag => Some("PidTagAttachTag"),
+            Self::TagRenderingPosition => Some("PidTagRenderingPosition"),
+            Self::TagAttachTransportName => Some("PidTagAttachTransportName"),
+            Self::TagAttachLongPathname => Some("PidTagAttachLongPathname"),
+            Self::TagAttachMimeTag => Some("PidTagAttachMimeTag"),
+            Self::TagAttachAdditionalInformation => Some("PidTagAttachAdditionalInformation"),
+            Self::TagAttachMimeSequence => Some("PidTagAttachMimeSequence"),
+            Self::TagAttachContentBase => Some("PidTagAttachContentBase"),
+            Self::TagAttachContentId => Some("PidTagAttachContentId"),
+            Self::TagAttachContentLocation => Some("PidTagAttachContentLocation"),
+            Self::TagAttachFlags => Some("PidTagAttachFlags"),
+            Self::TagAttachPayloadProviderGuidString => Some("PidTagAttachPayloadProviderGuidString"),
+            Self::TagAttachPayloadClass => Some("PidTagAttachPayloadClass"),
+            Self::TagTextAttachmentCharset => Some("PidTagTextAttachmentCharset"),
+            Self::TagDisplayType => Some("PidTagDisplayType"),
+            Self::TagTemplateid => Some("PidTagTemplateid"),
+            Self::TagDisplayTypeEx => Some("PidTagDisplayTypeEx"),
+            Self::TagSmtpAddress => Some("PidTagSmtpAddress"),
+            Self::TagAddressBookDisplayNamePrintable => Some("PidTagAddressBookDisplayNamePrintable"),
+            Self::TagAccount => Some("PidTagAccount"),
+            Self::TagAlternateRecipient => Some("PidTagAlternateRecipient"),
+            Self::TagCallbackTelephoneNumber => Some("PidTagCallbackTelephoneNumber"),
+            Self::TagConversionProhibited => Some("PidTagConversionProhibited"),
+            Self::TagGeneration => Some("PidTagGeneration"),
+            Self::TagGivenName => Some("PidTagGivenName"),
+            Self::TagGovernmentIdNumber => Some("PidTagGovernmentIdNumber"),
+            Self::TagBusinessTelephoneNumber => Some("PidTagBusinessTelephoneNumber"),
+            Self::TagHomeTelephoneNumber => Some("PidTagHomeTelephoneNumber"),
+            Self::TagInitials => Some("PidTagInitials"),
+            Self::TagKeyword => Some("PidTagKeyword"),
+            Self::TagLanguage => Some("PidTagLanguage"),
+            Self::TagLocation => Some("PidTagLocation"),
+            Self::TagMailPermission => Some("PidTagMailPermission"),
+            Self::TagMessageHandlingSystemCommonName => Some("PidTagMessageHandlingSystemCommonName"),
+            Self::TagOrganizationalIdNumber => Some("PidTagOrganizationalIdNumber"),
+            Self::TagSurname => Some("PidTagSurname"),
+            Self::TagOriginalEntryId => Some("PidTagOriginalEntryId"),
+            Self::TagOriginalDisplayName => Some("PidTagOriginalDisplayName"),
+            Self::TagOriginalSearchKey => Some("PidTagOriginalSearchKey"),
+            Self::TagPostalAddress => Some("PidTagPostalAddress"),
+            Self::TagCompanyName => Some("PidTagCompanyName"),
+            Self::TagTitle => Some("PidTagTitle"),
+            Self::TagDepartmentName => Some("PidTagDepartmentName"),
+            Self::TagOfficeLocation => Some("PidTagOfficeLocation"),
+            Self::TagPrimaryTelephoneNumber => Some("PidTagPrimaryTelephoneNumber"),
+            Self::TagBusiness2TelephoneNumber => Some("PidTagBusiness2TelephoneNumber"),
+            Self::TagMobileTelephoneNumber => Some("PidTagMobileTelephoneNumber"),
+            Self::TagRadioTelephoneNumber => Some("PidTagRadioTelephoneNumber"),
+            Self::TagCarTelephoneNumber => Some("PidTagCarTelephoneNumber"),
+            Self::TagOtherTelephoneNumber => Some("PidTagOtherTelephoneNumber"),
+            Self::TagTransmittableDisplayName => Some("PidTagTransmittableDisplayName"),
+            Self::TagPagerTelephoneNumber => Some("PidTagPagerTelephoneNumber"),
+            Self::TagUserCertificate => Some("PidTagUserCertificate"),
+            Self::TagPrimaryFaxNumber => Some("PidTagPrimaryFaxNumber"),
+            Self::TagBusinessFaxNumber => Some("PidTagBusinessFaxNumber"),
+            Self::TagHomeFaxNumber => Some("PidTagHomeFaxNumber"),
+            Self::TagCountry => Some("PidTagCountry"),
+            Self::TagLocality => Some("PidTagLocality"),
+            Self::TagStateOrProvince => Some("PidTagStateOrProvince"),
+            Self::TagStreetAddress => Some("PidTagStreetAddress"),
+            Self::TagPostalCode => Some("PidTagPostalCode"),
+            Self::TagPostOfficeBox => Some("PidTagPostOfficeBox"),
+            Self::TagTelexNumber => Some("PidTagTelexNumber"),
+            Self::TagIsdnNumber => Some("PidTagIsdnNumber"),
+            Self::TagAssistantTelephoneNumber => Some("PidTagAssistantTelephoneNumber"),
+            Self::TagHome2TelephoneNumber => Some("PidTagHome2TelephoneNumber"),
+            Self::TagAssistant => Some("PidTagAssistant"),
+            Self::TagSendRichInfo => Some("PidTagSendRichInfo"),
+            Self::TagWeddingAnniversary => Some("PidTagWeddingAnniversary"),
+            Self::TagBirthday => Some("PidTagBirthday"),
+            Self::TagHobbies => Some("PidTagHobbies"),
+            Self::TagMiddleName => Some("PidTagMiddleName"),
+            Self::TagDisplayNamePrefix => Some("PidTagDisplayNamePrefix"),
+            Self::TagProfession => Some("PidTagProfession"),
+            Self::TagReferredByName => Some("PidTagReferredByName"),
+            Self::TagSpouseName => Some("PidTagSpouseName"),
+            Self::TagComputerNetworkName => Some("PidTagComputerNetworkName"),
+            Self::TagCustomerId => Some("PidTagCustomerId"),
+            Self::TagTelecommunicationsDeviceForDeafTelephoneNumber => Some("PidTagTelecommunicationsDeviceForDeafTelephoneNumber"),
+            Self::TagFtpSite => Some("PidTagFtpSite"),
+            Self::TagGender => Some("PidTagGender"),
+            Self::TagManagerName => Some("PidTagManagerName"),
+            Self::TagNickname => Some("PidTagNickname"),
+            Self::TagPersonalHomePage => Some("PidTagPersonalHomePage"),
+            Self::TagBusinessHomePage => Some("PidTagBusinessHomePage"),
+            Self::TagContactVersion => Some("PidTagContactVersion"),
+            Self::TagContactAddressTypes => Some("PidTagContactAddressTypes"),
+            Self::TagCompanyMainTelephoneNumber => Some("PidTagCompanyMainTelephoneNumber"),
+            Self::TagChildrensNames => Some("PidTagChildrensNames"),
+            Self::TagHomeAddressCity => Some("PidTagHomeAddressCity"),
+            Self::TagHomeAddressCountry => Some("PidTagHomeAddressCountry"),
+            Self::TagHomeAddressPostalCode => Some("PidTagHomeAddressPostalCode"),
+            Self::TagHomeAddressStateOrProvince => Some("PidTagHomeAddressStateOrProvince"),
+            Self::TagHomeAddressStreet => Some("PidTagHomeAddressStreet"),
+            Self::TagHomeAddressPostOfficeBox => Some("PidTagHomeAddressPostOfficeBox"),
+            Self::TagOtherAddressCity => Some("PidTagOtherAddressCity"),
+            Self::TagOtherAddressCountry => Some("PidTagOtherAddressCountry"),
+            Self::TagOtherAddressPostalCode => Some("PidTagOtherAddressPostalCode"),
+            Self::TagOtherAddressStateOrProvince => Some("PidTagOtherAddressStateOrProvince"),
+            Self::TagOtherAddressStreet => Some("PidTagOtherAddressStreet"),
+            Self::TagOtherAddressPostOfficeBox => Some("PidTagOtherAddressPostOfficeBox"),
+            Self::TagUserX509Certificate => Some("PidTagUserX509Certificate"),
+            Self::TagSendInternetEncoding => Some("PidTagSendInternetEncoding"),
+            Self::TagStoreProviders => Some("PidTagStoreProviders"),
+            Self::TagAbProviders => Some("PidTagAbProviders"),
+            Self::TagTransportProviders => Some("PidTagTransportProviders"),
+            Self::TagDefaultProfile => Some("PidTagDefaultProfile"),
+            Self::TagAbSearchPath => Some("PidTagAbSearchPath"),
+            Self::TagAbDefaultDir => Some("PidTagAbDefaultDir"),
+            Self::TagAbDefaultPab => Some("PidTagAbDefaultPab"),
+            Self::TagServiceName => Some("PidTagServiceName"),
+            Self::TagServiceDllName => Some("PidTagServiceDllName"),
+            Self::TagServiceEntryName => Some("PidTagServiceEntryName"),
+            Self::TagServiceUid => Some("PidTagServiceUid"),
+            Self::TagServiceExtraUids => Some("PidTagServiceExtraUids"),
+            Self::TagServices => Some("PidTagServices"),
+            Self::TagServiceSupportFiles => Some("PidTagServiceSupportFiles"),
+            Self::TagServiceDeleteFiles => Some("PidTagServiceDeleteFiles"),
+            Self::TagAbSearchPathUpdate => Some("PidTagAbSearchPathUpdate"),
+            Self::TagProfileName => Some("PidTagProfileName"),
+            Self::TagServiceInstallId => Some("PidTagServiceInstallId"),
+            Self::TagIdentityDisplay => Some("PidTagIdentityDisplay"),
+            Self::TagIdentityEntryId => Some("PidTagIdentityEntryId"),
+            Self::TagResourceMethods => Some("PidTagResourceMethods"),
+            Self::TagResourceType => Some("PidTagResourceType"),
+            Self::TagStatusCode => Some("PidTagStatusCode"),
+            Self::TagIdentitySearchKey => Some("PidTagIdentitySearchKey"),
+            Self::TagOwnStoreEntryId => Some("PidTagOwnStoreEntryId"),
+            Self::TagResourcePath => Some("PidTagResourcePath"),
+            Self::TagStatusString => Some("PidTagStatusString"),
+            Self::TagRemoteProgress => Some("PidTagRemoteProgress"),
+            Self::TagRemoteProgressText => Some("PidTagRemoteProgressText"),
+            Self::TagRemoteValidateOk => Some("PidTagRemoteValidateOk"),
+            Self::TagControlFlags => Some("PidTagControlFlags"),
+            Self::TagControlStructure => Some("PidTagControlStructure"),
+            Self::TagControlType => Some("PidTagControlType"),
+            Self::TagDeltaX => Some("PidTagDeltaX"),
+            Self::TagDeltaY => Some("PidTagDeltaY"),
+            Self::TagXCoordinate => Some("PidTagXCoordinate"),
+            Self::TagYCoordinate => Some("PidTagYCoordinate"),
+            Self::TagControlId => Some("PidTagControlId"),
+            Self::TagInitialDetailsPane => Some("PidTagInitialDetailsPane"),
+            Self::TagInternetCodepage => Some("PidTagInternetCodepage"),
+            Self::TagAutoResponseSuppress => Some("PidTagAutoResponseSuppress"),
+            Self::TagAccessControlListData => Some("PidTagAccessControlListData"),
+            Self::TagRulesTable => Some("PidTagRulesTable"),
+            Self::TagDelegatedByRule => Some("PidTagDelegatedByRule"),
+            Self::TagResolveMethod => Some("PidTagResolveMethod"),
+            Self::TagHasDeferredActionMessages => Some("PidTagHasDeferredActionMessages"),
+            Self::TagDeferredSendNumber => Some("PidTagDeferredSendNumber"),
+            Self::TagDeferredSendUnits => Some("PidTagDeferredSendUnits"),
+            Self::TagExpiryNumber => Some("PidTagExpiryNumber"),
+            Self::TagExpiryUnits => Some("PidTagExpiryUnits"),
+            Self::TagDeferredSendTime => Some("PidTagDeferredSendTime"),
+            Self::TagConflictEntryId => Some("PidTagConflictEntryId"),
+            Self::TagMessageLocaleId => Some("PidTagMessageLocaleId"),
+            Self::TagCreatorName => Some("PidTagCreatorName"),
+            Self::TagCreatorEntryId => Some("PidTagCreatorEntryId"),
+            Self::TagLastModifierName => Some("PidTagLastModifierName"),
+            Self::TagLastModifierEntryId => Some("PidTagLastModifierEntryId"),
+            Self::TagMessageCodepage => Some("PidTagMessageCodepage"),
+            Self::TagSentRepresentingFlags => Some("PidTagSentRepresentingFlags"),
+            Self::TagReadReceiptAddressType => Some("PidTagReadReceiptAddressType"),
+            Self::TagReadReceiptEmailAddress => Some("PidTagReadReceiptEmailAddress"),
+            Self::TagReadReceiptName => Some("PidTagReadReceiptName"),
+            Self::TagContentFilterSpamConfidenceLevel => Some("PidTagContentFilterSpamConfidenceLevel"),
+            Self::TagSenderIdStatus => Some("PidTagSenderIdStatus"),
+            Self::TagHierRev => Some("PidTagHierRev"),
+            Self::TagPurportedSenderDomain => Some("PidTagPurportedSenderDomain"),
+            Self::TagInternetMailOverrideFormat => Some("PidTagInternetMailOverrideFormat"),
+            Self::TagMessageEditorFormat => Some("PidTagMessageEditorFormat"),
+            Self::TagSenderSmtpAddress => Some("PidTagSenderSmtpAddress"),
+            Self::TagSentRepresentingSmtpAddress => Some("PidTagSentRepresentingSmtpAddress"),
+            Self::TagReadReceiptSmtpAddress => Some("PidTagReadReceiptSmtpAddress"),
+            Self::TagReceivedBySmtpAddress => Some("PidTagReceivedBySmtpAddress"),
+            Self::TagReceivedRepresentingSmtpAddress => Some("PidTagReceivedRepresentingSmtpAddress"),
+            Self::TagRecipientOrder => Some("PidTagRecipientOrder"),
+            Self::TagRecipientProposed => Some("PidTagRecipientProposed"),
+            Self::TagRecipientProposedStartTime => Some("PidTagRecipientProposedStartTime"),
+            Self::TagRecipientProposedEndTime => Some("PidTagRecipientProposedEndTime"),
+            Self::TagRecipientDisplayName => Some("PidTagRecipientDisplayName"),
+            Self::TagRecipientEntryId => Some("PidTagRecipientEntryId"),
+            Self::TagRecipientTrackStatusTime => Some("PidTagRecipientTrackStatusTime"),
+            Self::TagRecipientFlags => Some("PidTagRecipientFlags"),
+            Self::TagRecipientTrackStatus => Some("PidTagRecipientTrackStatus"),
+            Self::TagJunkIncludeContacts => Some("PidTagJunkIncludeContacts"),
+            Self::TagJunkThreshold => Some("PidTagJunkThreshold"),
+            Self::TagJunkPermanentlyDelete => Some("PidTagJunkPermanentlyDelete"),
+            Self::TagJunkAddRecipientsToSafeSendersList => Some("PidTagJunkAddRecipientsToSafeSendersList"),
+            Self::TagJunkPhishingEnableLinks => Some("PidTagJunkPhishingEnableLinks"),
+            Self::TagMimeSkeleton => Some("PidTagMimeSkeleton"),
+            Self::TagReplyTemplateId => Some("PidTagReplyTemplateId"),
+            Self::TagSourceKey => Some("PidTagSourceKey"),
+            Self::TagParentSourceKey => Some("PidTagParentSourceKey"),
+            Self::TagChangeKey => Some("PidTagChangeKey"),
+            Self::TagPredecessorChangeList => Some("PidTagPredecessorChangeList"),
+            Self::TagRuleMessageState => Some("PidTagRuleMessageState"),
+            Self::TagRuleMessageUserFlags => Some("PidTagRuleMessageUserFlags"),
+            Self::TagRuleMessageProvider => Some("PidTagRuleMessageProvider"),
+            Self::TagRuleMessageName => Some("PidTagRuleMessageName"),
+            Self::TagRuleMessageLevel => Some("PidTagRuleMessageLevel"),
+            Self::TagRuleMessageProviderData => Some("PidTagRuleMessageProviderData"),
+            Self::TagRuleMessageSequence => Some("PidTagRuleMessageSequence"),
+            Self::TagContactAddressBookUid => Some("PidTagContactAddressBookUid"),
+            Self::TagContactAddressBookSortFlag => Some("PidTagContactAddressBookSortFlag"),
+            Self::TagProfileType => Some("PidTagProfileType"),
+            Self::TagContactAddressBookFolderEntryId => Some("PidTagContactAddressBookFolderEntryId"),
+            Self::TagContactAddressBookStoreSupportMask => Some("PidTagContactAddressBookStoreSupportMask"),
+            Self::TagContactAddressBookStoreName => Some("PidTagContactAddressBookStoreName"),
+            Self::TagContactAddressBookFolderName => Some("PidTagContactAddressBookFolderName"),
+            Self::TagContactAddressBookMultipleAddressFlag => Some("PidTagContactAddressBookMultipleAddressFlag"),
+            Self::TagUserEntryId => Some("PidTagUserEntryId"),
+            Self::TagMailboxOwnerEntryId => Some("PidTagMailboxOwnerEntryId"),
+            Self::TagMailboxOwnerName => Some("PidTagMailboxOwnerName"),
+            Self::TagOutOfOfficeState => Some("PidTagOutOfOfficeState"),
+            Self::TagContactAddressBookFolderEntryIds => Some("PidTagContactAddressBookFolderEntryIds"),
+            Self::TagContactAddressBookStoreSupportMasks => Some("PidTagContactAddressBookStoreSupportMasks"),
+            Self::TagSchedulePlusFreeBusyEntryId => Some("PidTagSchedulePlusFreeBusyEntryId"),
+            Self::TagContactAddressBookFolderNames => Some("PidTagContactAddressBookFolderNames"),
+            Self::TagContactAddressBookDisplayNames => Some("PidTagContactAddressBookDisplayNames"),
+            Self::TagContactAddressBookMultipleAddressFlags => Some("PidTagContactAddressBookMultipleAddressFlags"),
+            Self::TagContactAddressBookStoreEntryIds => Some("PidTagContactAddressBookStoreEntryIds"),
+            Self::TagRpcOverHttpProxyAuthScheme => Some("PidTagRpcOverHttpProxyAuthScheme"),
+            Self::TagSerializedReplidGuidMap => Some("PidTagSerializedReplidGuidMap"),
+            Self::TagRights => Some("PidTagRights"),
+            Self::TagHasRules => Some("PidTagHasRules"),
+            Self::TagAddressBookEntryId => Some("PidTagAddressBookEntryId"),
+            Self::TagHierarchyChangeNumber => Some("PidTagHierarchyChangeNumber"),
+            Self::TagClientActions => Some("PidTagClientActions"),
+            Self::TagDamOriginalEntryId => Some("PidTagDamOriginalEntryId"),
+            Self::TagDamBackPatched => Some("PidTagDamBackPatched"),
+            Self::TagRuleError => Some("PidTagRuleError"),
+            Self::TagRuleActionType => Some("PidTagRuleActionType"),
+            Self::TagHasNamedProperties => Some("PidTagHasNamedProperties"),
+            Self::TagRuleActionNumber => Some("PidTagRuleActionNumber"),
+            Self::TagRuleFolderEntryId => Some("PidTagRuleFolderEntryId"),
+            Self::TagRecipientNumber => Some("PidTagRecipientNumber"),
+            Self::TagProhibitReceiveQuota => Some("PidTagProhibitReceiveQuota"),
+            Self::TagInConflict => Some("PidTagInConflict"),
+            Self::TagMaximumSubmitMessageSize => Some("PidTagMaximumSubmitMessageSize"),
+            Self::TagProhibitSendQuota => Some("PidTagProhibitSendQuota"),
+            Self::TagLongTermEntryIdFromTable => Some("PidTagLongTermEntryIdFromTable"),
+            Self::TagMemberId => Some("PidTagMemberId"),
+            Self::TagMemberName => Some("PidTagMemberName"),
+            Self::TagMemberRights => Some("PidTagMemberRights"),
+            Self::TagRuleId => Some("PidTagRuleId"),
+            Self::TagRuleIds => Some("PidTagRuleIds"),
+            Self::TagRuleSequence => Some("PidTagRuleSequence"),
+            Self::TagRuleState => Some("PidTagRuleState"),
+            Self::TagRuleUserFlags => Some("PidTagRuleUserFlags"),
+            Self::TagRuleCondition => Some("PidTagRuleCondition"),
+            Self::TagRuleActions => Some("PidTagRuleActions"),
+            Self::TagRuleProvider => Some("PidTagRuleProvider"),
+            Self::TagRuleName => Some("PidTagRuleName"),
+            Self::TagRuleLevel => Some("PidTagRuleLevel"),
+            Self::TagRuleProviderData => Some("PidTagRuleProviderData"),
+            Self::TagDeletedOn => Some("PidTagDeletedOn"),
+            Self::TagLocaleId => Some("PidTagLocaleId"),
+            Self::TagFolderFlags => Some("PidTagFolderFlags"),
+            Self::TagCodePageId => Some("PidTagCodePageId"),
+            Self::TagPstPath => Some("PidTagPstPath"),
+            Self::TagPstRememberPassword => Some("PidTagPstRememberPassword"),
+            Self::TagPstPasswordSzOld => Some("PidTagPstPasswordSzOld"),
+            Self::TagAddressBookManageDistributionList => Some("PidTagAddressBookManageDistributionList"),
+            Self::TagSortLocaleId => Some("PidTagSortLocaleId"),
+            Self::TagLocalCommitTime => Some("PidTagLocalCommitTime"),
+            Self::TagLocalCommitTimeMax => Some("PidTagLocalCommitTimeMax"),
+            Self::TagDeletedCountTotal => Some("PidTagDeletedCountTotal"),
+            Self::TagFlatUrlName => Some("PidTagFlatUrlName"),
+            Self::TagSentMailSvrEID => Some("PidTagSentMailSvrEID"),
+            Self::TagDeferredActionMessageOriginalEntryId => Some("PidTagDeferredActionMessageOriginalEntryId"),
+            Self::TagFolderId => Some("PidTagFolderId"),
+            Self::TagParentFolderId => Some("PidTagParentFolderId"),
+            Self::TagMid => Some("PidTagMid"),
+            Self::TagInstID => Some("PidTagInstID"),
+            Self::TagInstanceNum => Some("PidTagInstanceNum"),
+            Self::TagAddressBookMessageId => Some("PidTagAddressBookMessageId"),
+            Self::TagPstConfigurationFlags => Some("PidTagPstConfigurationFlags"),
+            Self::TagPstPathHint => Some("PidTagPstPathHint"),
+            Self::TagChangeNumber => Some("PidTagChangeNumber"),
+            Self::TagAssociated => Some("PidTagAssociated"),
+            Self::TagAutoConfigurationUserPassword => Some("PidTagAutoConfigurationUserPassword"),
+            Self::TagAutoConfigurationUserEmail => Some("PidTagAutoConfigurationUserEmail"),
+            Self::TagAutoConfigurationUserName => Some("PidTagAutoConfigurationUserName"),
+            Self::TagOfflineAddressBookName => Some("PidTagOfflineAddressBookName"),
+            Self::TagOfflineAddressBookSequence => Some("PidTagOfflineAddressBookSequence"),
+            Self::TagOfflineAddressBookContainerGuid => Some("PidTagOfflineAddressBookContainerGuid"),
+            Self::TagOfflineAddressBookMessageClass => Some("PidTagOfflineAddressBookMessageClass"),
+            Self::TagFaxNumberOfPages => Some("PidTagFaxNumberOfPages"),
+            Self::TagOfflineAddressBookTruncatedProperties => Some("PidTagOfflineAddressBookTruncatedProperties"),
+            Self::TagCallId => Some("PidTagCallId"),
+            Self::TagReportingMessageTransferAgent => Some("PidTagReportingMessageTransferAgent"),
+            Self::TagSearchFolderLastUsed => Some("PidTagSearchFolderLastUsed"),
+            Self::TagSearchFolderExpiration => Some("PidTagSearchFolderExpiration"),
+            Self::TagScheduleInfoResourceType => Some("PidTagScheduleInfoResourceType"),
+            Self::TagScheduleInfoDelegatorWantsCopy => Some("PidTagScheduleInfoDelegatorWantsCopy"),
+            Self::TagScheduleInfoDontMailDelegates => Some("PidTagScheduleInfoDontMailDelegates"),
+            Self::TagScheduleInfoDelegateNames => Some("PidTagScheduleInfoDelegateNames"),
+            Self::TagScheduleInfoDelegateEntryIds => Some("PidTagScheduleInfoDelegateEntryIds"),
+            Self::TagGatewayNeedsToRefresh => Some("PidTagGatewayNeedsToRefresh"),
+            Self::TagFreeBusyPublishStart => Some("PidTagFreeBusyPublishStart"),
+            Self::TagFreeBusyPublishEnd => Some("PidTagFreeBusyPublishEnd"),
+            Self::TagFreeBusyMessageEmailAddress => Some("PidTagFreeBusyMessageEmailAddress"),
+            Self::TagScheduleInfoDelegateNamesW => Some("PidTagScheduleInfoDelegateNamesW"),
+            Self::TagScheduleInfoDelegatorWantsInfo => Some("PidTagScheduleInfoDelegatorWantsInfo"),
+            Self::TagWlinkEntryId => Some("PidTagWlinkEntryId"),
+            Self::TagWlinkRecordKey => Some("PidTagWlinkRecordKey"),
+            Self::TagWlinkStoreEntryId => Some("PidTagWlinkStoreEntryId"),
+            Self::TagScheduleInfoMonthsMerged => Some("PidTagScheduleInfoMonthsMerged"),
+            Self::TagScheduleInfoFreeBusyMerged => Some("PidTagScheduleInfoFreeBusyMerged"),
+            Self::TagScheduleInfoMonthsTentative => Some("PidTagScheduleInfoMonthsTentative"),
+            Self::TagScheduleInfoFreeBusyTentative => Some("PidTagScheduleInfoFreeBusyTentative"),
+            Self::TagScheduleInfoMonthsBusy => Some("PidTagScheduleInfoMonthsBusy"),
+            Self::TagScheduleInfoFreeBusyBusy => Some("PidTagScheduleInfoFreeBusyBusy"),
+            Self::TagScheduleInfoMonthsAway => Some("PidTagScheduleInfoMonthsAway"),
+            Self::TagScheduleInfoFreeBusyAway => Some("PidTagScheduleInfoFreeBusyAway"),
+            Self::TagFreeBusyRangeTimestamp => Some("PidTagFreeBusyRangeTimestamp"),
+            Self::TagFreeBusyCountMonths => Some("PidTagFreeBusyCountMonths"),
+            Self::TagScheduleInfoAppointmentTombstone => Some("PidTagScheduleInfoAppointmentTombstone"),
+            Self::TagDelegateFlags => Some("PidTagDelegateFlags"),
+            Self::TagScheduleInfoFreeBusy => Some("PidTagScheduleInfoFreeBusy"),
+            Self::TagScheduleInfoAutoAcceptAppointments => Some("PidTagScheduleInfoAutoAcceptAppointments"),
+            Self::TagScheduleInfoDisallowRecurringAppts => Some("PidTagScheduleInfoDisallowRecurringAppts"),
+            Self::TagScheduleInfoDisallowOverlappingAppts => Some("PidTagScheduleInfoDisallowOverlappingAppts"),
+            Self::TagWlinkClientID => Some("PidTagWlinkClientID"),
+            Self::TagWlinkAddressBookStoreEID => Some("PidTagWlinkAddressBookStoreEID"),
+            Self::TagWlinkROGroupType => Some("PidTagWlinkROGroupType"),
+            Self::TagNonDeliveryReportFromEntryId => Some("PidTagNonDeliveryReportFromEntryId"),
+            Self::TagNonDeliveryReportFromName => Some("PidTagNonDeliveryReportFromName"),
+            Self::TagNonDeliveryReportFromSearchKey => Some("PidTagNonDeliveryReportFromSearchKey"),
+            Self::TagViewDescriptorBinary => Some("PidTagViewDescriptorBinary"),
+            Self::TagViewDescriptorStrings => Some("PidTagViewDescriptorStrings"),
+            Self::TagViewDescriptorName => Some("PidTagViewDescriptorName"),
+            Self::TagViewDescriptorVersion => Some("PidTagViewDescriptorVersion"),
+            Self::TagRoamingDatatypes => Some("PidTagRoamingDatatypes"),
+            Self::TagRoamingDictionary => Some("PidTagRoamingDictionary"),
+            Self::TagRoamingXmlStream => Some("PidTagRoamingXmlStream"),
+            Self::TagRoamingBinary => Some("PidTagRoamingBinary"),
+            Self::TagOscSyncEnabled => Some("PidTagOscSyncEnabled"),
+            Self::TagProcessed => Some("PidTagProcessed"),
+            Self::TagExceptionReplaceTime => Some("PidTagExceptionReplaceTime"),
+            Self::TagAttachmentLinkId => Some("PidTagAttachmentLinkId"),
+            Self::TagExceptionStartTime => Some("PidTagExceptionStartTime"),
+            Self::TagExceptionEndTime => Some("PidTagExceptionEndTime"),
+            Self::TagAttachmentFlags => Some("PidTagAttachmentFlags"),
+            Self::TagAttachmentHidden => Some("PidTagAttachmentHidden"),
+            Self::TagAttachmentContactPhoto => Some("PidTagAttachmentContactPhoto"),
+            Self::TagAddressBookFolderPathname => Some("PidTagAddressBookFolderPathname"),
+            Self::TagAddressBookManager => Some("PidTagAddressBookManager"),
+            Self::TagAddressBookHomeMessageDatabase => Some("PidTagAddressBookHomeMessageDatabase"),
+            Self::TagAddressBookIsMemberOfDistributionList => Some("PidTagAddressBookIsMemberOfDistributionList"),
+            Self::TagAddressBookMember => Some("PidTagAddressBookMember"),
+            Self::TagAddressBookOwner => Some("PidTagAddressBookOwner"),
+            Self::TagAddressBookReports => Some("PidTagAddressBookReports"),
+            Self::TagAddressBookProxyAddresses => Some("PidTagAddressBookProxyAddresses"),
+            Self::TagAddressBookTargetAddress => Some("PidTagAddressBookTargetAddress"),
+            Self::TagAddressBookPublicDelegates => Some("PidTagAddressBookPublicDelegates"),
+            Self::TagAddressBookOwnerBackLink => Some("PidTagAddressBookOwnerBackLink"),
+            Self::TagAddressBookExtensionAttribute1 => Some("PidTagAddressBookExtensionAttribute1"),
+            Self::TagAddressBookExtensionAttribute2 => Some("PidTagAddressBookExtensionAttribute2"),
+            Self::TagAddressBookExtensionAttribute3 => Some("PidTagAddressBookExtensionAttribute3"),
+            Self::TagAddressBookExtensionAttribute4 => Some("PidTagAddressBookExtensionAttribute4"),
+            Self::TagAddressBookExtensionAttribute5 => Some("PidTagAddressBookExtensionAttribute5"),
+            Self::TagAddressBookExtensionAttribute6 => Some("PidTagAddressBookExtensionAttribute6"),
+            Self::TagAddressBookExtensionAttribute7 => Some("PidTagAddressBookExtensionAttribute7"),
+            Self::TagAddressBookExtensionAttribute8 => Some("PidTagAddressBookExtensionAttribute8"),
+            Self::TagAddressBookExtensionAttribute9 => Some("PidTagAddressBookExtensionAttribute9"),
+            Self::TagAddressBookExtensionAttribute10 => Some("PidTagAddressBookExtensionAttribute10"),
+            Self::TagAddressBookObjectDistinguishedName => Some("PidTagAddressBookObjectDistinguishedName"),
+            Self::TagAddressBookDeliveryContentLength => Some("PidTagAddressBookDeliveryContentLength"),
+            Self::TagAddressBookDistributionListMemberSubmitAccepted => Some("PidTagAddressBookDistributionListMemberSubmitAccepted"),
+            Self::TagAddressBookNetworkAddress => Some("PidTagAddressBookNetworkAddress"),
+            Self::TagAddressBookExtensionAttribute11 => Some("PidTagAddressBookExtensionAttribute11"),
+            Self::TagAddressBookExtensionAttribute12 => Some("PidTagAddressBookExtensionAttribute12"),
+            Self::TagAddressBookExtensionAttribute13 => Some("PidTagAddressBookExtensionAttribute13"),
+            Self::TagAddressBookExtensionAttribute14 => Some("PidTagAddressBookExtensionAttribute14"),
+            Self::TagAddressBookExtensionAttribute15 => Some("PidTagAddressBookExtensionAttribute15"),
+            Self::TagAddressBookX509Certificate => Some("PidTagAddressBookX509Certificate"),
+            Self::TagAddressBookObjectGuid => Some("PidTagAddressBookObjectGuid"),
+            Self::TagAddressBookPhoneticGivenName => Some("PidTagAddressBookPhoneticGivenName"),
+            Self::TagAddressBookPhoneticSurname => Some("PidTagAddressBookPhoneticSurname"),
+            Self::TagAddressBookPhoneticDepartmentName => Some("PidTagAddressBookPhoneticDepartmentName"),
+            Self::TagAddressBookPhoneticCompanyName => Some("PidTagAddressBookPhoneticCompanyName"),
+            Self::TagAddressBookPhoneticDisplayName => Some("PidTagAddressBookPhoneticDisplayName"),
+            Self::TagAddressBookDisplayTypeExtended => Some("PidTagAddressBookDisplayTypeExtended"),
+            Self::TagAddressBookHierarchicalShowInDepartments => Some("PidTagAddressBookHierarchicalShowInDepartments"),
+            Self::TagAddressBookRoomContainers => Some("PidTagAddressBookRoomContainers"),
+            Self::TagAddressBookHierarchicalDepartmentMembers => Some("PidTagAddressBookHierarchicalDepartmentMembers"),
+            Self::TagAddressBookHierarchicalRootDepartment => Some("PidTagAddressBookHierarchicalRootDepartment"),
+            Self::TagAddressBookHierarchicalParentDepartment => Some("PidTagAddressBookHierarchicalParentDepartment"),
+            Self::TagAddressBookHierarchicalChildDepartments => Some("PidTagAddressBookHierarchicalChildDepartments"),
+            Self::TagThumbnailPhoto => Some("PidTagThumbnailPhoto"),
+            Self::TagAddressBookSeniorityIndex => Some("PidTagAddressBookSeniorityIndex"),
+            Self::TagAddressBookOrganizationalUnitRootDistinguishedName => Some("PidTagAddressBookOrganizationalUnitRootDistinguishedName"),
+            Self::TagAddressBookSenderHintTranslations => Some("PidTagAddressBookSenderHintTranslations"),
+            Self::TagAddressBookModerationEnabled => Some("PidTagAddressBookModerationEnabled"),
+            Self::TagSpokenName => Some("PidTagSpokenName"),
+            Self::TagAddressBookAuthorizedSenders => Some("PidTagAddressBookAuthorizedSenders"),
+            Self::TagAddressBookUnauthorizedSenders => Some("PidTagAddressBookUnauthorizedSenders"),
+            Self::TagAddressBookDistributionListMemberSubmitRejected => Some("PidTagAddressBookDistributionListMemberSubmitRejected"),
+            Self::TagAddressBookDistributionListRejectMessagesFromDLMembers => Some("PidTagAddressBookDistributionListRejectMessagesFromDLMembers"),
+            Self::TagAddressBookHierarchicalIsHierarchicalGroup => Some("PidTagAddressBookHierarchicalIsHierarchicalGroup"),
+            Self::TagAddressBookDistributionListMemberCount => Some("PidTagAddressBookDistributionListMemberCount"),
+            Self::TagAddressBookDistributionListExternalMemberCount => Some("PidTagAddressBookDistributionListExternalMemberCount"),
+            Self::TagAddressBookIsMaster => Some("PidTagAddressBookIsMaster"),
+            Self::TagAddressBookParentEntryId => Some("PidTagAddressBookParentEntryId"),
+            Self::TagAddressBookContainerId => Some("PidTagAddressBookContainerId"),
+            Self::TagEmsAbServer => Some("PidTagEmsAbServer"),
+            Self::Other(_) => None,
+        }
+    }
+}
+impl fmt::Display for PropTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "0x{:04X}", u16::from(*self)),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::ical::{as_str, escape_text, named_value, push_line, tagged_value};
+use crate::tnef::{PidLid, Property, PropTag};
+
+
+const EMAIL_FIELDS: [(PidLid, PidLid); 3] = [
+    (PidLid::Email1AddressType, PidLid::Email1EmailAddress),
+    (PidLid::Email2AddressType, PidLid::Email2EmailAddress),
+    (PidLid::Email3AddressType, PidLid::Email3EmailAddress),
+];
+const TELEPHONE_FIELDS: [(PropTag, &str); 6] = [
+    (PropTag::TagBusinessTelephoneNumber, "WORK,VOICE"),
+    (PropTag::TagHomeTelephoneNumber, "HOME,VOICE"),
+    (PropTag::TagHome2TelephoneNumber, "HOME,VOICE"),
+    (PropTag::TagMobileTelephoneNumber, "CELL,VOICE"),
+    (PropTag::TagBusinessFaxNumber, "WORK,FAX"),
+    (PropTag::TagHomeFaxNumber, "HOME,FAX"),
+];
+
+
+fn tagged_str(props: &[Property], tag: PropTag) -> Option<&str> {
+    tagged_value(props, tag)
+        .and_then(as_str)
+        .filter(|s| !s.is_empty())
+}
+
+fn named_str(props: &[Property], lid: PidLid) -> Option<&str> {
+    named_value(props, lid)
+        .and_then(as_str)
+        .filter(|s| !s.is_empty())
+}
+
+fn structured_value(components: &[Option<&str>]) -> String {
+    components.iter()
+        .map(|c| c.map(escape_text).unwrap_or_default())
+        .collect::<Vec<String>>()
+        .join(";")
+}
+
+
+pub fn is_contact_class(message_class: &str) -> bool {
+    message_class.to_lowercase().starts_with("ipm.contact")
+}
+
+
+pub fn build_vcard(props: &[Property]) -> Option<String> {
+    // FN is mandatory
+    let formatted_name = tagged_str(props, PropTag::TagDisplayName)
+        .or_else(|| named_str(props, PidLid::FileUnder))
+        .or_else(|| tagged_str(props, PropTag::TagSubject))?;
+
+    let mut vcard = String::new();
+    push_line(&mut vcard, "BEGIN:VCARD");
+    push_line(&mut vcard, "VERSION:3.0");
+    push_line(&mut vcard, &format!("FN:{}", escape_text(formatted_name)));
+
+    // family name, given name, additional names, prefixes, suffixes
+    let name = structured_value(&[
+        tagged_str(props, PropTag::TagSurname),
+        tagged_str(props, PropTag::TagGivenName),
+        tagged_str(props, PropTag::TagMiddleName),
+        tagged_str(props, PropTag::TagDisplayNamePrefix),
+        tagged_str(props, PropTag::TagGeneration),
+    ]);
+    push_line(&mut vcard, &format!("N:{}", name));
+
+    if let Some(company) = tagged_str(props, PropTag::TagCompanyName) {
+        let organization = structured_value(&[Some(company), tagged_str(props, PropTag::TagDepartmentName)]);
+        push_line(&mut vcard, &format!("ORG:{}", organization.trim_end_matches(';')));
+    }
+    if let Some(title) = tagged_str(props, PropTag::TagTitle) {
+        push_line(&mut vcard, &format!("TITLE:{}", escape_text(title)));
+    }
+
+    for (type_lid, address_lid) in EMAIL_FIELDS {
+        let address = match named_str(props, address_lid) {
+            Some(a) => a,
+            None => continue,
+        };
+        // X.500 DNs of Exchange contacts are not email addresses
+        let is_smtp = named_str(props, type_lid)
+            .map(|t| t.eq_ignore_ascii_case("SMTP"))
+            .unwrap_or(true);
+        if is_smtp {
+            push_line(&mut vcard, &format!("EMAIL;TYPE=INTERNET:{}", escape_text(address)));
+        }
+    }
+
+    for (tag, tel_type) in TELEPHONE_FIELDS {
+        if let Some(number) = tagged_str(props, tag) {
+            push_line(&mut vcard, &format!("TEL;TYPE={}:{}", tel_type, escape_text(number)));
+        }
+    }
+
+    // post office box, extended address, street, locality, region, postal code, country
+    let work_address = [
+        named_str(props, PidLid::WorkAddressPostOfficeBox),
+        None,
+        named_str(props, PidLid::WorkAddressStreet),
+        named_str(props, PidLid::WorkAddressCity),
+        named_str(props, PidLid::WorkAddressState),
+        named_str(props, PidLid::WorkAddressPostalCode),
+        named_str(props, PidLid::WorkAddressCountry),
+    ];
+    let home_address = [
+        tagged_str(props, PropTag::TagHomeAddressPostOfficeBox),
+        None,
+        tagged_str(props, PropTag::TagHomeAddressStreet),
+        tagged_str(props, PropTag::TagHomeAddressCity),
+        tagged_str(props, PropTag::TagHomeAddressStateOrProvince),
+        tagged_str(props, PropTag::TagHomeAddressPostalCode),
+        tagged_str(props, PropTag::TagHomeAddressCountry),
+    ];
+    for (address, adr_type) in [(work_address, "WORK"), (home_address, "HOME")] {
+        if address.iter().any(|c| c.is_some()) {
+            push_line(&mut vcard, &format!("ADR;TYPE={}:{}", adr_type, structured_value(&address)));
+        }
+    }
+
+    push_line(&mut vcard, "END:VCARD");
+    Some(vcard)
+}